@@ -18,7 +18,8 @@ pub struct GameProfile {
     pub uuid: Uuid,
     /// The username of the player.
     ///
-    /// Limited to 16 bytes.
+    /// Limited to 16 characters.
+    #[limit(16)]
     pub name: String,
     /// The properties of the player, including their in-game skin and cape.
     ///
@@ -62,7 +63,7 @@ impl AzBuf for GameProfileProperties {
             });
         }
         for _ in 0..properties_len {
-            let key = String::azalea_read_limited(buf, 16)?;
+            let key = String::azalea_read_limited(buf, 64)?;
             let value = ProfilePropertyValue::azalea_read(buf)?;
             properties.insert(key, value);
         }
@@ -211,4 +212,53 @@ mod tests {
             }
         );
     }
+
+    #[test]
+    fn test_game_profile_roundtrip() {
+        let mut map = IndexMap::new();
+        map.insert(
+            "textures".to_owned(),
+            ProfilePropertyValue {
+                value: "ewogICJ0aW1lc3RhbXAiIDogMAp9".to_owned(),
+                signature: Some("c2lnbmF0dXJl".to_owned()),
+            },
+        );
+        map.insert(
+            "unsigned".to_owned(),
+            ProfilePropertyValue {
+                value: "asdf".to_owned(),
+                signature: None,
+            },
+        );
+        let profile = GameProfile {
+            uuid: Uuid::parse_str("f1a2b3c4-d5e6-f7a8-b9c0-d1e2f3a4b5c6").unwrap(),
+            name: "Notch".to_owned(),
+            properties: GameProfileProperties { map }.into(),
+        };
+
+        let mut buf = Vec::new();
+        profile.azalea_write(&mut buf).unwrap();
+        let read_profile = GameProfile::azalea_read(&mut io::Cursor::new(&buf)).unwrap();
+        assert_eq!(read_profile, profile);
+    }
+
+    #[test]
+    fn test_game_profile_name_too_long() {
+        let mut profile = GameProfile::new(Uuid::nil(), "a".repeat(17));
+        let mut buf = Vec::new();
+        profile.azalea_write(&mut buf).unwrap();
+        let err = GameProfile::azalea_read(&mut io::Cursor::new(&buf)).unwrap_err();
+        assert!(matches!(
+            err.root_cause(),
+            BufReadError::StringLengthTooLong {
+                length: 17,
+                max_length: 16
+            }
+        ));
+
+        profile.name = "a".repeat(16);
+        let mut buf = Vec::new();
+        profile.azalea_write(&mut buf).unwrap();
+        assert!(GameProfile::azalea_read(&mut io::Cursor::new(&buf)).is_ok());
+    }
 }
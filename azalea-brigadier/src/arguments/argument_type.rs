@@ -6,7 +6,70 @@ use crate::{
     suggestion::{Suggestions, SuggestionsBuilder},
 };
 
+/// A type of argument that can be parsed from a command.
+///
+/// You can implement this trait to create your own argument types. The value
+/// that's returned by [`Self::parse`] is stored in the [`CommandContext`],
+/// and it can be retrieved with [`CommandContext::get_argument`].
+///
+/// ```
+/// # use std::{any::Any, sync::Arc};
+/// use azalea_brigadier::{
+///     arguments::ArgumentType, errors::CommandSyntaxError, prelude::*,
+///     string_reader::StringReader,
+/// };
+///
+/// #[derive(Clone, Copy, Debug, PartialEq)]
+/// struct Vec3 {
+///     x: f64,
+///     y: f64,
+///     z: f64,
+/// }
+///
+/// /// Three numbers separated by spaces, like `1 2.5 -3`.
+/// struct Vec3Argument;
+/// impl ArgumentType for Vec3Argument {
+///     fn parse(&self, reader: &mut StringReader) -> Result<Arc<dyn Any>, CommandSyntaxError> {
+///         let x = reader.read_double()?;
+///         reader.expect(' ')?;
+///         let y = reader.read_double()?;
+///         reader.expect(' ')?;
+///         let z = reader.read_double()?;
+///         Ok(Arc::new(Vec3 { x, y, z }))
+///     }
+///
+///     fn examples(&self) -> Vec<String> {
+///         vec!["0 0 0".to_owned(), "1 2.5 -3".to_owned()]
+///     }
+/// }
+///
+/// let mut dispatcher = CommandDispatcher::<()>::new();
+/// dispatcher.register(
+///     literal("tp").then(argument("pos", Vec3Argument).executes(|ctx| {
+///         let pos = ctx.get_argument::<Vec3>("pos").unwrap();
+///         assert_eq!(
+///             *pos,
+///             Vec3 {
+///                 x: 1.,
+///                 y: 2.5,
+///                 z: -3.
+///             }
+///         );
+///         1
+///     })),
+/// );
+///
+/// assert_eq!(dispatcher.execute("tp 1 2.5 -3", ()).unwrap(), 1);
+/// assert!(dispatcher.execute("tp 1 2.5", ()).is_err());
+/// ```
+///
+/// [`CommandContext`]: crate::context::CommandContext
+/// [`CommandContext::get_argument`]: crate::context::CommandContext::get_argument
 pub trait ArgumentType {
+    /// Read the argument from the reader, and return the parsed value.
+    ///
+    /// If the argument couldn't be parsed, the reader's cursor should
+    /// usually be at the position of the error.
     fn parse(&self, reader: &mut StringReader) -> Result<Arc<dyn Any>, CommandSyntaxError>;
 
     fn list_suggestions(&self, _builder: SuggestionsBuilder) -> Suggestions {
@@ -18,7 +18,7 @@ use crate::{
     result_consumer::{DefaultResultConsumer, ResultConsumer},
     string_reader::StringReader,
     suggestion::{Suggestions, SuggestionsBuilder},
-    tree::CommandNode,
+    tree::{CommandNode, CommandTreeNode},
 };
 
 /// The root of the command tree. You need to make this to register commands.
@@ -34,6 +34,7 @@ where
 {
     pub root: Arc<RwLock<CommandNode<S, R>>>,
     consumer: Box<dyn ResultConsumer<S, R> + Send + Sync>,
+    case_insensitive_literals: bool,
 }
 
 impl<S, R: CommandResultTrait> CommandDispatcher<S, R> {
@@ -41,6 +42,7 @@ impl<S, R: CommandResultTrait> CommandDispatcher<S, R> {
         Self {
             root: Arc::new(RwLock::new(CommandNode::default())),
             consumer: Box::new(DefaultResultConsumer),
+            case_insensitive_literals: false,
         }
     }
 
@@ -76,7 +78,10 @@ impl<S, R: CommandResultTrait> CommandDispatcher<S, R> {
         let mut potentials: Vec<ParseResults<S, R>> = vec![];
         let cursor = original_reader.cursor();
 
-        for child in node.read().get_relevant_nodes(&mut original_reader.clone()) {
+        for child in node.read().get_relevant_nodes_with_case(
+            &mut original_reader.clone(),
+            self.case_insensitive_literals,
+        ) {
             if !child.read().can_use(&source) {
                 continue;
             }
@@ -178,7 +183,8 @@ impl<S, R: CommandResultTrait> CommandDispatcher<S, R> {
     /// Parse and execute the command using the given input and context.
     ///
     /// The number returned depends on the command, and may not be of
-    /// significance.
+    /// significance. If the command was forked (like `execute as @a ...`),
+    /// then the number of forks that successfully ran is returned instead.
     ///
     /// This is a shortcut for `Self::parse` and `Self::execute_parsed`.
     pub fn execute(
@@ -473,6 +479,94 @@ impl<S, R: CommandResultTrait> CommandDispatcher<S, R> {
     }
 }
 
+impl<S, R> CommandDispatcher<S, R>
+where
+    Self: Sync + Send,
+{
+    /// Set whether literals should be matched case-insensitively when parsing,
+    /// so `/Gamemode` matches a `gamemode` literal.
+    ///
+    /// Only ASCII letters are compared case-insensitively. Suggestions will
+    /// still use the casing that the literal was registered with.
+    ///
+    /// This is disabled by default.
+    pub fn set_case_insensitive_literals(&mut self, enabled: bool) {
+        self.case_insensitive_literals = enabled;
+    }
+
+    /// Whether literals are matched case-insensitively.
+    ///
+    /// See [`Self::set_case_insensitive_literals`].
+    pub fn case_insensitive_literals(&self) -> bool {
+        self.case_insensitive_literals
+    }
+
+    /// Export the registered command tree as plain data, for example to
+    /// generate documentation or to build a declare-commands packet.
+    ///
+    /// Redirects are represented as the path to their target, so this
+    /// terminates even if a node redirects back to one of its parents.
+    ///
+    /// ```
+    /// # use azalea_brigadier::prelude::*;
+    /// # use azalea_brigadier::tree::CommandTreeNodeKind;
+    /// let mut subject = CommandDispatcher::<()>::new();
+    /// subject.register(literal("foo").then(argument("bar", integer()).executes(|_| 42)));
+    ///
+    /// let tree = subject.export_tree();
+    /// assert_eq!(tree.kind, CommandTreeNodeKind::Root);
+    /// assert_eq!(tree.children[0].name, "foo");
+    /// assert!(tree.children[0].children[0].executable);
+    /// ```
+    pub fn export_tree(&self) -> CommandTreeNode {
+        crate::tree::export_tree(&self.root)
+    }
+
+    /// Find pairs of sibling nodes that could both match the same input,
+    /// which usually means that a command was designed incorrectly.
+    ///
+    /// The `consumer` is called with the parent node, the two ambiguous
+    /// children, and the example inputs that both of them accept. Inputs are
+    /// only found from [`ArgumentType::examples`], so arguments without
+    /// examples can't be detected as ambiguous.
+    ///
+    /// ```
+    /// # use azalea_brigadier::prelude::*;
+    /// let mut subject = CommandDispatcher::<()>::new();
+    /// subject.register(literal("give").then(literal("all")));
+    /// subject.register(literal("give").then(argument("player", word())));
+    ///
+    /// let mut ambiguities = Vec::new();
+    /// subject.find_ambiguities(|parent, child, sibling, inputs| {
+    ///     ambiguities.push((
+    ///         parent.name().to_owned(),
+    ///         child.name().to_owned(),
+    ///         sibling.name().to_owned(),
+    ///         inputs.len(),
+    ///     ));
+    /// });
+    /// assert_eq!(
+    ///     ambiguities,
+    ///     [("give".to_owned(), "all".to_owned(), "player".to_owned(), 1)]
+    /// );
+    /// ```
+    ///
+    /// [`ArgumentType::examples`]: crate::arguments::ArgumentType::examples
+    pub fn find_ambiguities(
+        &self,
+        mut consumer: impl FnMut(
+            &CommandNode<S, R>,
+            &CommandNode<S, R>,
+            &CommandNode<S, R>,
+            &HashSet<String>,
+        ),
+    ) {
+        self.root
+            .read()
+            .find_ambiguities(&mut consumer, self.case_insensitive_literals);
+    }
+}
+
 impl<S, R: CommandResultTrait> Default for CommandDispatcher<S, R> {
     fn default() -> Self {
         Self::new()
@@ -104,6 +104,26 @@ impl<S, R> CommandContext<S, R> {
         argument.map(|a| a.result.as_ref())
     }
 
+    /// Get the parsed value of an argument, or `None` if there's no argument
+    /// with that name or it has a different type.
+    ///
+    /// This is mostly useful for custom [`ArgumentType`]s, since the built-in
+    /// types have their own getters like [`get_integer`].
+    ///
+    /// [`ArgumentType`]: crate::arguments::ArgumentType
+    /// [`get_integer`]: crate::arguments::integer_argument_type::get_integer
+    pub fn get_argument<T: Any>(&self, name: &str) -> Option<&T> {
+        self.argument(name)?.downcast_ref::<T>()
+    }
+
+    /// Get the range in the input that the argument with the given name was
+    /// parsed from.
+    ///
+    /// This is useful for pointing at a specific argument in error messages.
+    pub fn argument_range(&self, name: &str) -> Option<StringRange> {
+        self.arguments.get(name).map(|a| a.range)
+    }
+
     pub fn redirect_modifier(&self) -> Option<&RedirectModifier<S, R>> {
         self.modifier.as_ref().map(|m| m.as_ref())
     }
@@ -0,0 +1,103 @@
+use std::{collections::HashMap, sync::Arc};
+
+use parking_lot::RwLock;
+
+use super::CommandNode;
+use crate::builder::argument_builder::ArgumentBuilderType;
+
+/// A plain data representation of a [`CommandNode`] and its children, as
+/// returned by [`CommandDispatcher::export_tree`].
+///
+/// Unlike [`CommandNode`], this doesn't contain any closures, so it can be
+/// compared, cloned cheaply, and (with the `serde` feature) serialized.
+///
+/// [`CommandDispatcher::export_tree`]: crate::command_dispatcher::CommandDispatcher::export_tree
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct CommandTreeNode {
+    pub kind: CommandTreeNodeKind,
+    /// The literal value or argument name of this node. This is empty for
+    /// the root node.
+    pub name: String,
+    /// Whether this node has a command that can be executed.
+    pub executable: bool,
+    /// The children of this node, sorted by name.
+    pub children: Vec<CommandTreeNode>,
+    /// The path of names from the root to the node that this node redirects
+    /// to.
+    ///
+    /// An empty path means the root node. This is `None` if the node doesn't
+    /// redirect anywhere, or if the target isn't reachable from the root.
+    pub redirect: Option<Vec<String>>,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum CommandTreeNodeKind {
+    Root,
+    Literal,
+    Argument,
+}
+
+type NodePtr<S, R> = *const RwLock<CommandNode<S, R>>;
+
+/// Build a [`CommandTreeNode`] from the given root node.
+///
+/// Redirects are only ever stored as paths, so cycles created by redirecting
+/// to a parent (like `/execute run`) don't cause infinite recursion.
+pub(crate) fn export_tree<S, R>(root: &Arc<RwLock<CommandNode<S, R>>>) -> CommandTreeNode {
+    let mut paths = HashMap::new();
+    collect_paths(root, &mut Vec::new(), &mut paths);
+    export_node(root, true, &paths)
+}
+
+fn collect_paths<S, R>(
+    node: &Arc<RwLock<CommandNode<S, R>>>,
+    current: &mut Vec<String>,
+    paths: &mut HashMap<NodePtr<S, R>, Vec<String>>,
+) {
+    // a node might be reachable through several paths if it was added as a
+    // child more than once, in which case the first one wins
+    if paths.contains_key(&Arc::as_ptr(node)) {
+        return;
+    }
+    paths.insert(Arc::as_ptr(node), current.clone());
+
+    for (name, child) in &node.read().children {
+        current.push(name.clone());
+        collect_paths(child, current, paths);
+        current.pop();
+    }
+}
+
+fn export_node<S, R>(
+    node: &Arc<RwLock<CommandNode<S, R>>>,
+    is_root: bool,
+    paths: &HashMap<NodePtr<S, R>, Vec<String>>,
+) -> CommandTreeNode {
+    let node = node.read();
+
+    let kind = if is_root {
+        CommandTreeNodeKind::Root
+    } else {
+        match node.value {
+            ArgumentBuilderType::Literal(_) => CommandTreeNodeKind::Literal,
+            ArgumentBuilderType::Argument(_) => CommandTreeNodeKind::Argument,
+        }
+    };
+
+    CommandTreeNode {
+        kind,
+        name: node.name().to_owned(),
+        executable: node.command.is_some(),
+        children: node
+            .children
+            .values()
+            .map(|child| export_node(child, false, paths))
+            .collect(),
+        redirect: node
+            .redirect
+            .as_ref()
+            .and_then(|target| paths.get(&Arc::as_ptr(target)).cloned()),
+    }
+}
@@ -1,5 +1,5 @@
 use std::{
-    collections::{BTreeMap, HashMap},
+    collections::{BTreeMap, HashMap, HashSet},
     fmt::{self, Debug},
     hash::{Hash, Hasher},
     ptr,
@@ -20,6 +20,17 @@ use crate::{
     suggestion::{Suggestions, SuggestionsBuilder},
 };
 
+mod export;
+
+pub(crate) use export::export_tree;
+pub use export::{CommandTreeNode, CommandTreeNodeKind};
+
+/// A callback for [`CommandNode::find_ambiguities`], which is called with the
+/// parent node, the two sibling nodes that are ambiguous, and the example
+/// inputs that both of them would accept.
+pub type AmbiguityConsumer<'a, S, R> =
+    dyn FnMut(&CommandNode<S, R>, &CommandNode<S, R>, &CommandNode<S, R>, &HashSet<String>) + 'a;
+
 pub type Command<S, R> =
     Option<Arc<dyn Fn(&CommandContext<S, R>) -> Result<R, CommandSyntaxError> + Send + Sync>>;
 
@@ -87,6 +98,16 @@ impl<S, R> CommandNode<S, R> {
     pub fn get_relevant_nodes(
         &self,
         input: &mut StringReader,
+    ) -> Vec<Arc<RwLock<CommandNode<S, R>>>> {
+        self.get_relevant_nodes_with_case(input, false)
+    }
+
+    /// Like [`Self::get_relevant_nodes`], but optionally matching literals
+    /// without caring about ASCII case.
+    pub fn get_relevant_nodes_with_case(
+        &self,
+        input: &mut StringReader,
+        case_insensitive: bool,
     ) -> Vec<Arc<RwLock<CommandNode<S, R>>>> {
         let literals = &self.literals;
 
@@ -104,7 +125,13 @@ impl<S, R> CommandNode<S, R> {
                 .take(input.cursor() - cursor)
                 .collect();
             input.cursor = cursor;
-            let literal = literals.get(&text);
+            let mut literal = literals.get(&text);
+            if literal.is_none() && case_insensitive {
+                literal = literals
+                    .iter()
+                    .find(|(name, _)| name.eq_ignore_ascii_case(&text))
+                    .map(|(_, node)| node);
+            }
             if let Some(literal) = literal {
                 vec![literal.clone()]
             } else {
@@ -180,7 +207,10 @@ impl<S, R> CommandNode<S, R> {
             }
             ArgumentBuilderType::Literal(ref literal) => {
                 let start = reader.cursor();
-                let end = self.parse(reader);
+                let end = self.parse(
+                    reader,
+                    context_builder.dispatcher.case_insensitive_literals(),
+                );
 
                 if let Some(end) = end {
                     context_builder.with_node(
@@ -198,7 +228,7 @@ impl<S, R> CommandNode<S, R> {
         }
     }
 
-    fn parse(&self, reader: &mut StringReader) -> Option<usize> {
+    fn parse(&self, reader: &mut StringReader, case_insensitive: bool) -> Option<usize> {
         match self.value {
             ArgumentBuilderType::Argument(_) => {
                 panic!("Can't parse argument.")
@@ -207,12 +237,16 @@ impl<S, R> CommandNode<S, R> {
                 let start = reader.cursor();
                 if reader.can_read_length(literal.value.len()) {
                     let end = start + literal.value.len();
-                    if reader
+                    let text = reader
                         .string()
                         .get(start..end)
-                        .expect("Couldn't slice reader correctly?")
-                        == literal.value
-                    {
+                        .expect("Couldn't slice reader correctly?");
+                    let matches = if case_insensitive {
+                        text.eq_ignore_ascii_case(&literal.value)
+                    } else {
+                        text == literal.value
+                    };
+                    if matches {
                         reader.cursor = end;
                         if !reader.can_read() || reader.peek() == ' ' {
                             return Some(end);
@@ -226,6 +260,54 @@ impl<S, R> CommandNode<S, R> {
         None
     }
 
+    /// Some example inputs that this node would accept.
+    pub fn examples(&self) -> Vec<String> {
+        match &self.value {
+            ArgumentBuilderType::Literal(literal) => vec![literal.value.clone()],
+            ArgumentBuilderType::Argument(argument) => argument.examples(),
+        }
+    }
+
+    /// Whether the entire input could be parsed by this node.
+    pub fn is_valid_input(&self, input: &str, case_insensitive: bool) -> bool {
+        let mut reader = StringReader::from(input);
+        match &self.value {
+            ArgumentBuilderType::Literal(_) => self.parse(&mut reader, case_insensitive).is_some(),
+            ArgumentBuilderType::Argument(argument) => {
+                argument.parse(&mut reader).is_ok() && (!reader.can_read() || reader.peek() == ' ')
+            }
+        }
+    }
+
+    /// Recursively look for pairs of sibling nodes that would both accept
+    /// one of the other's examples, and call the `consumer` for each of
+    /// them.
+    ///
+    /// Note that each ambiguous pair is reported from both directions, since
+    /// one node's examples being accepted by its sibling doesn't mean the
+    /// reverse is also true.
+    pub fn find_ambiguities(&self, consumer: &mut AmbiguityConsumer<S, R>, case_insensitive: bool) {
+        for child in self.children.values() {
+            let child = child.read();
+            for sibling in self.children.values() {
+                let sibling = sibling.read();
+                if ptr::eq(&*child, &*sibling) {
+                    continue;
+                }
+
+                let matches = child
+                    .examples()
+                    .into_iter()
+                    .filter(|input| sibling.is_valid_input(input, case_insensitive))
+                    .collect::<HashSet<_>>();
+                if !matches.is_empty() {
+                    consumer(self, &child, &sibling, &matches);
+                }
+            }
+            child.find_ambiguities(consumer, case_insensitive);
+        }
+    }
+
     pub fn list_suggestions(
         &self,
         context: CommandContext<S, R>,
@@ -0,0 +1,121 @@
+use std::collections::HashSet;
+
+use azalea_brigadier::prelude::*;
+
+fn find_ambiguities(
+    subject: &CommandDispatcher<()>,
+) -> Vec<(String, String, String, HashSet<String>)> {
+    let mut ambiguities = Vec::new();
+    subject.find_ambiguities(|parent, child, sibling, inputs| {
+        ambiguities.push((
+            parent.name().to_owned(),
+            child.name().to_owned(),
+            sibling.name().to_owned(),
+            inputs.clone(),
+        ));
+    });
+    ambiguities
+}
+
+fn inputs(inputs: &[&str]) -> HashSet<String> {
+    inputs.iter().map(|&s| s.to_owned()).collect()
+}
+
+#[test]
+fn find_ambiguities_literal_and_argument() {
+    let mut subject = CommandDispatcher::<()>::new();
+    subject.register(
+        literal("foo")
+            .then(literal("123").executes(|_| 1))
+            .then(argument("bar", integer()).executes(|_| 2)),
+    );
+
+    assert_eq!(
+        find_ambiguities(&subject),
+        [
+            (
+                "foo".to_owned(),
+                "123".to_owned(),
+                "bar".to_owned(),
+                inputs(&["123"])
+            ),
+            // "123" is also one of the examples for integer arguments
+            (
+                "foo".to_owned(),
+                "bar".to_owned(),
+                "123".to_owned(),
+                inputs(&["123"])
+            ),
+        ]
+    );
+}
+
+#[test]
+fn find_ambiguities_overlapping_arguments() {
+    let mut subject = CommandDispatcher::<()>::new();
+    subject.register(
+        literal("foo")
+            .then(argument("a", integer()))
+            .then(argument("b", double())),
+    );
+
+    // every integer is a valid double, and some of the double examples are
+    // also valid integers
+    assert_eq!(
+        find_ambiguities(&subject),
+        [
+            (
+                "foo".to_owned(),
+                "a".to_owned(),
+                "b".to_owned(),
+                inputs(&["0", "123", "-123"])
+            ),
+            (
+                "foo".to_owned(),
+                "b".to_owned(),
+                "a".to_owned(),
+                inputs(&["0", "-1"])
+            ),
+        ]
+    );
+}
+
+#[test]
+fn find_ambiguities_case_insensitive_literals() {
+    let mut subject = CommandDispatcher::<()>::new();
+    subject.register(literal("foo").executes(|_| 1));
+    subject.register(literal("FOO").executes(|_| 2));
+    assert!(find_ambiguities(&subject).is_empty());
+
+    subject.set_case_insensitive_literals(true);
+    assert_eq!(
+        find_ambiguities(&subject),
+        [
+            (
+                "".to_owned(),
+                "FOO".to_owned(),
+                "foo".to_owned(),
+                inputs(&["FOO"])
+            ),
+            (
+                "".to_owned(),
+                "foo".to_owned(),
+                "FOO".to_owned(),
+                inputs(&["foo"])
+            ),
+        ]
+    );
+}
+
+#[test]
+fn find_ambiguities_none() {
+    let mut subject = CommandDispatcher::<()>::new();
+    subject.register(
+        literal("foo")
+            .then(literal("bar").then(argument("baz", integer())))
+            .then(literal("baz")),
+    );
+    subject.register(literal("bar").then(argument("baz", bool())));
+
+    assert!(find_ambiguities(&subject).is_empty());
+}
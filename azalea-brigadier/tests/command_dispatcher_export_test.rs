@@ -0,0 +1,68 @@
+use azalea_brigadier::{
+    prelude::*,
+    tree::{CommandTreeNode, CommandTreeNodeKind},
+};
+
+fn node(
+    kind: CommandTreeNodeKind,
+    name: &str,
+    executable: bool,
+    children: Vec<CommandTreeNode>,
+) -> CommandTreeNode {
+    CommandTreeNode {
+        kind,
+        name: name.to_owned(),
+        executable,
+        children,
+        redirect: None,
+    }
+}
+
+#[test]
+fn export_tree() {
+    let mut subject = CommandDispatcher::<()>::new();
+    let foo = subject.register(
+        literal("foo")
+            .then(argument("bar", integer()).executes(|_| 42))
+            .then(literal("baz").executes(|_| 1)),
+    );
+    subject.register(literal("alias").redirect(foo));
+    let execute = subject.register(literal("execute"));
+    subject.register(literal("execute").then(literal("run").redirect(subject.root.clone())));
+    // redirecting back into its own parent must not recurse forever
+    subject.register(literal("execute").then(literal("again").redirect(execute)));
+
+    let mut alias = node(CommandTreeNodeKind::Literal, "alias", false, vec![]);
+    alias.redirect = Some(vec!["foo".to_owned()]);
+    let mut again = node(CommandTreeNodeKind::Literal, "again", false, vec![]);
+    again.redirect = Some(vec!["execute".to_owned()]);
+    let mut run = node(CommandTreeNodeKind::Literal, "run", false, vec![]);
+    run.redirect = Some(vec![]);
+
+    assert_eq!(
+        subject.export_tree(),
+        node(
+            CommandTreeNodeKind::Root,
+            "",
+            false,
+            vec![
+                alias,
+                node(
+                    CommandTreeNodeKind::Literal,
+                    "execute",
+                    false,
+                    vec![again, run]
+                ),
+                node(
+                    CommandTreeNodeKind::Literal,
+                    "foo",
+                    false,
+                    vec![
+                        node(CommandTreeNodeKind::Argument, "bar", true, vec![]),
+                        node(CommandTreeNodeKind::Literal, "baz", true, vec![]),
+                    ]
+                ),
+            ]
+        )
+    );
+}
@@ -54,6 +54,33 @@ fn execute_unknown_command() {
     assert_eq!(err.cursor().unwrap(), 0);
 }
 
+#[test]
+fn execute_case_insensitive_literal() {
+    let mut subject = CommandDispatcher::new();
+    subject.register(literal("gamemode").then(literal("creative").executes(|_| 42)));
+
+    // literals are case-sensitive by default
+    let err = subject
+        .execute("Gamemode creative", &CommandSource {})
+        .err()
+        .unwrap();
+    assert_eq!(err.kind(), &BuiltInError::DispatcherUnknownCommand);
+
+    subject.set_case_insensitive_literals(true);
+    assert_eq!(
+        subject
+            .execute("Gamemode CREATIVE", &CommandSource {})
+            .unwrap(),
+        42
+    );
+    assert_eq!(
+        subject
+            .execute("gamemode creative", &CommandSource {})
+            .unwrap(),
+        42
+    );
+}
+
 #[test]
 fn execute_impermissible_command() {
     let mut subject = CommandDispatcher::<_, i32>::new();
@@ -266,6 +293,47 @@ fn execute_redirected() {
     assert_eq!(subject.execute_parsed(parse).unwrap(), 2);
 }
 
+#[test]
+fn execute_forked_returns_successful_fork_count() {
+    #[derive(Debug, PartialEq)]
+    struct NumberSource(i32);
+
+    let mut subject = CommandDispatcher::new();
+
+    let modifier =
+        |_: &CommandContext<NumberSource>| -> Result<Vec<Arc<NumberSource>>, CommandSyntaxError> {
+            Ok(vec![
+                Arc::new(NumberSource(1)),
+                Arc::new(NumberSource(2)),
+                Arc::new(NumberSource(3)),
+            ])
+        };
+
+    subject.register(literal("actual").executes(|_| 42));
+    subject.register(literal("fails_on_two").executes_result(
+        |c: &CommandContext<NumberSource>| {
+            if c.source.0 == 2 {
+                Err(BuiltInError::DispatcherUnknownCommand.create())
+            } else {
+                Ok(42)
+            }
+        },
+    ));
+    subject.register(literal("forked").fork(subject.root.clone(), Arc::new(modifier)));
+
+    assert_eq!(
+        subject.execute("forked actual", NumberSource(0)).unwrap(),
+        3
+    );
+    // failing forks aren't counted, and don't make the whole command fail
+    assert_eq!(
+        subject
+            .execute("forked fails_on_two", NumberSource(0))
+            .unwrap(),
+        2
+    );
+}
+
 #[test]
 fn execute_orphaned_subcommand() {
     let mut subject = CommandDispatcher::new();
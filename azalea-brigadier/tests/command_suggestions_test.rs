@@ -90,6 +90,29 @@ fn get_completion_suggestions_root_commands_partial() {
     );
 }
 
+#[test]
+fn get_completion_suggestions_case_insensitive_literals() {
+    let mut subject = CommandDispatcher::<()>::new();
+    subject.register(literal("gamemode").then(literal("creative")));
+    subject.set_case_insensitive_literals(true);
+
+    // the suggestions keep the casing of the registered literals
+    test_suggestions(
+        &subject,
+        "GameMode C",
+        10,
+        StringRange::between(9, 10),
+        vec!["creative"],
+    );
+    test_suggestions(
+        &subject,
+        "G",
+        1,
+        StringRange::between(0, 1),
+        vec!["gamemode"],
+    );
+}
+
 #[test]
 fn get_completion_suggestions_root_commands_partial_with_input_offset() {
     let mut subject = CommandDispatcher::<()>::new();
@@ -1 +1,44 @@
+use azalea_brigadier::{
+    arguments::integer_argument_type::integer,
+    builder::{literal_argument_builder::literal, required_argument_builder::argument},
+    command_dispatcher::CommandDispatcher,
+    context::StringRange,
+};
 
+#[derive(Debug, PartialEq)]
+struct CommandSource {}
+
+#[test]
+fn argument_range() {
+    let mut subject = CommandDispatcher::<CommandSource>::new();
+    subject.register(
+        literal("foo").then(argument("value", integer()).then(literal("bar").executes(|_| 42))),
+    );
+
+    let input = "foo 123 bar";
+    let parse = subject.parse(input.into(), CommandSource {});
+    let context = parse.context.build(input);
+
+    let range = context.argument_range("value").unwrap();
+    assert_eq!(range, StringRange::new(4, 7));
+    assert_eq!(range.get(input), "123");
+
+    // literals aren't arguments
+    assert_eq!(context.argument_range("bar"), None);
+    assert_eq!(context.argument_range("missing"), None);
+}
+
+#[test]
+fn get_argument() {
+    let mut subject = CommandDispatcher::<CommandSource>::new();
+    subject.register(literal("foo").then(argument("value", integer())));
+
+    let input = "foo 123";
+    let parse = subject.parse(input.into(), CommandSource {});
+    let context = parse.context.build(input);
+
+    assert_eq!(context.get_argument::<i32>("value"), Some(&123));
+    // wrong type
+    assert_eq!(context.get_argument::<i64>("value"), None);
+    assert_eq!(context.get_argument::<i32>("missing"), None);
+}
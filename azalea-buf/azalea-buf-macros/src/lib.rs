@@ -3,9 +3,9 @@ mod write;
 
 use proc_macro::TokenStream;
 use quote::quote;
-use syn::{DeriveInput, parse_macro_input};
+use syn::{DeriveInput, Variant, parse_macro_input, punctuated::Punctuated, token::Comma};
 
-#[proc_macro_derive(AzBuf, attributes(var, limit))]
+#[proc_macro_derive(AzBuf, attributes(var, limit, az_buf))]
 pub fn derive_azbuf(input: TokenStream) -> TokenStream {
     let DeriveInput {
         ident,
@@ -17,7 +17,7 @@ pub fn derive_azbuf(input: TokenStream) -> TokenStream {
     let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
     let writable = write::create_fn_azalea_write(&data);
-    let readable = read::create_fn_azalea_read(&data);
+    let readable = read::create_fn_azalea_read(&ident, &data);
     quote! {
         impl #impl_generics azalea_buf::AzBuf for #ident #ty_generics #where_clause {
             #writable
@@ -26,3 +26,80 @@ pub fn derive_azbuf(input: TokenStream) -> TokenStream {
     }
     .into()
 }
+
+/// Get the ids that each variant of an enum is written with.
+///
+/// A variant's id can be set explicitly with `#[az_buf(id = N)]` (which may be
+/// negative) or with a normal Rust discriminant. Variants without either get
+/// the id of the previous variant plus one, starting from zero.
+///
+/// The second value returned is whether any variant used `#[az_buf(id = N)]`,
+/// in which case the Rust discriminants can't be used as the ids.
+fn variant_ids(variants: &Punctuated<Variant, Comma>) -> (Vec<u32>, bool) {
+    let mut ids = Vec::with_capacity(variants.len());
+    let mut has_id_attr = false;
+    let mut next_id: u32 = 0;
+    for variant in variants {
+        let id = if let Some(id) = parse_id_attr(variant) {
+            has_id_attr = true;
+            id
+        } else if let Some((_, d)) = &variant.discriminant {
+            match d {
+                syn::Expr::Lit(e) => match &e.lit {
+                    syn::Lit::Int(i) => i.base10_parse().unwrap(),
+                    _ => panic!("Error parsing enum discriminant as int (is {e:?})"),
+                },
+                syn::Expr::Unary(_) => {
+                    panic!("Negative enum discriminants are not supported, use #[az_buf(id = N)]")
+                }
+                _ => panic!("Error parsing enum discriminant as literal (is {d:?})"),
+            }
+        } else {
+            next_id
+        };
+        ids.push(id);
+        next_id = id.wrapping_add(1);
+    }
+    (ids, has_id_attr)
+}
+
+/// Parse `#[az_buf(id = N)]` on an enum variant, returning the id as it'll be
+/// written as a varint (so negative ids are two's complement). Setting the id
+/// more than once on the same variant is an error.
+fn parse_id_attr(variant: &Variant) -> Option<u32> {
+    let mut id = None;
+    for attr in &variant.attrs {
+        if !attr.path().is_ident("az_buf") {
+            continue;
+        }
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("id") {
+                if id.is_some() {
+                    return Err(meta.error("duplicate `id` in #[az_buf] attributes"));
+                }
+                let expr: syn::Expr = meta.value()?.parse()?;
+                id = Some(parse_int_expr(&expr)?);
+                Ok(())
+            } else {
+                Err(meta.error("unknown az_buf attribute, expected `id`"))
+            }
+        })
+        .unwrap_or_else(|e| panic!("Error parsing #[az_buf] attribute: {e}"));
+    }
+    id
+}
+
+fn parse_int_expr(expr: &syn::Expr) -> syn::Result<u32> {
+    match expr {
+        syn::Expr::Lit(syn::ExprLit {
+            lit: syn::Lit::Int(i),
+            ..
+        }) => Ok(i.base10_parse::<i32>()? as u32),
+        syn::Expr::Unary(syn::ExprUnary {
+            op: syn::UnOp::Neg(_),
+            expr,
+            ..
+        }) => Ok(parse_int_expr(expr)?.wrapping_neg()),
+        _ => Err(syn::Error::new_spanned(expr, "expected an integer literal")),
+    }
+}
@@ -1,11 +1,12 @@
 use quote::{ToTokens, quote};
 use syn::{Data, Field, FieldsNamed, Ident, punctuated::Punctuated, token::Comma};
 
-pub fn create_fn_azalea_read(data: &Data) -> proc_macro2::TokenStream {
+pub fn create_fn_azalea_read(ident: &Ident, data: &Data) -> proc_macro2::TokenStream {
+    let type_name = ident.to_string();
     match data {
         syn::Data::Struct(syn::DataStruct { fields, .. }) => match fields {
             syn::Fields::Named(FieldsNamed { named, .. }) => {
-                let (read_fields, read_field_names) = read_named_fields(named);
+                let (read_fields, read_field_names) = read_named_fields(named, &type_name);
 
                 quote! {
                     fn azalea_read(buf: &mut std::io::Cursor<&[u8]>) -> std::result::Result<Self, azalea_buf::BufReadError> {
@@ -24,7 +25,7 @@ pub fn create_fn_azalea_read(data: &Data) -> proc_macro2::TokenStream {
                 }
             }
             syn::Fields::Unnamed(fields) => {
-                let read_fields = read_unnamed_fields(&fields.unnamed);
+                let read_fields = read_unnamed_fields(&fields.unnamed, &type_name);
 
                 quote! {
                     fn azalea_read(buf: &mut std::io::Cursor<&[u8]>) -> std::result::Result<Self, azalea_buf::BufReadError> {
@@ -37,35 +38,15 @@ pub fn create_fn_azalea_read(data: &Data) -> proc_macro2::TokenStream {
         },
         syn::Data::Enum(syn::DataEnum { variants, .. }) => {
             let mut match_contents = quote!();
-            let mut variant_discrim: u32 = 0;
-            let mut first = true;
+            let (variant_ids, _) = crate::variant_ids(variants);
             let mut first_reader = None;
-            for variant in variants {
+            for (variant, variant_discrim) in variants.iter().zip(variant_ids) {
                 let variant_name = &variant.ident;
-                match &variant.discriminant.as_ref() {
-                    Some(d) => {
-                        variant_discrim = match &d.1 {
-                            syn::Expr::Lit(e) => match &e.lit {
-                                syn::Lit::Int(i) => i.base10_parse().unwrap(),
-                                _ => panic!("Error parsing enum discriminant as int (is {e:?})"),
-                            },
-                            syn::Expr::Unary(_) => {
-                                panic!("Negative enum discriminants are not supported")
-                            }
-                            _ => {
-                                panic!("Error parsing enum discriminant as literal (is {:?})", d.1)
-                            }
-                        }
-                    }
-                    None => {
-                        if !first {
-                            variant_discrim += 1;
-                        }
-                    }
-                }
+                let variant_type_name = format!("{type_name}::{variant_name}");
                 let reader = match &variant.fields {
                     syn::Fields::Named(f) => {
-                        let (read_fields, read_field_names) = read_named_fields(&f.named);
+                        let (read_fields, read_field_names) =
+                            read_named_fields(&f.named, &variant_type_name);
 
                         quote! {
                             #(#read_fields)*
@@ -76,37 +57,14 @@ pub fn create_fn_azalea_read(data: &Data) -> proc_macro2::TokenStream {
                     }
                     syn::Fields::Unnamed(fields) => {
                         let mut reader_code = quote! {};
-                        for f in &fields.unnamed {
-                            let is_variable_length =
-                                f.attrs.iter().any(|a| a.path().is_ident("var"));
-                            let limit =
-                                f.attrs
-                                    .iter()
-                                    .find(|a| a.path().is_ident("limit"))
-                                    .map(|a| {
-                                        a.parse_args::<syn::LitInt>()
-                                            .unwrap()
-                                            .base10_parse::<u32>()
-                                            .unwrap()
-                                    });
-
-                            if is_variable_length && limit.is_some() {
-                                panic!("Fields cannot have both var and limit attributes");
-                            }
-
-                            if is_variable_length {
-                                reader_code.extend(quote! {
-                                    Self::#variant_name(azalea_buf::AzBufVar::azalea_read_var(buf)?),
-                                });
-                            } else if let Some(limit) = limit {
-                                reader_code.extend(quote! {
-                                    Self::#variant_name(azalea_buf::AzBufLimited::azalea_read_limited(buf, #limit)?),
-                                });
-                            } else {
-                                reader_code.extend(quote! {
-                                    Self::#variant_name(azalea_buf::AzBuf::azalea_read(buf)?),
-                                });
-                            }
+                        for (i, f) in fields.unnamed.iter().enumerate() {
+                            let reader_call = get_reader_call(
+                                f,
+                                &format!("while reading field {i} of `{variant_type_name}`"),
+                            );
+                            reader_code.extend(quote! {
+                                Self::#variant_name(#reader_call),
+                            });
                         }
                         quote! { Ok(#reader_code) }
                     }
@@ -114,9 +72,8 @@ pub fn create_fn_azalea_read(data: &Data) -> proc_macro2::TokenStream {
                         Ok(Self::#variant_name)
                     },
                 };
-                if first {
+                if first_reader.is_none() {
                     first_reader = Some(reader.clone());
-                    first = false;
                 };
 
                 match_contents.extend(quote! {
@@ -130,7 +87,7 @@ pub fn create_fn_azalea_read(data: &Data) -> proc_macro2::TokenStream {
 
             quote! {
                 fn azalea_read(buf: &mut std::io::Cursor<&[u8]>) -> std::result::Result<Self, azalea_buf::BufReadError> {
-                    let id = azalea_buf::AzBufVar::azalea_read_var(buf)?;
+                    let id: u32 = azalea_buf::AzBufVar::azalea_read_var(buf)?;
 
                     match id {
                         #match_contents
@@ -144,15 +101,22 @@ pub fn create_fn_azalea_read(data: &Data) -> proc_macro2::TokenStream {
     }
 }
 
-fn read_named_fields(
-    named: &Punctuated<Field, Comma>,
-) -> (Vec<proc_macro2::TokenStream>, Vec<&Option<Ident>>) {
+fn read_named_fields<'a>(
+    named: &'a Punctuated<Field, Comma>,
+    type_name: &str,
+) -> (Vec<proc_macro2::TokenStream>, Vec<&'a Option<Ident>>) {
     let read_fields = named
         .iter()
         .map(|f| {
+            check_field_type(f);
             let field_name = &f.ident;
+            let field_name_str = field_name.as_ref().unwrap().to_string();
+            let field_name_str = field_name_str.trim_start_matches("r#");
 
-            let reader_call = get_reader_call(f);
+            let reader_call = get_reader_call(
+                f,
+                &format!("while reading field `{field_name_str}` of `{type_name}`"),
+            );
             quote! { let #field_name = #reader_call; }
         })
         .collect::<Vec<_>>();
@@ -161,17 +125,36 @@ fn read_named_fields(
     (read_fields, read_field_names)
 }
 
-fn read_unnamed_fields(unnamed: &Punctuated<Field, Comma>) -> Vec<proc_macro2::TokenStream> {
+fn read_unnamed_fields(
+    unnamed: &Punctuated<Field, Comma>,
+    type_name: &str,
+) -> Vec<proc_macro2::TokenStream> {
     unnamed
         .iter()
-        .map(|f| {
-            let reader_call = get_reader_call(f);
+        .enumerate()
+        .map(|(i, f)| {
+            check_field_type(f);
+            let reader_call =
+                get_reader_call(f, &format!("while reading field {i} of `{type_name}`"));
             quote! { #reader_call }
         })
         .collect::<Vec<_>>()
 }
 
-fn get_reader_call(f: &Field) -> proc_macro2::TokenStream {
+fn check_field_type(f: &Field) {
+    let field_type = &f.ty;
+    if !matches!(field_type, syn::Type::Path(_) | syn::Type::Array(_)) {
+        panic!(
+            "Error reading field {:?}: {}",
+            f.ident.clone(),
+            field_type.to_token_stream()
+        )
+    }
+}
+
+/// Get the code to read the field, which adds the given context to the error if
+/// it fails.
+fn get_reader_call(f: &Field, context: &str) -> proc_macro2::TokenStream {
     let is_variable_length = f
         .attrs
         .iter()
@@ -191,30 +174,24 @@ fn get_reader_call(f: &Field) -> proc_macro2::TokenStream {
         panic!("Fields cannot have both var and limit attributes");
     }
 
-    let field_type = &f.ty;
-
-    // do a different buf.write_* for each field depending on the type
-    // if it's a string, use buf.write_string
-    match field_type {
-        syn::Type::Path(_) | syn::Type::Array(_) => {
-            if is_variable_length {
-                quote! {
-                    azalea_buf::AzBufVar::azalea_read_var(buf)?
-                }
-            } else if let Some(limit) = limit {
-                quote! {
-                    azalea_buf::AzBufLimited::azalea_read_limited(buf, #limit)?
-                }
-            } else {
-                quote! {
-                    azalea_buf::AzBuf::azalea_read(buf)?
-                }
-            }
+    let read_call = if is_variable_length {
+        quote! {
+            azalea_buf::AzBufVar::azalea_read_var(buf)
         }
-        _ => panic!(
-            "Error reading field {:?}: {}",
-            f.ident.clone(),
-            field_type.to_token_stream()
-        ),
+    } else if let Some(limit) = limit {
+        quote! {
+            azalea_buf::AzBufLimited::azalea_read_limited(buf, #limit)
+        }
+    } else {
+        quote! {
+            azalea_buf::AzBuf::azalea_read(buf)
+        }
+    };
+
+    quote! {
+        #read_call.map_err(|e| azalea_buf::BufReadError::Context {
+            context: #context.to_owned(),
+            source: Box::new(e),
+        })?
     }
 }
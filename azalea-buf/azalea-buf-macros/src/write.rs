@@ -39,34 +39,8 @@ pub fn create_fn_azalea_write(data: &Data) -> proc_macro2::TokenStream {
             let mut is_data_enum = false;
             let mut match_arms = quote!();
             let mut match_arms_without_id = quote!();
-            let mut variant_discrim: u32 = 0;
-            let mut first = true;
-            for variant in variants {
-                match &variant.discriminant.as_ref() {
-                    Some(d) => {
-                        variant_discrim = match &d.1 {
-                            syn::Expr::Lit(e) => match &e.lit {
-                                syn::Lit::Int(i) => i.base10_parse().unwrap(),
-                                // syn::Lit::Str(s) => s.value(),
-                                _ => panic!("Error parsing enum discriminant as int (is {e:?})"),
-                            },
-                            syn::Expr::Unary(_) => {
-                                panic!("Negative enum discriminants are not supported")
-                            }
-                            _ => {
-                                panic!("Error parsing enum discriminant as literal (is {:?})", d.1)
-                            }
-                        }
-                    }
-                    None => {
-                        if first {
-                            first = false;
-                        } else {
-                            variant_discrim += 1;
-                        }
-                    }
-                }
-
+            let (variant_ids, has_id_attr) = crate::variant_ids(variants);
+            for (variant, variant_discrim) in variants.iter().zip(variant_ids) {
                 let variant_name = &variant.ident;
 
                 // the variant number that we're going to write
@@ -135,7 +109,7 @@ pub fn create_fn_azalea_write(data: &Data) -> proc_macro2::TokenStream {
                     }
                 }
             }
-            if is_data_enum {
+            if is_data_enum || has_id_attr {
                 quote! {
                     fn azalea_write(&self, buf: &mut impl std::io::Write) -> std::result::Result<(), std::io::Error> {
                         match self {
@@ -145,7 +119,7 @@ pub fn create_fn_azalea_write(data: &Data) -> proc_macro2::TokenStream {
                     }
                 }
             } else {
-                // optimization: if it doesn't have data we can just do `as u32`
+                // optimization: if it doesn't have data or custom ids we can just do `as u32`
                 quote! {
                     fn azalea_write(&self, buf: &mut impl std::io::Write) -> std::result::Result<(), std::io::Error> {
                         azalea_buf::AzBufVar::azalea_write_var(&(*self as u32), buf)
@@ -23,3 +23,89 @@ impl From<&str> for UnsizedByteArray {
         Self(s.as_bytes().to_vec())
     }
 }
+
+/// A `Vec<u8>` that's prefixed by a VarInt with the size.
+///
+/// This is encoded the same way as a `Vec<u8>`, but it's useful for making it
+/// clear that a field isn't an [`UnsizedByteArray`].
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct PrefixedByteArray(pub Vec<u8>);
+
+impl Deref for PrefixedByteArray {
+    type Target = [u8];
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl From<Vec<u8>> for PrefixedByteArray {
+    fn from(vec: Vec<u8>) -> Self {
+        Self(vec)
+    }
+}
+
+impl From<&str> for PrefixedByteArray {
+    fn from(s: &str) -> Self {
+        Self(s.as_bytes().to_vec())
+    }
+}
+
+/// One of two types, encoded with a leading bool that's `true` for [`Left`]
+/// and `false` for [`Right`].
+///
+/// This is for the common "bool-tagged union" pattern in the protocol, which
+/// is `ByteBufCodecs.either` in vanilla.
+///
+/// [`Left`]: Either::Left
+/// [`Right`]: Either::Right
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Either<L, R> {
+    Left(L),
+    Right(R),
+}
+
+/// Either an id into a registry or an inline value, encoded as a VarInt that's
+/// `0` for [`Inline`] (followed by the value) or the registry id plus one for
+/// [`Id`].
+///
+/// This is the encoding used by vanilla's `Holder` codec, for things like
+/// sound events. If the registry is known at compile time, you should
+/// usually use `azalea_registry::Holder` instead.
+///
+/// Writing an [`Id`] of `u32::MAX` fails, since there's no room for the plus
+/// one.
+///
+/// [`Inline`]: RegistryIdOr::Inline
+/// [`Id`]: RegistryIdOr::Id
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum RegistryIdOr<T> {
+    Id(u32),
+    Inline(T),
+}
+
+/// A signed integer that's encoded as a zigzag VarInt (or VarLong, for `i64`).
+///
+/// Zigzag encoding maps small negative numbers to small positive ones (`0 ->
+/// 0`, `-1 -> 1`, `1 -> 2`, `-2 -> 3`, etc.), so a number like `-1` takes one
+/// byte instead of the five that a regular VarInt would use.
+///
+/// Most of the protocol uses regular VarInts (with `#[var]`), so you should
+/// only use this for the fields that vanilla encodes with zigzag. Since the
+/// wrapper already determines the encoding, it doesn't need `#[var]`.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct ZigZag<T>(pub T);
+
+impl<T> Deref for ZigZag<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> From<T> for ZigZag<T> {
+    fn from(value: T) -> Self {
+        Self(value)
+    }
+}
@@ -3,13 +3,14 @@ use std::{
     hash::Hash,
     io::{self, Cursor, Write},
     sync::Arc,
+    time::{Duration, SystemTime, UNIX_EPOCH},
 };
 
 use indexmap::IndexMap;
 
 use crate::{
-    AzBuf, AzBufLimited, AzBufVar, BufReadError, MAX_STRING_LENGTH, UnsizedByteArray, read_bytes,
-    read_utf_with_len, write_utf_with_len,
+    AzBuf, AzBufLimited, AzBufVar, BufReadError, Either, MAX_STRING_LENGTH, PrefixedByteArray,
+    RegistryIdOr, UnsizedByteArray, ZigZag, read_bytes, read_utf_with_len, write_utf_with_len,
 };
 
 impl AzBuf for UnsizedByteArray {
@@ -24,6 +25,28 @@ impl AzBuf for UnsizedByteArray {
     }
 }
 
+impl AzBufLimited for UnsizedByteArray {
+    fn azalea_read_limited(buf: &mut Cursor<&[u8]>, limit: u32) -> Result<Self, BufReadError> {
+        let length = buf.get_ref().len() - buf.position() as usize;
+        if length > limit as usize {
+            return Err(BufReadError::VecLengthTooLong {
+                length: length.try_into().unwrap_or(u32::MAX),
+                max_length: limit,
+            });
+        }
+        Self::azalea_read(buf)
+    }
+}
+
+impl AzBuf for PrefixedByteArray {
+    fn azalea_read(buf: &mut Cursor<&[u8]>) -> Result<Self, BufReadError> {
+        Vec::<u8>::azalea_read(buf).map(PrefixedByteArray)
+    }
+    fn azalea_write(&self, buf: &mut impl Write) -> io::Result<()> {
+        self.0.azalea_write(buf)
+    }
+}
+
 macro_rules! impl_for_map_type {
     ($ty: ident) => {
         impl<K: AzBuf + Eq + Hash, V: AzBuf> AzBuf for $ty<K, V> {
@@ -137,6 +160,66 @@ impl_for_list_type!(Box<[T]>);
 // counters on the heap) and we'd like to avoid that. for us it's typically
 // better to do `Arc<Box<[T]>>`.
 
+/// Read a VarInt-prefixed list into a [`SmallVec`], without allocating if
+/// there are at most `N` items.
+///
+/// [`SmallVec`]: smallvec::SmallVec
+#[cfg(feature = "smallvec")]
+fn read_small_vec<T, const N: usize>(
+    buf: &mut Cursor<&[u8]>,
+    length: usize,
+    read_item: impl Fn(&mut Cursor<&[u8]>) -> Result<T, BufReadError>,
+) -> Result<smallvec::SmallVec<[T; N]>, BufReadError> {
+    // same capacity limit as for Vec
+    let mut contents = smallvec::SmallVec::with_capacity(usize::min(length, 65536));
+    for _ in 0..length {
+        contents.push(read_item(buf)?);
+    }
+    Ok(contents)
+}
+
+#[cfg(feature = "smallvec")]
+impl<T: AzBuf, const N: usize> AzBuf for smallvec::SmallVec<[T; N]> {
+    fn azalea_read(buf: &mut Cursor<&[u8]>) -> Result<Self, BufReadError> {
+        let length = u32::azalea_read_var(buf)? as usize;
+        read_small_vec(buf, length, T::azalea_read)
+    }
+    fn azalea_write(&self, buf: &mut impl Write) -> io::Result<()> {
+        (self.len() as u32).azalea_write_var(buf)?;
+        for item in self {
+            T::azalea_write(item, buf)?;
+        }
+        Ok(())
+    }
+}
+#[cfg(feature = "smallvec")]
+impl<T: AzBufVar, const N: usize> AzBufVar for smallvec::SmallVec<[T; N]> {
+    fn azalea_read_var(buf: &mut Cursor<&[u8]>) -> Result<Self, BufReadError> {
+        let length = u32::azalea_read_var(buf)? as usize;
+        read_small_vec(buf, length, T::azalea_read_var)
+    }
+    fn azalea_write_var(&self, buf: &mut impl Write) -> io::Result<()> {
+        (self.len() as u32).azalea_write_var(buf)?;
+        for item in self {
+            T::azalea_write_var(item, buf)?;
+        }
+        Ok(())
+    }
+}
+#[cfg(feature = "smallvec")]
+impl<T: AzBuf, const N: usize> AzBufLimited for smallvec::SmallVec<[T; N]> {
+    fn azalea_read_limited(buf: &mut Cursor<&[u8]>, limit: u32) -> Result<Self, BufReadError> {
+        let length = u32::azalea_read_var(buf)?;
+        if length > limit {
+            return Err(BufReadError::VecLengthTooLong {
+                length,
+                max_length: limit,
+            });
+        }
+        read_small_vec(buf, length as usize, T::azalea_read)
+    }
+}
+
 impl AzBuf for Vec<u8> {
     fn azalea_read(buf: &mut Cursor<&[u8]>) -> Result<Self, BufReadError> {
         let length = i32::azalea_read_var(buf)? as usize;
@@ -305,6 +388,75 @@ impl<A: AzBuf, B: AzBuf> AzBuf for (A, B) {
     }
 }
 
+impl<L: AzBuf, R: AzBuf> AzBuf for Either<L, R> {
+    fn azalea_read(buf: &mut Cursor<&[u8]>) -> Result<Self, BufReadError> {
+        Ok(if bool::azalea_read(buf)? {
+            Either::Left(L::azalea_read(buf)?)
+        } else {
+            Either::Right(R::azalea_read(buf)?)
+        })
+    }
+    fn azalea_write(&self, buf: &mut impl Write) -> io::Result<()> {
+        match self {
+            Either::Left(left) => {
+                true.azalea_write(buf)?;
+                left.azalea_write(buf)
+            }
+            Either::Right(right) => {
+                false.azalea_write(buf)?;
+                right.azalea_write(buf)
+            }
+        }
+    }
+}
+
+impl<T: AzBuf> AzBuf for RegistryIdOr<T> {
+    fn azalea_read(buf: &mut Cursor<&[u8]>) -> Result<Self, BufReadError> {
+        let id = u32::azalea_read_var(buf)?;
+        Ok(if id == 0 {
+            RegistryIdOr::Inline(T::azalea_read(buf)?)
+        } else {
+            RegistryIdOr::Id(id - 1)
+        })
+    }
+    fn azalea_write(&self, buf: &mut impl Write) -> io::Result<()> {
+        match self {
+            RegistryIdOr::Id(id) => id
+                .checked_add(1)
+                .ok_or_else(|| {
+                    io::Error::new(
+                        io::ErrorKind::InvalidInput,
+                        "registry id is too large to be written",
+                    )
+                })?
+                .azalea_write_var(buf),
+            RegistryIdOr::Inline(value) => {
+                0u32.azalea_write_var(buf)?;
+                value.azalea_write(buf)
+            }
+        }
+    }
+}
+
+impl AzBuf for ZigZag<i32> {
+    fn azalea_read(buf: &mut Cursor<&[u8]>) -> Result<Self, BufReadError> {
+        let value = u32::azalea_read_var(buf)?;
+        Ok(ZigZag((value >> 1) as i32 ^ -((value & 1) as i32)))
+    }
+    fn azalea_write(&self, buf: &mut impl Write) -> io::Result<()> {
+        (((self.0 << 1) ^ (self.0 >> 31)) as u32).azalea_write_var(buf)
+    }
+}
+impl AzBuf for ZigZag<i64> {
+    fn azalea_read(buf: &mut Cursor<&[u8]>) -> Result<Self, BufReadError> {
+        let value = u64::azalea_read_var(buf)?;
+        Ok(ZigZag((value >> 1) as i64 ^ -((value & 1) as i64)))
+    }
+    fn azalea_write(&self, buf: &mut impl Write) -> io::Result<()> {
+        (((self.0 << 1) ^ (self.0 >> 63)) as u64).azalea_write_var(buf)
+    }
+}
+
 impl<T: AzBuf> AzBuf for Arc<T> {
     fn azalea_read(buf: &mut Cursor<&[u8]>) -> Result<Self, BufReadError> {
         Ok(Arc::new(T::azalea_read(buf)?))
@@ -313,3 +465,33 @@ impl<T: AzBuf> AzBuf for Arc<T> {
         T::azalea_write(&**self, buf)
     }
 }
+
+/// Timestamps are encoded as the number of milliseconds since the Unix epoch,
+/// as an `i64`.
+///
+/// Times that are too far from the epoch to fit in an `i64` are clamped when
+/// they're written.
+impl AzBuf for SystemTime {
+    fn azalea_read(buf: &mut Cursor<&[u8]>) -> Result<Self, BufReadError> {
+        let millis = i64::azalea_read(buf)?;
+        let offset = Duration::from_millis(millis.unsigned_abs());
+        let time = if millis >= 0 {
+            UNIX_EPOCH.checked_add(offset)
+        } else {
+            UNIX_EPOCH.checked_sub(offset)
+        };
+        time.ok_or_else(|| BufReadError::Custom(format!("Timestamp {millis} can't be represented")))
+    }
+    fn azalea_write(&self, buf: &mut impl Write) -> io::Result<()> {
+        let millis = match self.duration_since(UNIX_EPOCH) {
+            Ok(after_epoch) => i64::try_from(after_epoch.as_millis()).unwrap_or(i64::MAX),
+            Err(err) => {
+                // the time is before the epoch
+                i64::try_from(err.duration().as_millis())
+                    .map(|millis| -millis)
+                    .unwrap_or(i64::MIN)
+            }
+        };
+        millis.azalea_write(buf)
+    }
+}
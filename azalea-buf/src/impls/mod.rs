@@ -3,10 +3,12 @@ mod primitives;
 
 use std::{
     backtrace::Backtrace,
+    borrow::Cow,
     io::{self, Cursor, Write},
 };
 
 use thiserror::Error;
+use tracing::warn;
 
 /// A trait that's implemented on types that are used by the Minecraft protocol.
 pub trait AzBuf
@@ -80,6 +82,13 @@ pub enum BufReadError {
     },
     #[error("{0}")]
     Custom(String),
+    /// Another error that happened while reading a specific field, usually
+    /// added by `#[derive(AzBuf)]`.
+    #[error("{context}: {source}")]
+    Context {
+        context: String,
+        source: Box<BufReadError>,
+    },
     #[cfg(feature = "serde_json")]
     #[error("{source}")]
     Deserialization {
@@ -101,14 +110,46 @@ pub enum BufReadError {
     },
 }
 
+impl BufReadError {
+    /// Get the innermost error, skipping any [`BufReadError::Context`]
+    /// wrappers.
+    pub fn root_cause(&self) -> &BufReadError {
+        match self {
+            BufReadError::Context { source, .. } => source.root_cause(),
+            _ => self,
+        }
+    }
+}
+
+/// Get the number of bytes that haven't been read yet from the buffer.
+pub fn remaining(buf: &Cursor<&[u8]>) -> usize {
+    buf.get_ref().len().saturating_sub(buf.position() as usize)
+}
+
+/// Get the next byte in the buffer without advancing its position.
+///
+/// This is useful for manual decoders that need to decide how to read
+/// something based on an upcoming tag or discriminant. A
+/// [`BufReadError::UnexpectedEof`] is returned if there are no bytes left.
+pub fn peek_u8(buf: &Cursor<&[u8]>) -> Result<u8, BufReadError> {
+    buf.get_ref()
+        .get(buf.position() as usize)
+        .copied()
+        .ok_or_else(|| BufReadError::UnexpectedEof {
+            attempted_read: 1,
+            actual_read: 0,
+            backtrace: Backtrace::capture(),
+        })
+}
+
 pub(crate) fn read_bytes<'a>(
     buf: &'a mut Cursor<&[u8]>,
     length: usize,
 ) -> Result<&'a [u8], BufReadError> {
-    if length > (buf.get_ref().len() - buf.position() as usize) {
+    if length > remaining(buf) {
         return Err(BufReadError::UnexpectedEof {
             attempted_read: length,
-            actual_read: buf.get_ref().len() - buf.position() as usize,
+            actual_read: remaining(buf),
             backtrace: Backtrace::capture(),
         });
     }
@@ -118,10 +159,10 @@ pub(crate) fn read_bytes<'a>(
     Ok(data)
 }
 
-pub(crate) fn read_utf_with_len<'a>(
+fn read_utf_bytes_with_len<'a>(
     buf: &'a mut Cursor<&[u8]>,
     max_length: u32,
-) -> Result<&'a str, BufReadError> {
+) -> Result<&'a [u8], BufReadError> {
     let length = u32::azalea_read_var(buf)?;
     // i don't know why it's multiplied by 4 but it's like that in mojang's code so
     if length > max_length * 4 {
@@ -131,19 +172,138 @@ pub(crate) fn read_utf_with_len<'a>(
         });
     }
 
-    let buffer = read_bytes(buf, length as usize)?;
+    read_bytes(buf, length as usize)
+}
+
+pub(crate) fn read_utf_with_len<'a>(
+    buf: &'a mut Cursor<&[u8]>,
+    max_length: u32,
+) -> Result<&'a str, BufReadError> {
+    let buffer = read_utf_bytes_with_len(buf, max_length)?;
     let string = std::str::from_utf8(buffer).map_err(|_| BufReadError::InvalidUtf8 {
         bytes: buffer.to_vec(),
         lossy: String::from_utf8_lossy(buffer).to_string(),
         // backtrace: Backtrace::capture(),
     })?;
-    if string.len() > length as usize {
-        return Err(BufReadError::StringLengthTooLong { length, max_length });
-    }
+    check_utf_char_length(string, max_length)?;
 
     Ok(string)
 }
 
+/// Make sure that the string has at most `max_length` characters.
+///
+/// Like in vanilla, the characters are counted as UTF-16 code units.
+fn check_utf_char_length(string: &str, max_length: u32) -> Result<(), BufReadError> {
+    let length = string.encode_utf16().count();
+    if length > max_length as usize {
+        return Err(BufReadError::StringLengthTooLong {
+            length: length as u32,
+            max_length,
+        });
+    }
+    Ok(())
+}
+
+/// Read a length-prefixed string, replacing invalid UTF-8 with
+/// [`char::REPLACEMENT_CHARACTER`] instead of erroring.
+///
+/// This is a more lenient alternative to [`String::azalea_read_limited`], for
+/// when a slightly malformed string shouldn't make the entire packet fail to
+/// decode. A warning is logged if the string wasn't valid UTF-8.
+pub fn read_utf_lossy(buf: &mut Cursor<&[u8]>, max_length: u32) -> Result<String, BufReadError> {
+    let buffer = read_utf_bytes_with_len(buf, max_length)?;
+    match String::from_utf8_lossy(buffer) {
+        Cow::Borrowed(string) => Ok(string.to_owned()),
+        Cow::Owned(string) => {
+            warn!("String was not valid UTF-8, read it as {string:?}");
+            Ok(string)
+        }
+    }
+}
+
+/// Read a VarInt-prefixed array of longs, and unpack `count` entries of
+/// `bits_per_entry` bits each from it.
+///
+/// This is the format used for chunk section data since 1.16, where entries
+/// never span across two longs, so any leftover high bits in each long are
+/// unused. The first entry is stored in the lowest bits of the first long.
+///
+/// If `bits_per_entry` is 0, then the array is expected to be empty and every
+/// entry will be 0.
+pub fn read_bit_packed(
+    buf: &mut Cursor<&[u8]>,
+    bits_per_entry: u8,
+    count: usize,
+) -> Result<Vec<u32>, BufReadError> {
+    if bits_per_entry > 32 {
+        return Err(BufReadError::Custom(format!(
+            "Bits per entry must be at most 32, but was {bits_per_entry}"
+        )));
+    }
+
+    let length = u32::azalea_read_var(buf)? as usize;
+    if bits_per_entry == 0 {
+        for _ in 0..length {
+            u64::azalea_read(buf)?;
+        }
+        return Ok(vec![0; count]);
+    }
+
+    let entries_per_long = 64 / bits_per_entry as usize;
+    let expected_length = count.div_ceil(entries_per_long);
+    if length < expected_length {
+        return Err(BufReadError::Custom(format!(
+            "Expected at least {expected_length} longs for {count} entries of {bits_per_entry} bits, but got {length}"
+        )));
+    }
+
+    let mask = (1u64 << bits_per_entry) - 1;
+    let mut entries = Vec::with_capacity(count);
+    for _ in 0..length {
+        let long = u64::azalea_read(buf)?;
+        for i in 0..entries_per_long {
+            if entries.len() == count {
+                break;
+            }
+            entries.push(((long >> (i * bits_per_entry as usize)) & mask) as u32);
+        }
+    }
+    Ok(entries)
+}
+
+/// Read an optional NBT compound that's absent if it starts with an end tag,
+/// like the item data from before 1.20.2.
+///
+/// Unlike the modern network format, the root compound here has a name
+/// (which is usually empty and is discarded). `None` is returned if the
+/// first byte is a `TAG_End`, which is also consumed.
+pub fn read_optional_nbt(
+    buf: &mut Cursor<&[u8]>,
+) -> Result<Option<simdnbt::owned::NbtCompound>, BufReadError> {
+    Ok(match simdnbt::owned::read(buf)? {
+        simdnbt::owned::Nbt::Some(nbt) => Some(nbt.as_compound()),
+        simdnbt::owned::Nbt::None => None,
+    })
+}
+
+/// Write an optional NBT compound in the format read by
+/// [`read_optional_nbt`].
+///
+/// The root compound is written with an empty name, and `None` is written as
+/// a single `TAG_End`.
+pub fn write_optional_nbt(
+    buf: &mut impl Write,
+    nbt: Option<&simdnbt::owned::NbtCompound>,
+) -> io::Result<()> {
+    let nbt = match nbt {
+        Some(compound) => simdnbt::owned::Nbt::new("".into(), compound.clone()),
+        None => simdnbt::owned::Nbt::None,
+    };
+    let mut data = Vec::new();
+    nbt.write(&mut data);
+    buf.write_all(&data)
+}
+
 pub(crate) fn write_utf_with_len(
     buf: &mut impl Write,
     string: &str,
@@ -31,6 +31,11 @@ impl AzBufVar for i32 {
         let mut ans = 0;
         for i in 0..5 {
             buf.read_exact(&mut buffer)?;
+            // the last byte can only have 4 bits of data (7 * 4 + 4 = 32), and must not
+            // have the continuation bit set
+            if i == 4 && buffer[0] & 0b1111_0000 != 0 {
+                return Err(BufReadError::InvalidVarInt);
+            }
             ans |= ((buffer[0] & 0b0111_1111) as i32) << (7 * i);
             if buffer[0] & 0b1000_0000 == 0 {
                 break;
@@ -64,6 +69,10 @@ impl AzBufVar for i64 {
         for i in 0..10 {
             buf.read_exact(&mut buffer)
                 .map_err(|_| BufReadError::InvalidVarLong)?;
+            // the last byte can only have 1 bit of data (7 * 9 + 1 = 64)
+            if i == 9 && buffer[0] & 0b1111_1110 != 0 {
+                return Err(BufReadError::InvalidVarLong);
+            }
             ans |= ((buffer[0] & 0b0111_1111) as i64) << (7 * i);
             if buffer[0] & 0b1000_0000 == 0 {
                 break;
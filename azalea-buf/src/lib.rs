@@ -142,6 +142,43 @@ mod tests {
         assert_eq!(i32::azalea_read_var(&mut Cursor::new(&buf)).unwrap(), 7178);
     }
 
+    #[test]
+    fn test_read_varint_overlong() {
+        // continuation bit set on the 5th byte
+        let buf = vec![255, 255, 255, 255, 255, 1];
+        assert!(matches!(
+            i32::azalea_read_var(&mut Cursor::new(&buf)),
+            Err(BufReadError::InvalidVarInt)
+        ));
+        // data bits set that don't fit in an i32
+        let buf = vec![128, 128, 128, 128, 16];
+        assert!(matches!(
+            i32::azalea_read_var(&mut Cursor::new(&buf)),
+            Err(BufReadError::InvalidVarInt)
+        ));
+        assert!(matches!(
+            u32::azalea_read_var(&mut Cursor::new(&buf)),
+            Err(BufReadError::InvalidVarInt)
+        ));
+    }
+
+    #[test]
+    fn test_read_varlong_overlong() {
+        let buf = vec![255, 255, 255, 255, 255, 255, 255, 255, 255, 1];
+        assert_eq!(i64::azalea_read_var(&mut Cursor::new(&buf)).unwrap(), -1);
+
+        let buf = vec![255, 255, 255, 255, 255, 255, 255, 255, 255, 2];
+        assert!(matches!(
+            i64::azalea_read_var(&mut Cursor::new(&buf)),
+            Err(BufReadError::InvalidVarLong)
+        ));
+        let buf = vec![255, 255, 255, 255, 255, 255, 255, 255, 255, 129, 0];
+        assert!(matches!(
+            i64::azalea_read_var(&mut Cursor::new(&buf)),
+            Err(BufReadError::InvalidVarLong)
+        ));
+    }
+
     #[test]
     fn test_write_varlong() {
         let mut buf = Vec::new();
@@ -194,6 +231,64 @@ mod tests {
         assert_eq!(result, original_map);
     }
 
+    #[test]
+    fn test_index_map_preserves_order() {
+        let original_map = indexmap::IndexMap::from([
+            ("zz".to_owned(), 1),
+            ("a".to_owned(), 23),
+            ("mmm".to_owned(), 456),
+        ]);
+        let mut buf = Vec::new();
+        original_map.azalea_write(&mut buf).unwrap();
+
+        let result =
+            indexmap::IndexMap::<String, i32>::azalea_read(&mut Cursor::new(&buf)).unwrap();
+
+        assert_eq!(
+            result.keys().collect::<Vec<_>>(),
+            original_map.keys().collect::<Vec<_>>()
+        );
+        assert_eq!(result.get_index_of("a"), Some(1));
+    }
+
+    #[test]
+    fn test_read_utf_lossy() {
+        let buf = vec![4, b'a', 0xff, b'b', b'c'];
+        assert!(matches!(
+            String::azalea_read(&mut Cursor::new(&buf)),
+            Err(BufReadError::InvalidUtf8 { .. })
+        ));
+        assert_eq!(
+            read_utf_lossy(&mut Cursor::new(&buf), MAX_STRING_LENGTH).unwrap(),
+            "a\u{FFFD}bc"
+        );
+
+        let mut buf = Vec::new();
+        "valid".to_owned().azalea_write(&mut buf).unwrap();
+        assert_eq!(
+            read_utf_lossy(&mut Cursor::new(&buf), MAX_STRING_LENGTH).unwrap(),
+            "valid"
+        );
+    }
+
+    #[test]
+    fn test_read_string_character_limit() {
+        let mut buf = Vec::new();
+        "é".repeat(17).azalea_write(&mut buf).unwrap();
+        // 34 bytes is within the byte limit of 16 * 4, but it's 17 characters
+        assert!(matches!(
+            String::azalea_read_limited(&mut Cursor::new(&buf), 16),
+            Err(BufReadError::StringLengthTooLong {
+                length: 17,
+                max_length: 16
+            })
+        ));
+        assert_eq!(
+            String::azalea_read_limited(&mut Cursor::new(&buf), 17).unwrap(),
+            "é".repeat(17)
+        );
+    }
+
     #[test]
     fn test_long() {
         let mut buf: Vec<u8> = Vec::new();
@@ -201,4 +296,293 @@ mod tests {
 
         assert_eq!(u64::azalea_read(&mut Cursor::new(&buf)).unwrap(), 123456);
     }
+
+    fn write_bit_packed(values: &[u64], bits_per_entry: usize) -> Vec<u8> {
+        let entries_per_long = 64 / bits_per_entry;
+        let longs = values
+            .chunks(entries_per_long)
+            .map(|chunk| {
+                chunk
+                    .iter()
+                    .enumerate()
+                    .fold(0u64, |long, (i, v)| long | (v << (i * bits_per_entry)))
+            })
+            .collect::<Vec<u64>>();
+        let mut buf = Vec::new();
+        longs.azalea_write(&mut buf).unwrap();
+        buf
+    }
+
+    #[test]
+    fn test_prefixed_byte_array() {
+        let array = PrefixedByteArray::from(vec![1, 2, 3]);
+        let mut buf = Vec::new();
+        array.azalea_write(&mut buf).unwrap();
+        assert_eq!(buf, [3, 1, 2, 3]);
+        let mut cursor = Cursor::new(&buf[..]);
+        assert_eq!(PrefixedByteArray::azalea_read(&mut cursor).unwrap(), array);
+        assert_eq!(cursor.position(), 4);
+
+        // unlike UnsizedByteArray, it stops at the end of the array
+        buf.push(0xff);
+        let mut cursor = Cursor::new(&buf[..]);
+        assert_eq!(PrefixedByteArray::azalea_read(&mut cursor).unwrap(), array);
+        assert_eq!(cursor.position(), 4);
+
+        let empty = PrefixedByteArray::default();
+        let mut buf = Vec::new();
+        empty.azalea_write(&mut buf).unwrap();
+        assert_eq!(buf, [0]);
+        assert_eq!(
+            PrefixedByteArray::azalea_read(&mut Cursor::new(&buf[..])).unwrap(),
+            empty
+        );
+
+        // optional arrays are prefixed by a bool
+        let optional = Some(array.clone());
+        let mut buf = Vec::new();
+        optional.azalea_write(&mut buf).unwrap();
+        assert_eq!(buf, [1, 3, 1, 2, 3]);
+        assert_eq!(
+            Option::<PrefixedByteArray>::azalea_read(&mut Cursor::new(&buf[..])).unwrap(),
+            optional
+        );
+    }
+
+    #[test]
+    fn test_either() {
+        let left = Either::<u8, String>::Left(5);
+        let mut buf = Vec::new();
+        left.azalea_write(&mut buf).unwrap();
+        assert_eq!(buf, [1, 5]);
+        assert_eq!(
+            Either::<u8, String>::azalea_read(&mut Cursor::new(&buf[..])).unwrap(),
+            left
+        );
+
+        let right = Either::<u8, String>::Right("hi".to_owned());
+        let mut buf = Vec::new();
+        right.azalea_write(&mut buf).unwrap();
+        assert_eq!(buf, [0, 2, b'h', b'i']);
+        assert_eq!(
+            Either::<u8, String>::azalea_read(&mut Cursor::new(&buf[..])).unwrap(),
+            right
+        );
+    }
+
+    #[test]
+    fn test_registry_id_or() {
+        let id = RegistryIdOr::<String>::Id(5);
+        let mut buf = Vec::new();
+        id.azalea_write(&mut buf).unwrap();
+        assert_eq!(buf, [6]);
+        assert_eq!(
+            RegistryIdOr::<String>::azalea_read(&mut Cursor::new(&buf[..])).unwrap(),
+            id
+        );
+
+        let inline = RegistryIdOr::Inline("hi".to_owned());
+        let mut buf = Vec::new();
+        inline.azalea_write(&mut buf).unwrap();
+        assert_eq!(buf, [0, 2, b'h', b'i']);
+        assert_eq!(
+            RegistryIdOr::<String>::azalea_read(&mut Cursor::new(&buf[..])).unwrap(),
+            inline
+        );
+
+        assert!(
+            RegistryIdOr::<String>::Id(u32::MAX)
+                .azalea_write(&mut Vec::new())
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_read_bit_packed() {
+        for (bits_per_entry, max) in [(4, 15), (5, 31), (15, 32767)] {
+            let values = (0..100u64).map(|i| (i * 7) % (max + 1)).collect::<Vec<_>>();
+            let buf = write_bit_packed(&values, bits_per_entry);
+            let read = read_bit_packed(&mut Cursor::new(&buf), bits_per_entry as u8, values.len())
+                .unwrap();
+            assert_eq!(
+                read,
+                values.iter().map(|&v| v as u32).collect::<Vec<_>>(),
+                "bits_per_entry = {bits_per_entry}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_read_bit_packed_known_layout() {
+        // 5 bits per entry means 12 entries per long, and the top 4 bits are unused
+        let long: u64 = 0b1111 << 60 | 0b00011 << 5 | 0b00001;
+        let mut buf = Vec::new();
+        vec![long].azalea_write(&mut buf).unwrap();
+        let read = read_bit_packed(&mut Cursor::new(&buf), 5, 12).unwrap();
+        assert_eq!(read, [1, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+
+        // not enough data for the number of entries
+        let mut buf = Vec::new();
+        vec![long].azalea_write(&mut buf).unwrap();
+        assert!(read_bit_packed(&mut Cursor::new(&buf), 5, 13).is_err());
+    }
+
+    #[cfg(feature = "smallvec")]
+    #[test]
+    fn test_small_vec() {
+        use smallvec::{SmallVec, smallvec};
+
+        // fits inline
+        let list: SmallVec<[u32; 4]> = smallvec![1, 2, 3];
+        let mut buf = Vec::new();
+        list.azalea_write_var(&mut buf).unwrap();
+        // should be encoded the same way as a Vec
+        let mut vec_buf = Vec::new();
+        vec![1u32, 2, 3].azalea_write_var(&mut vec_buf).unwrap();
+        assert_eq!(buf, vec_buf);
+        let read = SmallVec::<[u32; 4]>::azalea_read_var(&mut Cursor::new(&buf)).unwrap();
+        assert_eq!(read, list);
+        assert!(!read.spilled());
+
+        // spills to the heap
+        let list: SmallVec<[String; 2]> = (0..5).map(|i| i.to_string()).collect();
+        let mut buf = Vec::new();
+        list.azalea_write(&mut buf).unwrap();
+        let read = SmallVec::<[String; 2]>::azalea_read(&mut Cursor::new(&buf)).unwrap();
+        assert_eq!(read, list);
+        assert!(read.spilled());
+
+        assert!(SmallVec::<[String; 2]>::azalea_read_limited(&mut Cursor::new(&buf), 4).is_err());
+    }
+
+    #[test]
+    fn test_zigzag() {
+        for (value, encoded) in [
+            (0, vec![0]),
+            (-1, vec![1]),
+            (1, vec![2]),
+            (-2, vec![3]),
+            (-64, vec![127]),
+            (64, vec![128, 1]),
+        ] {
+            let mut buf = Vec::new();
+            ZigZag(value).azalea_write(&mut buf).unwrap();
+            assert_eq!(buf, encoded, "{value}");
+            assert_eq!(
+                ZigZag::<i32>::azalea_read(&mut Cursor::new(&buf)).unwrap(),
+                ZigZag(value)
+            );
+
+            let mut buf = Vec::new();
+            ZigZag(value as i64).azalea_write(&mut buf).unwrap();
+            assert_eq!(buf, encoded, "{value}");
+            assert_eq!(
+                ZigZag::<i64>::azalea_read(&mut Cursor::new(&buf)).unwrap(),
+                ZigZag(value as i64)
+            );
+        }
+
+        for value in [i32::MIN, i32::MAX] {
+            let mut buf = Vec::new();
+            ZigZag(value).azalea_write(&mut buf).unwrap();
+            assert_eq!(buf.len(), 5);
+            assert_eq!(
+                ZigZag::<i32>::azalea_read(&mut Cursor::new(&buf)).unwrap(),
+                ZigZag(value)
+            );
+        }
+        for value in [i64::MIN, i64::MAX] {
+            let mut buf = Vec::new();
+            ZigZag(value).azalea_write(&mut buf).unwrap();
+            assert_eq!(buf.len(), 10);
+            assert_eq!(
+                ZigZag::<i64>::azalea_read(&mut Cursor::new(&buf)).unwrap(),
+                ZigZag(value)
+            );
+        }
+    }
+
+    #[test]
+    fn test_optional_nbt() {
+        let mut compound = simdnbt::owned::NbtCompound::new();
+        compound.insert("Damage", 3);
+
+        let mut buf = Vec::new();
+        write_optional_nbt(&mut buf, Some(&compound)).unwrap();
+        // compound tag id, then an empty name
+        assert_eq!(buf[..3], [10, 0, 0]);
+        let mut buf = Cursor::new(&buf[..]);
+        assert_eq!(read_optional_nbt(&mut buf).unwrap(), Some(compound));
+        assert_eq!(remaining(&buf), 0);
+
+        let mut buf = Vec::new();
+        write_optional_nbt(&mut buf, None).unwrap();
+        assert_eq!(buf, [0]);
+        let data = [0, 42];
+        let mut buf = Cursor::new(&data[..]);
+        assert_eq!(read_optional_nbt(&mut buf).unwrap(), None);
+        assert_eq!(u8::azalea_read(&mut buf).unwrap(), 42);
+
+        assert!(read_optional_nbt(&mut Cursor::new(&[8][..])).is_err());
+    }
+
+    #[test]
+    fn test_peek_u8() {
+        let data = [1, 2];
+        let mut buf = Cursor::new(&data[..]);
+        assert_eq!(remaining(&buf), 2);
+        assert_eq!(peek_u8(&buf).unwrap(), 1);
+        assert_eq!(peek_u8(&buf).unwrap(), 1);
+        assert_eq!(buf.position(), 0);
+
+        assert_eq!(u8::azalea_read(&mut buf).unwrap(), 1);
+        assert_eq!(peek_u8(&buf).unwrap(), 2);
+        assert_eq!(remaining(&buf), 1);
+
+        assert_eq!(u8::azalea_read(&mut buf).unwrap(), 2);
+        assert_eq!(remaining(&buf), 0);
+        assert!(matches!(
+            peek_u8(&buf),
+            Err(BufReadError::UnexpectedEof { .. })
+        ));
+        assert_eq!(buf.position(), 2);
+
+        // a position past the end shouldn't underflow
+        buf.set_position(10);
+        assert_eq!(remaining(&buf), 0);
+        assert!(peek_u8(&buf).is_err());
+    }
+
+    #[test]
+    fn test_system_time() {
+        use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+        let time = UNIX_EPOCH + Duration::from_millis(1_700_000_000_123);
+        let mut buf = Vec::new();
+        time.azalea_write(&mut buf).unwrap();
+        assert_eq!(buf, 1_700_000_000_123i64.to_be_bytes());
+        assert_eq!(
+            SystemTime::azalea_read(&mut Cursor::new(&buf)).unwrap(),
+            time
+        );
+
+        // sub-millisecond precision is truncated
+        let mut buf = Vec::new();
+        (time + Duration::from_micros(999))
+            .azalea_write(&mut buf)
+            .unwrap();
+        assert_eq!(
+            SystemTime::azalea_read(&mut Cursor::new(&buf)).unwrap(),
+            time
+        );
+
+        let before_epoch = UNIX_EPOCH - Duration::from_millis(1500);
+        let mut buf = Vec::new();
+        before_epoch.azalea_write(&mut buf).unwrap();
+        assert_eq!(buf, (-1500i64).to_be_bytes());
+        assert_eq!(
+            SystemTime::azalea_read(&mut Cursor::new(&buf)).unwrap(),
+            before_epoch
+        );
+    }
 }
@@ -0,0 +1,52 @@
+use std::io::Cursor;
+
+use azalea_buf::AzBuf;
+
+#[derive(AzBuf, Clone, Copy, Debug, PartialEq, Eq)]
+enum ExplicitIds {
+    #[az_buf(id = 5)]
+    A,
+    #[az_buf(id = 2)]
+    B,
+    // continues from the previous id
+    C,
+    #[az_buf(id = -1)]
+    D,
+}
+
+#[derive(AzBuf, Clone, Debug, PartialEq, Eq)]
+enum ExplicitIdsWithData {
+    #[az_buf(id = 7)]
+    Named {
+        value: u8,
+    },
+    #[az_buf(id = 1)]
+    Unnamed(#[var] u32),
+    Unit,
+}
+
+fn roundtrip<T: AzBuf + PartialEq + std::fmt::Debug>(value: &T) -> Vec<u8> {
+    let mut buf = Vec::new();
+    value.azalea_write(&mut buf).unwrap();
+    let read = T::azalea_read(&mut Cursor::new(&buf[..])).unwrap();
+    assert_eq!(&read, value);
+    buf
+}
+
+#[test]
+fn test_explicit_variant_ids() {
+    assert_eq!(roundtrip(&ExplicitIds::A), [5]);
+    assert_eq!(roundtrip(&ExplicitIds::B), [2]);
+    assert_eq!(roundtrip(&ExplicitIds::C), [3]);
+    assert_eq!(roundtrip(&ExplicitIds::D), [0xff, 0xff, 0xff, 0xff, 0x0f]);
+}
+
+#[test]
+fn test_explicit_variant_ids_with_data() {
+    assert_eq!(roundtrip(&ExplicitIdsWithData::Named { value: 9 }), [7, 9]);
+    assert_eq!(
+        roundtrip(&ExplicitIdsWithData::Unnamed(300)),
+        [1, 0xac, 0x02]
+    );
+    assert_eq!(roundtrip(&ExplicitIdsWithData::Unit), [2]);
+}
@@ -1,6 +1,7 @@
 #[cfg(all(feature = "azalea-buf", feature = "simdnbt"))]
 use std::io::{self, Cursor, Write};
 use std::{
+    cell::Cell,
     fmt::{self, Display},
     sync::LazyLock,
 };
@@ -11,7 +12,7 @@ use serde::{Deserialize, Deserializer, Serialize, de};
 
 use crate::{
     base_component::BaseComponent,
-    style::{ChatFormatting, Style},
+    style::{Ansi, ChatFormatting, Style},
     text_component::TextComponent,
     translatable_component::{PrimitiveOrComponent, TranslatableComponent},
 };
@@ -241,6 +242,93 @@ impl FormattedText {
         self.to_ansi_with_custom_style(&DEFAULT_STYLE)
     }
 
+    /// Similar to [`Self::to_ansi`], but obfuscated text is replaced with
+    /// characters from `random_char` instead of being concealed.
+    ///
+    /// This mimics how Minecraft renders obfuscated text, so you'll usually
+    /// want `random_char` to return a random printable ASCII character (like
+    /// `rng.random_range('!'..='~')` with the `rand` crate), and to call this
+    /// again every frame. Whitespace is left as-is, and every other character
+    /// is replaced with exactly one character from `random_char` so the layout
+    /// stays the same.
+    ///
+    /// ```rust
+    /// use azalea_chat::FormattedText;
+    /// use serde::de::Deserialize;
+    ///
+    /// let component = FormattedText::deserialize(&serde_json::json!({
+    ///    "text": "secret",
+    ///    "obfuscated": true,
+    /// })).unwrap();
+    ///
+    /// let mut chars = "#$%&".chars().cycle();
+    /// println!("{}", component.to_ansi_obfuscated(|| chars.next().unwrap()));
+    /// ```
+    pub fn to_ansi_obfuscated(&self, mut random_char: impl FnMut() -> char) -> String {
+        let obfuscated = Cell::new(false);
+        self.to_custom_format(
+            |running, new| {
+                obfuscated.set(new.obfuscated.unwrap_or_default());
+                // we do the obfuscation ourselves, so don't let the terminal hide the text
+                (
+                    running.compare_ansi(new).replace(Ansi::OBFUSCATED, ""),
+                    "".to_owned(),
+                )
+            },
+            |text| {
+                if !obfuscated.get() {
+                    return text.to_owned();
+                }
+                text.chars()
+                    .map(|c| if c.is_whitespace() { c } else { random_char() })
+                    .collect()
+            },
+            |style| if !style.is_empty() { Ansi::RESET } else { "" }.to_owned(),
+            &DEFAULT_STYLE,
+        )
+    }
+
+    /// Get the width of this component in pixels, as it would be rendered with
+    /// Minecraft's default font.
+    ///
+    /// Bold text is one pixel wider per character (except for spaces), like in
+    /// vanilla. Characters that aren't printable ASCII are assumed to be 6
+    /// pixels wide, since the exact width depends on the resource pack.
+    ///
+    /// ```rust
+    /// use azalea_chat::FormattedText;
+    ///
+    /// assert_eq!(FormattedText::from("Hi").width(), 8);
+    /// ```
+    pub fn width(&self) -> u32 {
+        let bold = Cell::new(false);
+        let width = Cell::new(0);
+        self.to_custom_format(
+            |_, new| {
+                bold.set(new.bold.unwrap_or_default());
+                ("".to_owned(), "".to_owned())
+            },
+            |text| {
+                let text_width = text
+                    .chars()
+                    .map(|c| {
+                        let char_width = default_font_char_width(c);
+                        if bold.get() && c != ' ' {
+                            char_width + 1
+                        } else {
+                            char_width
+                        }
+                    })
+                    .sum::<u32>();
+                width.set(width.get() + text_width);
+                "".to_owned()
+            },
+            |_| "".to_owned(),
+            &DEFAULT_STYLE,
+        );
+        width.get()
+    }
+
     /// Similar to [`Self::to_ansi`] but renders the result as HTML instead.
     pub fn to_html(&self) -> String {
         self.to_custom_format(
@@ -266,6 +354,25 @@ impl FormattedText {
     }
 }
 
+/// The horizontal advance of every printable ASCII character (from `' '` to
+/// `'~'`) in Minecraft's default font, including the one pixel of spacing after
+/// each character.
+const DEFAULT_FONT_ASCII_WIDTHS: [u8; 95] = [
+    4, 2, 5, 6, 6, 6, 6, 3, 5, 5, 5, 6, 2, 6, 2, 6, // ' ' to '/'
+    6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 2, 2, 5, 6, 5, 6, // '0' to '?'
+    7, 6, 6, 6, 6, 6, 6, 6, 6, 4, 6, 6, 6, 6, 6, 6, // '@' to 'O'
+    6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 4, 6, 4, 6, 6, // 'P' to '_'
+    3, 6, 6, 6, 6, 6, 5, 6, 6, 2, 6, 5, 3, 6, 6, 6, // '`' to 'o'
+    6, 6, 6, 6, 4, 6, 6, 6, 6, 6, 6, 5, 2, 5, 7, // 'p' to '~'
+];
+
+fn default_font_char_width(c: char) -> u32 {
+    match c {
+        ' '..='~' => DEFAULT_FONT_ASCII_WIDTHS[c as usize - ' ' as usize] as u32,
+        _ => 6,
+    }
+}
+
 impl IntoIterator for FormattedText {
     type Item = FormattedText;
     type IntoIter = std::vec::IntoIter<Self::Item>;
@@ -458,26 +565,59 @@ impl simdnbt::FromNbtTag for FormattedText {
 
 #[cfg(feature = "simdnbt")]
 impl FormattedText {
+    /// Convert this component into an NBT tag, using the compact plain string
+    /// form if it's a text component with no style or siblings.
+    ///
+    /// This matches how vanilla serializes components at the top level. It
+    /// can't be used for components in lists (like siblings), since NBT lists
+    /// can't mix strings and compounds.
+    pub fn to_compact_nbt_tag(self) -> simdnbt::owned::NbtTag {
+        use simdnbt::Serialize;
+
+        match self {
+            FormattedText::Text(c) if c.base == BaseComponent::default() => {
+                simdnbt::owned::NbtTag::String(c.text.into())
+            }
+            c => simdnbt::owned::NbtTag::Compound(c.to_compound()),
+        }
+    }
+
     fn from_nbt_string(s: &simdnbt::Mutf8Str) -> Self {
         FormattedText::from(s)
     }
+    /// Parse a list of components, where the first item is the base component
+    /// and the rest are its siblings.
+    ///
+    /// This is the NBT equivalent of a JSON array of components. Items can be
+    /// strings, compounds (including vanilla's `{"": ...}` wrapper for mixed
+    /// lists), or nested lists.
     fn from_nbt_list(list: simdnbt::borrow::NbtList) -> Option<FormattedText> {
         use tracing::debug;
 
-        let mut component;
-        if let Some(compounds) = list.compounds() {
-            component = FormattedText::from_nbt_compound(compounds.first()?)?;
-            for compound in compounds.into_iter().skip(1) {
-                component.append(FormattedText::from_nbt_compound(compound)?);
-            }
+        if list.empty() {
+            return Some(FormattedText::default());
+        }
+
+        let mut components: Vec<FormattedText> = if let Some(compounds) = list.compounds() {
+            compounds
+                .into_iter()
+                .map(FormattedText::from_nbt_compound)
+                .collect::<Option<_>>()?
         } else if let Some(strings) = list.strings() {
-            component = FormattedText::from(*(strings.first()?));
-            for &string in strings.iter().skip(1) {
-                component.append(FormattedText::from(string));
-            }
+            strings.iter().map(|&s| FormattedText::from(s)).collect()
+        } else if let Some(lists) = list.lists() {
+            lists
+                .into_iter()
+                .map(FormattedText::from_nbt_list)
+                .collect::<Option<_>>()?
         } else {
             debug!("couldn't parse {list:?} as FormattedText");
             return None;
+        };
+
+        let mut component = components.remove(0);
+        for sibling in components {
+            component.append(sibling);
         }
         Some(component)
     }
@@ -493,6 +633,7 @@ impl FormattedText {
             component = FormattedText::Text(TextComponent::new(text));
         } else if let Some(translate) = compound.get("translate") {
             let translate = translate.string()?.into();
+            let fallback = compound.string("fallback").map(|s| s.to_string());
             if let Some(with) = compound.get("with") {
                 let mut with_array = Vec::new();
                 let with_list = with.list()?;
@@ -561,12 +702,16 @@ impl FormattedText {
                     );
                     return None;
                 }
-                component =
-                    FormattedText::Translatable(TranslatableComponent::new(translate, with_array));
+                component = FormattedText::Translatable(TranslatableComponent::with_fallback(
+                    translate, fallback, with_array,
+                ));
             } else {
                 // if it doesn't have a "with", just have the with_array be empty
-                component =
-                    FormattedText::Translatable(TranslatableComponent::new(translate, Vec::new()));
+                component = FormattedText::Translatable(TranslatableComponent::with_fallback(
+                    translate,
+                    fallback,
+                    Vec::new(),
+                ));
             }
         } else if let Some(score) = compound.compound("score") {
             if score.get("name").is_none() || score.get("objective").is_none() {
@@ -657,10 +802,9 @@ impl AzBuf for FormattedText {
         }
     }
     fn azalea_write(&self, buf: &mut impl Write) -> io::Result<()> {
-        use simdnbt::Serialize;
-
         let mut out = Vec::new();
-        simdnbt::owned::BaseNbt::write_unnamed(&(self.clone().to_compound().into()), &mut out);
+        // network nbt is unnamed, so this is the same as writing just the tag
+        self.clone().to_compact_nbt_tag().write(&mut out);
         buf.write_all(&out)
     }
 }
@@ -744,6 +888,203 @@ mod tests {
         );
     }
 
+    #[test]
+    fn serialize_plain_text_as_string() {
+        let component = FormattedText::from("hello");
+        assert_eq!(
+            serde_json::to_value(&component).unwrap(),
+            serde_json::json!("hello")
+        );
+
+        let component =
+            FormattedText::Text(TextComponent::new("hello").with_style(Style::new().bold(true)));
+        assert_eq!(
+            serde_json::to_value(&component).unwrap(),
+            serde_json::json!({ "text": "hello", "bold": true })
+        );
+    }
+
+    #[cfg(all(feature = "azalea-buf", feature = "simdnbt"))]
+    #[test]
+    fn nbt_plain_text_as_string() {
+        let component = FormattedText::from("hello");
+        let mut buf = Vec::new();
+        component.azalea_write(&mut buf).unwrap();
+        // string tag id, then the length and the string
+        assert_eq!(buf, b"\x08\x00\x05hello");
+        assert_eq!(
+            FormattedText::azalea_read(&mut Cursor::new(&buf)).unwrap(),
+            component
+        );
+
+        let component =
+            FormattedText::Text(TextComponent::new("hello").with_style(Style::new().bold(true)));
+        let mut buf = Vec::new();
+        component.azalea_write(&mut buf).unwrap();
+        // compound tag id
+        assert_eq!(buf[0], 0x0a);
+        assert_eq!(
+            FormattedText::azalea_read(&mut Cursor::new(&buf)).unwrap(),
+            component
+        );
+    }
+
+    #[cfg(all(feature = "azalea-buf", feature = "simdnbt"))]
+    #[test]
+    fn nbt_translation_fallback_roundtrip() {
+        let component = FormattedText::Translatable(TranslatableComponent::with_fallback(
+            "translation.test.undefined".to_owned(),
+            Some("fallback: %s".to_owned()),
+            vec![PrimitiveOrComponent::String("a".to_owned())],
+        ));
+        let mut buf = Vec::new();
+        component.azalea_write(&mut buf).unwrap();
+        let read = FormattedText::azalea_read(&mut Cursor::new(&buf)).unwrap();
+        assert_eq!(read, component);
+        assert_eq!(read.to_string(), "fallback: a");
+    }
+
+    #[cfg(all(feature = "azalea-buf", feature = "simdnbt"))]
+    fn read_nbt_tag(tag: simdnbt::owned::NbtTag) -> FormattedText {
+        let mut buf = Vec::new();
+        tag.write(&mut buf);
+        FormattedText::azalea_read(&mut Cursor::new(&buf)).unwrap()
+    }
+
+    #[cfg(all(feature = "azalea-buf", feature = "simdnbt"))]
+    #[test]
+    fn nbt_list_root() {
+        use simdnbt::owned::{NbtCompound, NbtList, NbtTag};
+
+        let json_equivalent = |json: Value| FormattedText::deserialize(&json).unwrap();
+
+        // a list of compounds
+        let text = read_nbt_tag(NbtTag::List(NbtList::from(vec![
+            NbtCompound::from_values(vec![
+                ("text".into(), "hello ".into()),
+                ("color".into(), "red".into()),
+            ]),
+            NbtCompound::from_values(vec![("text".into(), "world".into())]),
+        ])));
+        assert_eq!(
+            text,
+            json_equivalent(serde_json::json!([
+                { "text": "hello ", "color": "red" },
+                { "text": "world" }
+            ]))
+        );
+        assert_eq!(text.to_string(), "hello world");
+
+        // a list of strings
+        let text = read_nbt_tag(NbtTag::List(NbtList::from(vec![
+            "a".to_owned(),
+            "b".to_owned(),
+        ])));
+        assert_eq!(text, json_equivalent(serde_json::json!(["a", "b"])));
+
+        // a mixed list, which vanilla writes as a list of compounds where the
+        // strings are wrapped in `{"": ...}`
+        let text = read_nbt_tag(NbtTag::List(NbtList::from(vec![
+            NbtTag::from("a"),
+            NbtTag::Compound(NbtCompound::from_values(vec![
+                ("text".into(), "b".into()),
+                ("bold".into(), NbtTag::Byte(1)),
+            ])),
+        ])));
+        assert_eq!(
+            text,
+            json_equivalent(serde_json::json!(["a", { "text": "b", "bold": true }]))
+        );
+
+        // nested lists
+        let text = read_nbt_tag(NbtTag::List(NbtList::List(vec![
+            NbtList::from(vec!["a".to_owned(), "b".to_owned()]),
+            NbtList::from(vec!["c".to_owned()]),
+        ])));
+        assert_eq!(
+            text,
+            json_equivalent(serde_json::json!([["a", "b"], ["c"]]))
+        );
+
+        // an empty list
+        let text = read_nbt_tag(NbtTag::List(NbtList::Empty));
+        assert_eq!(text, json_equivalent(serde_json::json!([])));
+    }
+
+    #[test]
+    fn font_roundtrip() {
+        let json = serde_json::json!({ "text": "hello", "font": "minecraft:alt" });
+        let component = FormattedText::deserialize(&json).unwrap();
+        assert_eq!(
+            component.get_base().style.font.as_deref(),
+            Some("minecraft:alt")
+        );
+        assert_eq!(serde_json::to_value(&component).unwrap(), json);
+
+        // children inherit the font unless they override it
+        let parent = Style::new().font("minecraft:alt".to_owned());
+        let child = Style::new().color(TextColor::RED);
+        assert_eq!(
+            parent.merged_with(&child).font.as_deref(),
+            Some("minecraft:alt")
+        );
+        let child = child.font("minecraft:uniform".to_owned());
+        assert_eq!(
+            parent.merged_with(&child).font.as_deref(),
+            Some("minecraft:uniform")
+        );
+    }
+
+    #[cfg(all(feature = "azalea-buf", feature = "simdnbt"))]
+    #[test]
+    fn nbt_font_roundtrip() {
+        let component = FormattedText::Text(
+            TextComponent::new("hello").with_style(Style::new().font("minecraft:alt".to_owned())),
+        );
+        let mut buf = Vec::new();
+        component.azalea_write(&mut buf).unwrap();
+        assert_eq!(
+            FormattedText::azalea_read(&mut Cursor::new(&buf)).unwrap(),
+            component
+        );
+    }
+
+    #[test]
+    fn insertion_roundtrip() {
+        let json = serde_json::json!({ "text": "player", "insertion": "/msg player " });
+        let component = FormattedText::deserialize(&json).unwrap();
+        let style = &component.get_base().style;
+        assert_eq!(style.insertion.as_deref(), Some("/msg player "));
+        assert!(!style.is_empty());
+        assert_eq!(serde_json::to_value(&component).unwrap(), json);
+
+        // children inherit the insertion unless they override it
+        let parent = Style::new().insertion("/msg player ".to_owned());
+        let child = Style::new().color(TextColor::RED);
+        assert_eq!(
+            parent.merged_with(&child).insertion.as_deref(),
+            Some("/msg player ")
+        );
+        let mut applied = child.clone();
+        applied.apply(&Style::new().insertion("/tp player".to_owned()));
+        assert_eq!(applied.insertion.as_deref(), Some("/tp player"));
+    }
+
+    #[cfg(all(feature = "azalea-buf", feature = "simdnbt"))]
+    #[test]
+    fn nbt_insertion_roundtrip() {
+        let component = FormattedText::Text(
+            TextComponent::new("player")
+                .with_style(Style::new().insertion("/msg player ".to_owned())),
+        );
+        let mut buf = Vec::new();
+        component.azalea_write(&mut buf).unwrap();
+        assert_eq!(
+            FormattedText::azalea_read(&mut Cursor::new(&buf)).unwrap(),
+            component
+        );
+    }
+
     #[test]
     fn deserialize_translation_invalid_fallback() {
         let j: Value = serde_json::from_str(
@@ -775,6 +1116,65 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_to_ansi_obfuscated() {
+        let json = serde_json::json!({
+            "text": "visible ",
+            "extra": [{"text": "héllo world", "obfuscated": true}, {"text": " end"}]
+        });
+        let component = FormattedText::deserialize(&json).unwrap();
+
+        let ansi = component.to_ansi_obfuscated(|| '#');
+        assert!(!ansi.contains(Ansi::OBFUSCATED));
+        assert!(!ansi.contains("héllo"));
+        assert!(ansi.contains("visible "));
+        assert!(ansi.contains(" end"));
+
+        let plain = strip_ansi(&ansi);
+        assert_eq!(plain.chars().count(), component.to_string().chars().count());
+        // whitespace is kept so words stay the same length
+        assert_eq!(plain, "visible ##### ##### end");
+
+        // every character gets a new random character
+        let mut chars = ('a'..='z').cycle();
+        let ansi2 = component.to_ansi_obfuscated(|| chars.next().unwrap());
+        assert_eq!(strip_ansi(&ansi2), "visible abcde fghij end");
+    }
+
+    #[test]
+    fn test_width() {
+        // measured with vanilla's Font::width
+        assert_eq!(FormattedText::from("Hello, world!").width(), 59);
+        assert_eq!(FormattedText::from(" ").width(), 4);
+        assert_eq!(FormattedText::from("").width(), 0);
+
+        let json = serde_json::json!({
+            "text": "Hi ",
+            "bold": true,
+            "extra": [{"text": "il"}, {"text": "il", "bold": false}]
+        });
+        let component = FormattedText::deserialize(&json).unwrap();
+        // bold "Hi " is 7 + 3 + 4, bold "il" is 3 + 4, and "il" is 2 + 3
+        assert_eq!(component.width(), 14 + 7 + 5);
+    }
+
+    fn strip_ansi(s: &str) -> String {
+        let mut out = String::new();
+        let mut chars = s.chars();
+        while let Some(c) = chars.next() {
+            if c == '\u{1b}' {
+                for c in chars.by_ref() {
+                    if c == 'm' {
+                        break;
+                    }
+                }
+            } else {
+                out.push(c);
+            }
+        }
+        out
+    }
+
     #[test]
     fn test_translatable_with_color_inheritance() {
         let json = serde_json::json!({
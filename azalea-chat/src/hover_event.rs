@@ -1,8 +1,10 @@
 use serde::Serialize;
 #[cfg(feature = "simdnbt")]
-use simdnbt::owned::NbtCompound;
+use simdnbt::{DeserializeError, owned::NbtCompound};
 
 use crate::FormattedText;
+#[cfg(feature = "simdnbt")]
+use crate::get_in_compound;
 
 #[derive(Clone, Debug, PartialEq, Serialize)]
 #[serde(rename_all = "snake_case", tag = "action")]
@@ -46,3 +48,23 @@ impl simdnbt::Serialize for HoverEvent {
         compound
     }
 }
+
+#[cfg(feature = "simdnbt")]
+impl simdnbt::Deserialize for HoverEvent {
+    fn from_compound(
+        compound: simdnbt::borrow::NbtCompound,
+    ) -> Result<Self, simdnbt::DeserializeError> {
+        let action = get_in_compound::<String>(&compound, "action")?;
+        Ok(match action.as_str() {
+            "show_text" => HoverEvent::ShowText {
+                value: Box::new(get_in_compound(&compound, "value")?),
+            },
+            "show_item" => HoverEvent::ShowItem {},
+            "show_entity" => HoverEvent::ShowEntity {
+                id: get_in_compound(&compound, "id")?,
+                name: Box::new(get_in_compound(&compound, "name")?),
+            },
+            _ => return Err(DeserializeError::MismatchedFieldType(action)),
+        })
+    }
+}
@@ -6,13 +6,16 @@ mod component;
 pub mod hover_event;
 #[cfg(feature = "numbers")]
 pub mod numbers;
+mod parse;
 pub mod style;
 pub mod text_component;
 pub mod translatable_component;
 
 pub use component::{DEFAULT_STYLE, FormattedText};
+pub use parse::{ParseError, parse};
 #[cfg(feature = "simdnbt")]
 use simdnbt::{DeserializeError, FromNbtTag};
+pub use text_component::strip_legacy_formatting;
 
 // TODO: remove this after simdnbt refactor
 #[cfg(feature = "simdnbt")]
@@ -0,0 +1,82 @@
+use thiserror::Error;
+
+use crate::{
+    FormattedText,
+    text_component::{
+        LEGACY_FORMATTING_CODE_SYMBOL, legacy_color_code_to_text_component_with_prefix,
+    },
+};
+
+/// Parse a string that's either a JSON text component or a string with legacy
+/// formatting codes (like `§cHello`) into a [`FormattedText`].
+///
+/// The input is treated as JSON if it starts with `{`, `[`, or `"` (ignoring
+/// leading whitespace), and as legacy text otherwise. Legacy formatting codes
+/// may start with either `§` or `&`, but `&` is only used if the input
+/// doesn't contain any `§`.
+///
+/// ```
+/// # use azalea_chat::parse;
+/// let json = parse(r#"{"text":"Hello","color":"red"}"#).unwrap();
+/// let legacy = parse("§cHello").unwrap();
+/// assert_eq!(json.to_string(), "Hello");
+/// assert_eq!(legacy.to_string(), "Hello");
+/// ```
+pub fn parse(input: &str) -> Result<FormattedText, ParseError> {
+    if input.trim_start().starts_with(['{', '[', '"']) {
+        Ok(serde_json::from_str(input)?)
+    } else {
+        let prefix = if input.contains(LEGACY_FORMATTING_CODE_SYMBOL) {
+            LEGACY_FORMATTING_CODE_SYMBOL
+        } else {
+            '&'
+        };
+        Ok(FormattedText::Text(
+            legacy_color_code_to_text_component_with_prefix(input, prefix),
+        ))
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum ParseError {
+    #[error("Invalid JSON text component: {0}")]
+    Json(#[from] serde_json::Error),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::style::TextColor;
+
+    #[test]
+    fn parse_json() {
+        let text = parse(r#"{"text":"Hello","color":"red","bold":true}"#).unwrap();
+        assert_eq!(text.to_string(), "Hello");
+        let style = &text.get_base().style;
+        assert_eq!(style.color, Some(TextColor::RED));
+        assert_eq!(style.bold, Some(true));
+
+        assert_eq!(parse(r#""plain""#).unwrap().to_string(), "plain");
+        assert_eq!(parse(r#"["a", {"text": "b"}]"#).unwrap().to_string(), "ab");
+        assert!(parse("{not json").is_err());
+    }
+
+    #[test]
+    fn parse_legacy() {
+        let text = parse("§cred §lbold").unwrap();
+        assert_eq!(text.to_string(), "red bold");
+        let siblings = &text.get_base().siblings;
+        assert_eq!(siblings.len(), 2);
+        assert_eq!(siblings[0].get_base().style.color, Some(TextColor::RED));
+        assert_eq!(siblings[1].get_base().style.bold, Some(true));
+
+        assert_eq!(parse("no formatting").unwrap().to_string(), "no formatting");
+
+        let text = parse("&cred & plain").unwrap();
+        assert_eq!(text.to_string(), "red & plain");
+        assert_eq!(
+            text.get_base().siblings[0].get_base().style.color,
+            Some(TextColor::RED)
+        );
+    }
+}
@@ -1,4 +1,10 @@
-use std::{collections::HashMap, fmt, sync::LazyLock};
+use std::{
+    borrow::Cow,
+    collections::HashMap,
+    fmt,
+    hash::{Hash, Hasher},
+    sync::LazyLock,
+};
 
 #[cfg(feature = "azalea-buf")]
 use azalea_buf::AzBuf;
@@ -9,10 +15,44 @@ use simdnbt::owned::{NbtCompound, NbtTag};
 
 use crate::{click_event::ClickEvent, hover_event::HoverEvent};
 
-#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+/// A color for text, either named (like `red`) or from a hex code.
+///
+/// Names are compared case-insensitively, so `red` and `RED` with the same
+/// value are equal. The alpha channel isn't compared either, so
+/// `TextColor::RED.with_alpha(0x80) == TextColor::RED`.
+#[derive(Clone, Debug)]
 pub struct TextColor {
+    /// The RGB value of the color.
     pub value: u32,
-    pub name: Option<String>,
+    pub name: Option<Cow<'static, str>>,
+    /// The alpha channel of the color, which is `0xFF` (fully opaque) by
+    /// default.
+    ///
+    /// This only exists for rendering, so it isn't sent over the network or
+    /// compared when checking if two colors are equal.
+    pub alpha: u8,
+}
+
+impl PartialEq for TextColor {
+    fn eq(&self, other: &Self) -> bool {
+        let names_eq = match (&self.name, &other.name) {
+            (Some(a), Some(b)) => a.eq_ignore_ascii_case(b),
+            (None, None) => true,
+            _ => false,
+        };
+        self.value == other.value && names_eq
+    }
+}
+impl Eq for TextColor {}
+impl Hash for TextColor {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.value.hash(state);
+        // has to match the case-insensitive PartialEq
+        self.name.is_some().hash(state);
+        for byte in self.name.iter().flat_map(|name| name.bytes()) {
+            byte.to_ascii_lowercase().hash(state);
+        }
+    }
 }
 
 impl Serialize for TextColor {
@@ -32,8 +72,40 @@ impl simdnbt::ToNbtTag for TextColor {
 }
 
 impl TextColor {
-    fn new(value: u32, name: Option<String>) -> Self {
-        Self { value, name }
+    pub const BLACK: TextColor = TextColor::from_named_formatting(ChatFormatting::Black);
+    pub const DARK_BLUE: TextColor = TextColor::from_named_formatting(ChatFormatting::DarkBlue);
+    pub const DARK_GREEN: TextColor = TextColor::from_named_formatting(ChatFormatting::DarkGreen);
+    pub const DARK_AQUA: TextColor = TextColor::from_named_formatting(ChatFormatting::DarkAqua);
+    pub const DARK_RED: TextColor = TextColor::from_named_formatting(ChatFormatting::DarkRed);
+    pub const DARK_PURPLE: TextColor = TextColor::from_named_formatting(ChatFormatting::DarkPurple);
+    pub const GOLD: TextColor = TextColor::from_named_formatting(ChatFormatting::Gold);
+    pub const GRAY: TextColor = TextColor::from_named_formatting(ChatFormatting::Gray);
+    pub const DARK_GRAY: TextColor = TextColor::from_named_formatting(ChatFormatting::DarkGray);
+    pub const BLUE: TextColor = TextColor::from_named_formatting(ChatFormatting::Blue);
+    pub const GREEN: TextColor = TextColor::from_named_formatting(ChatFormatting::Green);
+    pub const AQUA: TextColor = TextColor::from_named_formatting(ChatFormatting::Aqua);
+    pub const RED: TextColor = TextColor::from_named_formatting(ChatFormatting::Red);
+    pub const LIGHT_PURPLE: TextColor =
+        TextColor::from_named_formatting(ChatFormatting::LightPurple);
+    pub const YELLOW: TextColor = TextColor::from_named_formatting(ChatFormatting::Yellow);
+    pub const WHITE: TextColor = TextColor::from_named_formatting(ChatFormatting::White);
+
+    fn new(value: u32, name: Option<Cow<'static, str>>) -> Self {
+        Self {
+            value,
+            name,
+            alpha: 0xFF,
+        }
+    }
+
+    /// Panics (at compile time, when used in a const) if the formatting isn't a
+    /// color.
+    const fn from_named_formatting(formatting: ChatFormatting) -> Self {
+        Self {
+            value: formatting.color().unwrap(),
+            name: Some(Cow::Borrowed(formatting.name())),
+            alpha: 0xFF,
+        }
     }
 
     fn serialize(&self) -> String {
@@ -65,7 +137,22 @@ impl TextColor {
     }
 
     fn from_rgb(value: u32) -> TextColor {
-        TextColor { value, name: None }
+        TextColor::new(value, None)
+    }
+
+    /// Returns this color with the given alpha channel, for renderers that want
+    /// ARGB colors.
+    ///
+    /// The alpha is ignored when serializing, so the color is still written as
+    /// its name or as `#RRGGBB`.
+    pub fn with_alpha(mut self, alpha: u8) -> Self {
+        self.alpha = alpha;
+        self
+    }
+
+    /// The color as an ARGB value, with the alpha channel in the highest byte.
+    pub fn argb(&self) -> u32 {
+        ((self.alpha as u32) << 24) | (self.value & 0xFFFFFF)
     }
 }
 
@@ -82,20 +169,14 @@ static LEGACY_FORMAT_TO_COLOR: LazyLock<HashMap<&'static ChatFormatting, TextCol
             if formatter.is_format() || *formatter == ChatFormatting::Reset {
                 continue;
             }
-            legacy_format_to_color.insert(
-                formatter,
-                TextColor {
-                    value: formatter.color().unwrap(),
-                    name: Some(formatter.name().to_owned()),
-                },
-            );
+            legacy_format_to_color.insert(formatter, TextColor::from_named_formatting(*formatter));
         }
         legacy_format_to_color
     });
 static NAMED_COLORS: LazyLock<HashMap<String, TextColor>> = LazyLock::new(|| {
     let mut named_colors = HashMap::new();
     for color in LEGACY_FORMAT_TO_COLOR.values() {
-        named_colors.insert(color.name.clone().unwrap(), color.clone());
+        named_colors.insert(color.name.clone().unwrap().into_owned(), color.clone());
     }
     named_colors
 });
@@ -173,7 +254,7 @@ impl ChatFormatting {
         Self::Reset,
     ];
 
-    pub fn name(&self) -> &'static str {
+    pub const fn name(&self) -> &'static str {
         match self {
             Self::Black => "black",
             Self::DarkBlue => "dark_blue",
@@ -273,27 +354,38 @@ impl ChatFormatting {
         )
     }
 
-    pub fn color(&self) -> Option<u32> {
+    /// The color of this formatting code, as a `0xRRGGBB` integer, or `None`
+    /// if it's not a color.
+    pub const fn color(&self) -> Option<u32> {
         Some(match self {
-            Self::Black => 0,
-            Self::DarkBlue => 170,
-            Self::DarkGreen => 43520,
-            Self::DarkAqua => 43690,
-            Self::DarkRed => 11141120,
-            Self::DarkPurple => 11141290,
-            Self::Gold => 16755200,
-            Self::Gray => 11184810,
-            Self::DarkGray => 5592405,
-            Self::Blue => 5592575,
-            Self::Green => 5635925,
-            Self::Aqua => 5636095,
-            Self::Red => 16733525,
-            Self::LightPurple => 16733695,
-            Self::Yellow => 16777045,
-            Self::White => 16777215,
+            Self::Black => 0x000000,
+            Self::DarkBlue => 0x0000AA,
+            Self::DarkGreen => 0x00AA00,
+            Self::DarkAqua => 0x00AAAA,
+            Self::DarkRed => 0xAA0000,
+            Self::DarkPurple => 0xAA00AA,
+            Self::Gold => 0xFFAA00,
+            Self::Gray => 0xAAAAAA,
+            Self::DarkGray => 0x555555,
+            Self::Blue => 0x5555FF,
+            Self::Green => 0x55FF55,
+            Self::Aqua => 0x55FFFF,
+            Self::Red => 0xFF5555,
+            Self::LightPurple => 0xFF55FF,
+            Self::Yellow => 0xFFFF55,
+            Self::White => 0xFFFFFF,
             _ => return None,
         })
     }
+
+    /// The color of this formatting code as red, green, and blue components,
+    /// or `None` if it's not a color.
+    pub const fn color_rgb(&self) -> Option<(u8, u8, u8)> {
+        match self.color() {
+            Some(color) => Some(((color >> 16) as u8, (color >> 8) as u8, color as u8)),
+            None => None,
+        }
+    }
 }
 
 // from ChatFormatting to TextColor
@@ -305,12 +397,38 @@ impl TryFrom<ChatFormatting> for TextColor {
             return Err(format!("{} is not a color", formatter.name()));
         }
         let color = formatter.color().unwrap_or(0);
-        Ok(Self::new(color, Some(formatter.name().to_owned())))
+        Ok(Self::new(color, Some(Cow::Borrowed(formatter.name()))))
     }
 }
 
+/// Find the legacy color code that has exactly the given color.
+fn color_to_legacy_formatting(value: u32) -> Option<ChatFormatting> {
+    ChatFormatting::FORMATTERS
+        .into_iter()
+        .find(|formatter| !formatter.is_format() && formatter.color() == Some(value))
+}
+
 macro_rules! define_style_struct {
     ($($(#[$doc:meta])* $field:ident : $type:ty),* $(,)?) => {
+        /// The formatting of a piece of text, like its color and whether it's
+        /// bold.
+        ///
+        /// Every field has a setter with the same name that returns the
+        /// modified style, so styles can be built by chaining them:
+        ///
+        /// ```
+        /// # use azalea_chat::{style::{Style, TextColor}, text_component::TextComponent};
+        /// let style = Style::empty().color(TextColor::RED).bold(true);
+        /// assert_eq!(style.color, Some(TextColor::RED));
+        /// assert_eq!(style.bold, Some(true));
+        /// assert_eq!(style.italic, None);
+        ///
+        /// // passing `None` unsets the field
+        /// let style = style.bold(None);
+        /// assert_eq!(style.bold, None);
+        ///
+        /// let text = TextComponent::new("Hello").with_style(style);
+        /// ```
         #[derive(Clone, Debug, Default, PartialEq, serde::Serialize)]
         #[non_exhaustive]
         pub struct Style {
@@ -323,6 +441,7 @@ macro_rules! define_style_struct {
 
         impl Style {
             $(
+                #[doc = concat!("Return this style with [`", stringify!($field), "`](Self::", stringify!($field), ") set to the given value.")]
                 pub fn $field(mut self, value: impl Into<Option<$type>>) -> Self {
                     self.$field = value.into();
                     self
@@ -398,10 +517,12 @@ impl Style {
         };
 
         Style {
-            color: j
-                .get("color")
-                .and_then(|v| v.as_str())
-                .and_then(TextColor::parse),
+            color: j.get("color").and_then(|v| match v {
+                Value::String(s) => TextColor::parse(s),
+                // not vanilla, but some servers and plugins send colors as integers
+                Value::Number(n) => n.as_u64().map(|n| TextColor::from_rgb(n as u32)),
+                _ => None,
+            }),
             shadow_color: j
                 .get("shadow_color")
                 .and_then(|v| v.as_u64())
@@ -430,6 +551,8 @@ impl Style {
             && self.underlined.is_none()
             && self.strikethrough.is_none()
             && self.obfuscated.is_none()
+            && self.insertion.is_none()
+            && self.font.is_none()
     }
 
     /// find the necessary ansi code to get from this style to another
@@ -491,6 +614,81 @@ impl Style {
         ansi_codes
     }
 
+    /// Find the legacy formatting codes (like `§c`) that are necessary to get
+    /// from this style to another.
+    ///
+    /// Unlike ANSI, legacy formatting can't turn off individual formats, and
+    /// setting a color also clears all formats. Because of that, this will
+    /// start with [`ChatFormatting::Reset`] if any format was removed, and
+    /// will re-apply the formats after a color change.
+    ///
+    /// Colors that don't match a legacy color (like most hex colors) can't be
+    /// represented and are skipped.
+    pub fn compare_legacy(&self, after: &Style) -> Vec<ChatFormatting> {
+        let after_color = after
+            .color
+            .as_ref()
+            .and_then(|color| color_to_legacy_formatting(color.value));
+        let same_color = match (&self.color, &after.color) {
+            (Some(before_color), Some(after_color)) => before_color.value == after_color.value,
+            (None, None) => true,
+            _ => false,
+        };
+
+        let should_reset =
+            // if any property used to be true and now it's not, reset
+            (self.bold.unwrap_or_default() && !after.bold.unwrap_or_default()) ||
+            (self.italic.unwrap_or_default() && !after.italic.unwrap_or_default()) ||
+            (self.underlined.unwrap_or_default() && !after.underlined.unwrap_or_default()) ||
+            (self.strikethrough.unwrap_or_default() && !after.strikethrough.unwrap_or_default()) ||
+            (self.obfuscated.unwrap_or_default() && !after.obfuscated.unwrap_or_default()) ||
+            // there's no code to go back to the default color (or to a color that
+            // isn't a legacy one)
+            (!same_color && self.color.is_some() && after_color.is_none());
+
+        let mut codes = Vec::new();
+
+        let empty_style = Style::empty();
+
+        let mut before = if should_reset {
+            codes.push(ChatFormatting::Reset);
+            &empty_style
+        } else {
+            self
+        };
+
+        let color_changed = match (&before.color, after_color) {
+            (None, Some(_)) => true,
+            (Some(before_color), Some(after_color)) => {
+                Some(before_color.value) != after_color.color()
+            }
+            _ => false,
+        };
+        if color_changed {
+            codes.push(after_color.unwrap());
+            // colors clear all formats, so we have to add them again
+            before = &empty_style;
+        }
+
+        if !before.bold.unwrap_or_default() && after.bold.unwrap_or_default() {
+            codes.push(ChatFormatting::Bold);
+        }
+        if !before.italic.unwrap_or_default() && after.italic.unwrap_or_default() {
+            codes.push(ChatFormatting::Italic);
+        }
+        if !before.underlined.unwrap_or_default() && after.underlined.unwrap_or_default() {
+            codes.push(ChatFormatting::Underline);
+        }
+        if !before.strikethrough.unwrap_or_default() && after.strikethrough.unwrap_or_default() {
+            codes.push(ChatFormatting::Strikethrough);
+        }
+        if !before.obfuscated.unwrap_or_default() && after.obfuscated.unwrap_or_default() {
+            codes.push(ChatFormatting::Obfuscated);
+        }
+
+        codes
+    }
+
     /// Returns a new style that is a merge of self and other.
     /// For any field that `other` does not specify (is None), self's value is
     /// used.
@@ -527,9 +725,10 @@ impl Style {
                 self.obfuscated = None;
             }
             formatter => {
-                // if it's a color, set it
-                if let Some(color) = formatter.color() {
-                    self.color = Some(TextColor::from_rgb(color));
+                // if it's a color, set it (keeping the name so it's serialized as
+                // "red" instead of "#FF5555")
+                if let Ok(color) = TextColor::try_from(formatter) {
+                    self.color = Some(color);
                 }
             }
         }
@@ -585,9 +784,10 @@ impl simdnbt::Deserialize for Style {
     ) -> Result<Self, simdnbt::DeserializeError> {
         use crate::get_in_compound;
 
-        let color: Option<TextColor> = compound
-            .string("color")
-            .and_then(|v| TextColor::parse(&v.to_str()));
+        let color: Option<TextColor> = match compound.string("color") {
+            Some(v) => TextColor::parse(&v.to_str()),
+            None => compound.int("color").map(|v| TextColor::from_rgb(v as u32)),
+        };
         let shadow_color = get_in_compound(&compound, "shadow_color").ok();
         let bold = get_in_compound(&compound, "bold").ok();
         let italic = get_in_compound(&compound, "italic").ok();
@@ -595,8 +795,7 @@ impl simdnbt::Deserialize for Style {
         let strikethrough = get_in_compound(&compound, "strikethrough").ok();
         let obfuscated = get_in_compound(&compound, "obfuscated").ok();
         let click_event = get_in_compound(&compound, "click_event").ok();
-        // TODO
-        // let hover_event = get_in_compound(&compound, "hover_event")?;
+        let hover_event = get_in_compound(&compound, "hover_event").ok();
         let insertion = get_in_compound(&compound, "insertion").ok();
         let font = get_in_compound(&compound, "font").ok();
         Ok(Style {
@@ -608,7 +807,7 @@ impl simdnbt::Deserialize for Style {
             strikethrough,
             obfuscated,
             click_event,
-            hover_event: None,
+            hover_event,
             insertion,
             font,
         })
@@ -624,10 +823,150 @@ mod tests {
         assert_eq!(TextColor::parse("red").unwrap().value, 16733525);
     }
     #[test]
+    fn text_color_consts() {
+        assert_eq!(TextColor::RED.value, 16733525);
+        assert_eq!(TextColor::RED, TextColor::parse("red").unwrap());
+        assert_eq!(TextColor::DARK_BLUE, TextColor::parse("dark_blue").unwrap());
+        assert_eq!(serde_json::to_string(&TextColor::RED).unwrap(), "\"red\"");
+        assert_eq!(TextColor::WHITE.to_string(), "white");
+    }
+    #[test]
+    fn chat_formatting_color_rgb() {
+        assert_eq!(ChatFormatting::DarkAqua.color(), Some(43690));
+        assert_eq!(ChatFormatting::DarkAqua.color_rgb(), Some((0, 170, 170)));
+        assert_eq!(ChatFormatting::Gold.color_rgb(), Some((255, 170, 0)));
+        assert_eq!(ChatFormatting::Bold.color_rgb(), None);
+    }
+    #[test]
+    fn deserialize_integer_color() {
+        let style = Style::deserialize(&serde_json::json!({ "color": 16733525 }));
+        assert_eq!(style.color.unwrap().value, TextColor::RED.value);
+
+        let style = Style::deserialize(&serde_json::json!({ "color": "red" }));
+        assert_eq!(style.color, Some(TextColor::RED));
+    }
+    #[cfg(feature = "simdnbt")]
+    #[test]
+    fn nbt_integer_color() {
+        use std::io::Cursor;
+
+        use simdnbt::{
+            Deserialize,
+            owned::{NbtCompound, NbtTag},
+        };
+
+        let mut buf = Vec::new();
+        NbtCompound::from_values(vec![("color".into(), NbtTag::Int(16733525))]).write(&mut buf);
+        let compound = simdnbt::borrow::read_compound(&mut Cursor::new(&buf)).unwrap();
+        let style = Style::from_compound((&compound).into()).unwrap();
+        assert_eq!(style.color.unwrap().value, TextColor::RED.value);
+    }
+    #[cfg(feature = "simdnbt")]
+    fn nbt_roundtrip(style: &Style) -> Style {
+        use std::io::Cursor;
+
+        use simdnbt::{Deserialize, Serialize};
+
+        let mut buf = Vec::new();
+        style.clone().to_compound().write(&mut buf);
+        let compound = simdnbt::borrow::read_compound(&mut Cursor::new(&buf)).unwrap();
+        Style::from_compound((&compound).into()).unwrap()
+    }
+
+    #[cfg(feature = "simdnbt")]
+    #[test]
+    fn style_nbt_roundtrip() {
+        use crate::FormattedText;
+
+        let style = Style::new()
+            .color(TextColor::parse("#123456"))
+            .shadow_color(0xFF00FF00)
+            .bold(true)
+            .italic(false)
+            .underlined(true)
+            .strikethrough(false)
+            .obfuscated(true)
+            .click_event(ClickEvent::RunCommand {
+                command: "/help".to_owned(),
+            })
+            .hover_event(HoverEvent::ShowText {
+                value: Box::new(FormattedText::from("hi")),
+            })
+            .insertion("hello".to_owned())
+            .font("minecraft:alt".to_owned());
+        assert_eq!(nbt_roundtrip(&style), style);
+
+        let style = Style::new().hover_event(HoverEvent::ShowEntity {
+            id: 123,
+            name: Box::new(FormattedText::from("Steve")),
+        });
+        assert_eq!(nbt_roundtrip(&style), style);
+
+        // named colors keep their names
+        let style = Style::new().color(TextColor::RED).bold(false);
+        assert_eq!(nbt_roundtrip(&style), style);
+
+        assert_eq!(nbt_roundtrip(&Style::new()), Style::new());
+    }
+    #[test]
     fn text_color_hex_colors() {
         assert_eq!(TextColor::parse("#a1b2c3").unwrap().value, 10597059);
     }
 
+    #[test]
+    fn text_color_alpha() {
+        let color = TextColor::parse("#a1b2c3").unwrap();
+        assert_eq!(color.argb(), 0xFFA1B2C3);
+
+        let color = color.with_alpha(0x80);
+        assert_eq!(color.argb(), 0x80A1B2C3);
+        assert_eq!(color.value, 0xA1B2C3);
+        // alpha isn't compared
+        assert_eq!(TextColor::RED.with_alpha(0x80), TextColor::RED);
+        let hash = |color: &TextColor| {
+            let mut hasher = std::hash::DefaultHasher::new();
+            color.hash(&mut hasher);
+            hasher.finish()
+        };
+        assert_eq!(
+            hash(&TextColor::RED.with_alpha(0x80)),
+            hash(&TextColor::RED)
+        );
+        // or serialized
+        assert_eq!(serde_json::to_string(&color).unwrap(), "\"#A1B2C3\"");
+        assert_eq!(
+            serde_json::to_string(&TextColor::RED.with_alpha(0)).unwrap(),
+            "\"red\""
+        );
+    }
+
+    #[test]
+    fn text_color_case_insensitive_eq() {
+        let upper = TextColor {
+            name: Some("RED".into()),
+            ..TextColor::RED
+        };
+        assert_eq!(upper, TextColor::RED);
+        assert_eq!(TextColor::parse("Red").unwrap(), TextColor::RED);
+
+        let mut colors = std::collections::HashSet::new();
+        colors.insert(TextColor::RED);
+        colors.insert(upper.clone());
+        assert_eq!(colors.len(), 1);
+
+        // the name still matters if the casing is the same
+        assert_ne!(TextColor::from_rgb(TextColor::RED.value), TextColor::RED);
+        assert_ne!(
+            TextColor {
+                name: Some("blue".into()),
+                ..TextColor::RED
+            },
+            TextColor::RED
+        );
+        // and it's serialized in lowercase
+        assert_eq!(serde_json::to_string(&upper).unwrap(), "\"red\"");
+    }
+
     #[test]
     fn ansi_difference_should_reset() {
         let style_a = Style {
@@ -665,6 +1004,85 @@ mod tests {
         assert_eq!(ansi_difference, Ansi::ITALIC)
     }
 
+    #[test]
+    fn legacy_difference_should_reset() {
+        let style_a = Style {
+            bold: Some(true),
+            italic: Some(true),
+            ..Style::default()
+        };
+        let style_b = Style {
+            bold: Some(false),
+            italic: Some(true),
+            ..Style::default()
+        };
+        assert_eq!(
+            style_a.compare_legacy(&style_b),
+            vec![ChatFormatting::Reset, ChatFormatting::Italic]
+        );
+    }
+    #[test]
+    fn legacy_difference_shouldnt_reset() {
+        let style_a = Style {
+            bold: Some(true),
+            ..Style::default()
+        };
+        let style_b = Style {
+            bold: Some(true),
+            italic: Some(true),
+            ..Style::default()
+        };
+        assert_eq!(
+            style_a.compare_legacy(&style_b),
+            vec![ChatFormatting::Italic]
+        );
+    }
+    #[test]
+    fn legacy_difference_color_change_reapplies_formats() {
+        let style_a = Style {
+            bold: Some(true),
+            color: TextColor::parse("red"),
+            ..Style::default()
+        };
+        let style_b = Style {
+            bold: Some(true),
+            color: TextColor::parse("#55FF55"),
+            ..Style::default()
+        };
+        assert_eq!(
+            style_a.compare_legacy(&style_b),
+            vec![ChatFormatting::Green, ChatFormatting::Bold]
+        );
+        assert_eq!(style_b.compare_legacy(&style_b), vec![]);
+        assert_eq!(
+            style_b.compare_legacy(&Style::default()),
+            vec![ChatFormatting::Reset]
+        );
+    }
+
+    #[test]
+    fn legacy_difference_same_hex_color() {
+        let style_a = Style {
+            color: TextColor::parse("#123456"),
+            ..Style::default()
+        };
+        let style_b = Style {
+            color: TextColor::parse("#123456"),
+            bold: Some(true),
+            ..Style::default()
+        };
+        // the color can't be represented, but it didn't change either
+        assert_eq!(style_a.compare_legacy(&style_b), vec![ChatFormatting::Bold]);
+        assert_eq!(style_a.compare_legacy(&style_a), vec![]);
+        assert_eq!(
+            style_a.compare_legacy(&Style {
+                color: TextColor::parse("#654321"),
+                ..Style::default()
+            }),
+            vec![ChatFormatting::Reset]
+        );
+    }
+
     #[test]
     fn test_from_code() {
         assert_eq!(
@@ -678,6 +1096,13 @@ mod tests {
         let mut style = Style::default();
         style.apply_formatting(&ChatFormatting::Bold);
         style.apply_formatting(&ChatFormatting::Red);
-        assert_eq!(style.color, Some(TextColor::from_rgb(16733525)));
+        assert_eq!(
+            style.color,
+            Some(TextColor::new(16733525, Some("red".into())))
+        );
+        assert_eq!(
+            serde_json::to_value(&style).unwrap(),
+            serde_json::json!({ "bold": true, "color": "red" })
+        );
     }
 }
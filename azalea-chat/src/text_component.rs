@@ -60,6 +60,26 @@ pub const LEGACY_FORMATTING_CODE_SYMBOL: char = '§';
 /// Technically in Minecraft this is done when displaying the text, but AFAIK
 /// it's the same as just doing it in TextComponent
 pub fn legacy_color_code_to_text_component(legacy_color_code: &str) -> TextComponent {
+    legacy_color_code_to_text_component_with_prefix(
+        legacy_color_code,
+        LEGACY_FORMATTING_CODE_SYMBOL,
+    )
+}
+
+/// Like [`legacy_color_code_to_text_component`], but the formatting codes
+/// start with the given character instead of `§`.
+///
+/// This is useful for parsing text that was written by hand, where codes are
+/// usually written like `&c` since `§` is hard to type.
+///
+/// When using a prefix other than `§`, writing the prefix twice (like `&&`)
+/// results in a single literal prefix character, and a prefix that isn't
+/// followed by a valid formatting code is kept as-is. With `§`, invalid codes
+/// are dropped like in vanilla.
+pub fn legacy_color_code_to_text_component_with_prefix(
+    legacy_color_code: &str,
+    prefix: char,
+) -> TextComponent {
     if legacy_color_code.is_empty() {
         return TextComponent::new("");
     }
@@ -75,13 +95,18 @@ pub fn legacy_color_code_to_text_component(legacy_color_code: &str) -> TextCompo
     // the formatter code symbol
     let mut i = 0;
     while i < legacy_color_code.chars().count() {
-        if legacy_color_code.chars().nth(i).unwrap() == LEGACY_FORMATTING_CODE_SYMBOL {
+        if legacy_color_code.chars().nth(i).unwrap() == prefix {
             let formatting_code = legacy_color_code.chars().nth(i + 1);
             let Some(formatting_code) = formatting_code else {
+                if prefix != LEGACY_FORMATTING_CODE_SYMBOL {
+                    cur_component.text.push(prefix);
+                }
                 i += 1;
                 continue;
             };
-            if formatting_code == '#' {
+            if formatting_code == prefix && prefix != LEGACY_FORMATTING_CODE_SYMBOL {
+                cur_component.text.push(prefix);
+            } else if formatting_code == '#' {
                 let color = legacy_color_code
                     .chars()
                     .skip(i + 1)
@@ -104,6 +129,12 @@ pub fn legacy_color_code_to_text_component(legacy_color_code: &str) -> TextCompo
                     cur_component.text = "".to_owned();
                 };
                 cur_component.base.style.apply_formatting(&formatter);
+            } else if prefix != LEGACY_FORMATTING_CODE_SYMBOL {
+                // not a formatting code, so keep the prefix and read the next character
+                // normally
+                cur_component.text.push(prefix);
+                i += 1;
+                continue;
             }
             i += 1;
         } else {
@@ -125,6 +156,28 @@ pub fn legacy_color_code_to_text_component(legacy_color_code: &str) -> TextCompo
     final_component
 }
 
+/// Remove every legacy formatting code (like `§c`) from a string, leaving only
+/// the plain text.
+///
+/// Codes are matched case-insensitively like in vanilla, so `§C` is stripped
+/// too. A `§` that isn't followed by a valid formatting code (including one at
+/// the very end of the string) is left as-is.
+pub fn strip_legacy_formatting(s: &str) -> String {
+    let mut stripped = String::with_capacity(s.len());
+    let mut chars = s.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c == LEGACY_FORMATTING_CODE_SYMBOL
+            && let Some(&code) = chars.peek()
+            && ChatFormatting::from_code(code.to_ascii_lowercase()).is_some()
+        {
+            chars.next();
+            continue;
+        }
+        stripped.push(c);
+    }
+    stripped
+}
+
 impl TextComponent {
     pub fn new(text: impl Into<String>) -> Self {
         let text = text.into();
@@ -258,12 +311,54 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_legacy_color_code_with_ampersand_prefix() {
+        let component = FormattedText::Text(legacy_color_code_to_text_component_with_prefix(
+            "&cred &ltext",
+            '&',
+        ));
+        assert_eq!(
+            component.to_ansi(),
+            format!(
+                "{RED}red {BOLD}text{RESET}",
+                RED = Ansi::rgb(ChatFormatting::Red.color().unwrap()),
+                BOLD = Ansi::BOLD,
+                RESET = Ansi::RESET
+            )
+        );
+
+        let component = FormattedText::Text(legacy_color_code_to_text_component_with_prefix(
+            "Tom && Jerry & co &",
+            '&',
+        ));
+        assert_eq!(component.to_string(), "Tom & Jerry & co &");
+
+        // but a doubled § is still dropped like in vanilla
+        let component = FormattedText::Text(legacy_color_code_to_text_component("a§§b"));
+        assert_eq!(component.to_string(), "ab");
+    }
+
     #[test]
     fn test_serialize_to_json() {
         let component = TextComponent::new("Hello §aworld".to_owned()).get();
         assert_eq!(
             serde_json::to_string(&component).unwrap(),
-            "{\"text\":\"\",\"extra\":[\"Hello \",{\"text\":\"world\",\"color\":\"#55FF55\"}]}"
+            "{\"text\":\"\",\"extra\":[\"Hello \",{\"text\":\"world\",\"color\":\"green\"}]}"
+        );
+    }
+
+    #[test]
+    fn test_strip_legacy_formatting() {
+        assert_eq!(
+            strip_legacy_formatting("§aHypixel Network  §c[1.8-1.18]\n§b§lHAPPY HOLIDAYS"),
+            "Hypixel Network  [1.8-1.18]\nHAPPY HOLIDAYS"
+        );
+        assert_eq!(
+            strip_legacy_formatting("§lbold§r and §zinvalid"),
+            "bold and §zinvalid"
         );
+        assert_eq!(strip_legacy_formatting("dangling §"), "dangling §");
+        assert_eq!(strip_legacy_formatting("no codes"), "no codes");
+        assert_eq!(strip_legacy_formatting("§AHi §Lthere§R!"), "Hi there!");
     }
 }
@@ -65,6 +65,9 @@ impl simdnbt::Serialize for TranslatableComponent {
     fn to_compound(self) -> simdnbt::owned::NbtCompound {
         let mut compound = simdnbt::owned::NbtCompound::new();
         compound.insert("translate", self.key);
+        if let Some(fallback) = self.fallback {
+            compound.insert("fallback", fallback);
+        }
         compound.extend(self.base.style.to_compound());
 
         compound.insert("with", serialize_args_as_nbt(self.args));
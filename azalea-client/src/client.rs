@@ -29,7 +29,9 @@ use crate::{
     connection::RawConnection,
     cookies::ServerCookies,
     interact::BlockStatePredictionHandler,
-    local_player::{Experience, Hunger, PermissionLevel, TabList, TabListResource, WorldHolder},
+    local_player::{
+        Experience, Hunger, PermissionLevel, TabList, TabListResource, WorldBorder, WorldHolder,
+    },
     mining,
     movement::LastSentLookDirection,
     player::retroactively_add_game_profile_component,
@@ -67,6 +69,7 @@ pub struct JoinedClientBundle {
     pub chunk_batch_info: ChunkBatchInfo,
     pub hunger: Hunger,
     pub experience: Experience,
+    pub world_border: WorldBorder,
     pub cookies: ServerCookies,
 
     pub entity_id_index: EntityIdIndex,
@@ -1,6 +1,6 @@
 use std::{collections::HashMap, sync::Arc};
 
-use azalea_core::game_type::GameMode;
+use azalea_core::{game_type::GameMode, position::Vec3};
 use azalea_world::{PartialWorld, World};
 use bevy_ecs::{component::Component, prelude::*};
 use derive_more::{Deref, DerefMut};
@@ -135,6 +135,67 @@ impl Default for Experience {
     }
 }
 
+/// The world border that the server sent us, which entities can't move past.
+///
+/// The border is a square centered on [`Self::center_x`] and
+/// [`Self::center_z`], and it always extends infinitely up and down.
+#[derive(Clone, Component, Debug, PartialEq)]
+pub struct WorldBorder {
+    pub center_x: f64,
+    pub center_z: f64,
+    /// The length of each side of the border, in blocks.
+    ///
+    /// If the border is currently growing or shrinking, this is the size that
+    /// it's moving towards.
+    pub size: f64,
+    /// The maximum distance that the border can be from the center, in blocks.
+    pub absolute_max_size: u32,
+    /// How close to the border we have to be (in blocks) for the warning to
+    /// be shown.
+    pub warning_blocks: u32,
+    /// How many seconds before a moving border reaches us that the warning
+    /// will be shown.
+    pub warning_time: u32,
+}
+
+impl Default for WorldBorder {
+    fn default() -> Self {
+        WorldBorder {
+            center_x: 0.,
+            center_z: 0.,
+            size: 59_999_968.,
+            absolute_max_size: 29_999_984,
+            warning_blocks: 5,
+            warning_time: 15,
+        }
+    }
+}
+impl WorldBorder {
+    pub fn min_x(&self) -> f64 {
+        (self.center_x - self.size / 2.).max(-(self.absolute_max_size as f64))
+    }
+    pub fn max_x(&self) -> f64 {
+        (self.center_x + self.size / 2.).min(self.absolute_max_size as f64)
+    }
+    pub fn min_z(&self) -> f64 {
+        (self.center_z - self.size / 2.).max(-(self.absolute_max_size as f64))
+    }
+    pub fn max_z(&self) -> f64 {
+        (self.center_z + self.size / 2.).min(self.absolute_max_size as f64)
+    }
+
+    /// Returns whether the given position is inside the border.
+    ///
+    /// The Y coordinate is ignored.
+    pub fn contains(&self, pos: Vec3) -> bool {
+        // isWithinBounds
+        pos.x >= self.min_x()
+            && pos.x < self.max_x()
+            && pos.z >= self.min_z()
+            && pos.z < self.max_z()
+    }
+}
+
 impl WorldHolder {
     /// Create a new `WorldHolder` for the given entity.
     ///
@@ -16,7 +16,8 @@ impl Plugin for BlockUpdatePlugin {
             // has to be after ReceiveChunkEvent is handled so if we get chunk+blockupdate in one
             // Update then the block update actually gets applied
             handle_block_update_event.after(handle_receive_chunk_event),
-        );
+        )
+        .add_message::<BlockUpdateEvent>();
     }
 }
 
@@ -31,18 +32,48 @@ pub struct QueuedServerBlockUpdates {
     pub list: Vec<(BlockPos, BlockState)>,
 }
 
+/// A block in the world was changed by the server.
+///
+/// This is sent once for every changed block, including each of the blocks
+/// from a section blocks update packet. Updates that don't change the block
+/// (like when the server confirms a block that we predicted) don't send this
+/// event.
+///
+/// If the server changes a block while we're predicting it, the event is sent
+/// when the server acknowledges our prediction and the block is rolled back to
+/// the server's state.
+#[derive(Clone, Debug, Message)]
+pub struct BlockUpdateEvent {
+    pub entity: Entity,
+    pub pos: BlockPos,
+    pub old_state: BlockState,
+    pub new_state: BlockState,
+}
+
 pub fn handle_block_update_event(
     mut query: Query<(
+        Entity,
         &mut QueuedServerBlockUpdates,
         &WorldHolder,
         &mut BlockStatePredictionHandler,
     )>,
+    mut block_update_events: MessageWriter<BlockUpdateEvent>,
 ) {
-    for (mut queued, world_holder, mut prediction_handler) in query.iter_mut() {
+    for (entity, mut queued, world_holder, mut prediction_handler) in query.iter_mut() {
         let world = world_holder.shared.read();
         for (pos, block_state) in queued.list.drain(..) {
-            if !prediction_handler.update_known_server_state(pos, block_state) {
-                world.chunks.set_block_state(pos, block_state);
+            if prediction_handler.update_known_server_state(pos, block_state) {
+                continue;
+            }
+            if let Some(old_state) = world.chunks.set_block_state(pos, block_state)
+                && old_state != block_state
+            {
+                block_update_events.write(BlockUpdateEvent {
+                    entity,
+                    pos,
+                    old_state,
+                    new_state: block_state,
+                });
             }
         }
     }
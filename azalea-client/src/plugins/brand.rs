@@ -1,32 +1,91 @@
-use azalea_buf::AzBuf;
+use std::io::Cursor;
+
+use azalea_buf::{AzBuf, UnsizedByteArray};
 use azalea_protocol::packets::config::s_custom_payload::ServerboundCustomPayload;
+use azalea_registry::identifier::Identifier;
 use bevy_app::prelude::*;
 use bevy_ecs::prelude::*;
+use derive_more::Deref;
+use tracing::warn;
 
 use super::packet::config::SendConfigPacketEvent;
-use crate::{client_information::send_client_information, packet::login::InLoginState};
+use crate::{
+    client_information::send_client_information, custom_payload::CustomPayloadHandlers,
+    packet::login::InLoginState,
+};
 
-/// Send a [`ServerboundCustomPayload`] with "vanilla" as the brand on join.
+/// Send a [`ServerboundCustomPayload`] with our [`ClientBrand`] on join, and
+/// keep track of the brand that the server sends us in [`ServerBrand`].
 ///
 /// You can [disable this plugin](https://azalea.rs/azalea/struct.ClientBuilder.html#method.new_without_plugins)
-/// and register your own system if you'd like to send a different brand.
+/// and register your own system if you'd like to send the brand differently.
 pub struct BrandPlugin;
 impl Plugin for BrandPlugin {
     fn build(&self, app: &mut App) {
         app.add_systems(Update, send_brand.before(send_client_information));
+
+        app.init_resource::<CustomPayloadHandlers>()
+            .world_mut()
+            .resource_mut::<CustomPayloadHandlers>()
+            .register(Identifier::new("brand"), handle_server_brand);
     }
 }
 
-pub fn send_brand(mut commands: Commands, mut removed: RemovedComponents<InLoginState>) {
-    for entity in removed.read() {
-        let mut brand_data = Vec::new();
+/// A component that contains the brand that the server sent us on the
+/// `minecraft:brand` channel, like "vanilla" or "Paper".
+///
+/// This is only present if the server has sent its brand.
+#[derive(Clone, Component, Debug, Deref, Eq, PartialEq)]
+pub struct ServerBrand(pub String);
+
+/// The [`CustomPayloadHandlers`] handler for the `minecraft:brand` channel,
+/// which stores the brand that the server sent us in [`ServerBrand`].
+pub fn handle_server_brand(commands: &mut Commands, entity: Entity, data: &[u8]) {
+    match String::azalea_read(&mut Cursor::new(data)) {
+        Ok(brand) => {
+            commands.entity(entity).insert(ServerBrand(brand));
+        }
+        Err(e) => {
+            warn!("Failed to read server brand: {e}");
+        }
+    }
+}
+
+/// A component that contains the brand that our client sends to the server
+/// when it joins.
+///
+/// This defaults to "vanilla". To change it, either modify this component
+/// before the client leaves the login state, or use `Client::set_brand`.
+#[derive(Clone, Component, Debug, Deref, Eq, PartialEq)]
+pub struct ClientBrand(pub String);
+impl ClientBrand {
+    /// Serialize the brand into the data for a custom payload on the
+    /// `minecraft:brand` channel.
+    pub fn payload_data(&self) -> UnsizedByteArray {
+        let mut data = Vec::new();
+        self.0.azalea_write(&mut data).unwrap();
+        data.into()
+    }
+}
+impl Default for ClientBrand {
+    fn default() -> Self {
         // pretend to be vanilla
-        "vanilla".to_owned().azalea_write(&mut brand_data).unwrap();
+        Self("vanilla".to_owned())
+    }
+}
+
+pub fn send_brand(
+    mut commands: Commands,
+    mut removed: RemovedComponents<InLoginState>,
+    query: Query<&ClientBrand>,
+) {
+    for entity in removed.read() {
+        let brand = query.get(entity).cloned().unwrap_or_default();
         commands.trigger(SendConfigPacketEvent::new(
             entity,
             ServerboundCustomPayload {
                 identifier: "brand".into(),
-                data: brand_data.into(),
+                data: brand.payload_data(),
             },
         ));
     }
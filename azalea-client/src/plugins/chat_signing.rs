@@ -12,7 +12,11 @@ use chrono::Utc;
 use tracing::{debug, error};
 use uuid::Uuid;
 
-use super::{client_chat, login::IsAuthenticated, packet::game::SendGamePacketEvent};
+use super::{
+    client_chat::{self, ChatSigningMode},
+    login::IsAuthenticated,
+    packet::game::SendGamePacketEvent,
+};
 use crate::{InGameState, account::Account};
 
 pub struct ChatSigningPlugin;
@@ -93,6 +97,7 @@ pub fn request_certs_if_needed(
             &Account,
             Option<&OnlyRefreshCertsAfter>,
             Option<&ChatSigningSession>,
+            Option<&ChatSigningMode>,
         ),
         (
             Without<RequestCertsTask>,
@@ -101,7 +106,13 @@ pub fn request_certs_if_needed(
         ),
     >,
 ) {
-    for (entity, account, only_refresh_certs_after, chat_signing_session) in query.iter_mut() {
+    for (entity, account, only_refresh_certs_after, chat_signing_session, signing_mode) in
+        query.iter_mut()
+    {
+        // we don't need a chat session if we're not signing messages
+        if signing_mode == Some(&ChatSigningMode::Unsigned) {
+            continue;
+        }
         if let Some(only_refresh_certs_after) = only_refresh_certs_after
             && only_refresh_certs_after.refresh_at > Instant::now()
         {
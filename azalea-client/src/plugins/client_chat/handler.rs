@@ -6,7 +6,9 @@ use azalea_protocol::packets::{
 };
 use bevy_ecs::prelude::*;
 
-use super::ChatKind;
+#[cfg(feature = "online-mode")]
+use super::ChatSigningMode;
+use super::{ChatKind, MAX_CHAT_LENGTH};
 use crate::packet::game::SendGamePacketEvent;
 #[cfg(feature = "online-mode")]
 use crate::{account::Account, chat_signing::ChatSigningSession};
@@ -32,14 +34,18 @@ pub struct SendChatKindEvent {
 pub fn handle_send_chat_kind_event(
     mut events: MessageReader<SendChatKindEvent>,
     mut commands: Commands,
-    #[cfg(feature = "online-mode")] mut query: Query<(&Account, &mut ChatSigningSession)>,
+    #[cfg(feature = "online-mode")] mut query: Query<(
+        &Account,
+        &mut ChatSigningSession,
+        Option<&ChatSigningMode>,
+    )>,
 ) {
     for event in events.read() {
         let content = event
             .content
             .chars()
             .filter(|c| !matches!(c, '\x00'..='\x1F' | '\x7F' | '§'))
-            .take(256)
+            .take(MAX_CHAT_LENGTH)
             .collect::<String>();
 
         let timestamp = SystemTime::now();
@@ -49,7 +55,9 @@ pub fn handle_send_chat_kind_event(
                 let salt = azalea_crypto::signing::make_salt();
 
                 #[cfg(feature = "online-mode")]
-                let signature = if let Ok((account, mut chat_session)) = query.get_mut(event.entity)
+                let signature = if let Ok((account, mut chat_session, signing_mode)) =
+                    query.get_mut(event.entity)
+                    && signing_mode != Some(&ChatSigningMode::Unsigned)
                 {
                     Some(create_signature(
                         account,
@@ -202,6 +202,12 @@ pub struct ChatReceivedEvent {
     pub packet: ChatPacket,
 }
 
+/// The maximum number of characters that vanilla servers accept in a chat
+/// message or command.
+///
+/// Longer messages are truncated before they're sent.
+pub const MAX_CHAT_LENGTH: usize = 256;
+
 /// Send a chat message (or command, if it starts with a slash) to the server.
 #[derive(Message)]
 pub struct SendChatEvent {
@@ -230,6 +236,22 @@ pub fn handle_send_chat_event(
     }
 }
 
+/// Whether a client should sign the chat messages that it sends.
+///
+/// Many offline-mode servers and proxies accept unsigned chat. Setting this to
+/// [`ChatSigningMode::Unsigned`] makes messages get sent without a signature,
+/// and the client won't create a chat session with the server.
+///
+/// If this component isn't present, the client uses
+/// [`ChatSigningMode::Signed`]. Note that chat can only be signed by online
+/// accounts, and only when the `online-mode` feature is enabled.
+#[derive(Clone, Component, Copy, Debug, Default, Eq, PartialEq)]
+pub enum ChatSigningMode {
+    #[default]
+    Signed,
+    Unsigned,
+}
+
 /// A kind of chat packet, either a chat message or a command.
 pub enum ChatKind {
     Message,
@@ -16,7 +16,7 @@ use azalea_protocol::{
         game::ClientboundGamePacket, login::ClientboundLoginPacket,
     },
     read::{ReadPacketError, deserialize_packet},
-    write::serialize_packet,
+    write::serialize_packet_into,
 };
 use bevy_app::prelude::*;
 use bevy_ecs::prelude::*;
@@ -162,6 +162,15 @@ fn log_for_error(error: &ReadPacketError) {
     }
 }
 
+/// A component that contains the compression threshold that the server sent
+/// us while logging in.
+///
+/// Packets that are at least this many bytes long are compressed with zlib. A
+/// value of `None` means that compression is disabled, which is also the case
+/// if this component isn't present.
+#[derive(Clone, Component, Copy, Debug, Default, Eq, PartialEq)]
+pub struct CompressionThreshold(pub Option<i32>);
+
 /// The client's connection to the server.
 #[derive(Component)]
 pub struct RawConnection {
@@ -208,6 +217,7 @@ impl RawConnection {
             enc_cipher: writer.enc_cipher,
             network_packet_writer_tx,
             writer_task,
+            write_buf: Vec::new(),
         });
 
         conn
@@ -251,6 +261,21 @@ impl RawConnection {
         Ok(())
     }
 
+    /// Like [`Self::write`], but the packet is serialized into the given
+    /// buffer so its allocation can be reused for the next packet.
+    ///
+    /// The buffer is cleared before being written to.
+    pub fn write_into<P: ProtocolPacket + Debug>(
+        &mut self,
+        packet: impl Packet<P>,
+        buf: &mut Vec<u8>,
+    ) -> Result<(), WritePacketError> {
+        if let Some(network) = &mut self.network {
+            network.write_into(packet, buf)?;
+        }
+        Ok(())
+    }
+
     pub fn net_conn(&mut self) -> Option<&mut NetworkConnection> {
         self.network.as_mut()
     }
@@ -271,6 +296,12 @@ pub fn handle_raw_packet(
         ConnectionProtocol::Game => {
             let packet = Arc::new(deserialize_packet::<ClientboundGamePacket>(stream)?);
             trace!("Packet: {packet:?}");
+            #[cfg(feature = "packet-metrics")]
+            ecs.entity_mut(entity)
+                .entry::<crate::packet_metrics::PacketMetrics>()
+                .or_default()
+                .get_mut()
+                .record(packet.name(), raw_packet.len());
             game::process_packet(ecs, entity, packet.as_ref());
             queued_packet_events
                 .game
@@ -311,15 +342,33 @@ pub struct NetworkConnection {
     /// These will not be modified further, they should already be serialized
     /// and compressed and encrypted before being added here.
     network_packet_writer_tx: mpsc::UnboundedSender<Box<[u8]>>,
+    /// A scratch buffer that packets are serialized into by [`Self::write`],
+    /// so we don't have to allocate a new one for every packet.
+    write_buf: Vec<u8>,
 }
 impl NetworkConnection {
     pub fn write<P: ProtocolPacket + Debug>(
         &mut self,
         packet: impl Packet<P>,
+    ) -> Result<(), WritePacketError> {
+        let mut buf = mem::take(&mut self.write_buf);
+        let res = self.write_into(packet, &mut buf);
+        self.write_buf = buf;
+        res
+    }
+
+    /// Serialize the packet into `buf` and send it.
+    ///
+    /// The buffer is cleared first, and can be reused for the next packet to
+    /// avoid allocating.
+    pub fn write_into<P: ProtocolPacket + Debug>(
+        &mut self,
+        packet: impl Packet<P>,
+        buf: &mut Vec<u8>,
     ) -> Result<(), WritePacketError> {
         let packet = packet.into_variant();
-        let raw_packet = serialize_packet(&packet)?;
-        self.write_raw(&raw_packet)?;
+        serialize_packet_into(&packet, buf)?;
+        self.write_raw(buf)?;
 
         Ok(())
     }
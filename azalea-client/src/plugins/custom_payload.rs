@@ -0,0 +1,100 @@
+//! Custom payloads (also known as plugin messages), which are arbitrary data
+//! sent by the server on a named channel.
+
+use std::{collections::HashMap, fmt};
+
+use azalea_registry::identifier::Identifier;
+use bevy_app::{App, Plugin};
+use bevy_ecs::prelude::*;
+use tracing::trace;
+
+pub struct CustomPayloadPlugin;
+impl Plugin for CustomPayloadPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<CustomPayloadHandlers>()
+            .add_observer(handle_custom_payload);
+    }
+}
+
+/// An event that's triggered when the server sends us a custom payload, in
+/// either the configuration or the game state.
+///
+/// This is triggered for every channel, regardless of whether a handler was
+/// registered for it in [`CustomPayloadHandlers`].
+#[derive(Clone, Debug, EntityEvent)]
+pub struct CustomPayloadEvent {
+    /// The client entity that received the payload.
+    pub entity: Entity,
+    /// The channel that the payload was sent on, like `minecraft:brand`.
+    pub channel: Identifier,
+    /// The raw data of the payload. Its format depends on the channel.
+    pub data: Vec<u8>,
+}
+
+/// A function that's called when we receive a custom payload on a specific
+/// channel.
+///
+/// It's given the client entity that received the payload and the raw data.
+pub type CustomPayloadHandler = Box<dyn Fn(&mut Commands, Entity, &[u8]) + Send + Sync>;
+
+/// A resource that maps custom payload channels to the functions that should
+/// handle them.
+///
+/// Payloads on channels that don't have a handler are ignored (other than
+/// still triggering a [`CustomPayloadEvent`]).
+///
+/// ```
+/// # use azalea_client::custom_payload::CustomPayloadHandlers;
+/// # use azalea_registry::identifier::Identifier;
+/// # use bevy_ecs::prelude::*;
+/// fn register_handlers(mut handlers: ResMut<CustomPayloadHandlers>) {
+///     handlers.register(Identifier::new("mymod:hello"), |_commands, entity, data| {
+///         println!("{entity} got {} bytes on mymod:hello", data.len());
+///     });
+/// }
+/// ```
+#[derive(Default, Resource)]
+pub struct CustomPayloadHandlers {
+    handlers: HashMap<Identifier, CustomPayloadHandler>,
+}
+impl CustomPayloadHandlers {
+    /// Register a handler for the given channel, replacing the previous one
+    /// if there was one.
+    pub fn register(
+        &mut self,
+        channel: Identifier,
+        handler: impl Fn(&mut Commands, Entity, &[u8]) + Send + Sync + 'static,
+    ) -> Option<CustomPayloadHandler> {
+        self.handlers.insert(channel, Box::new(handler))
+    }
+    /// Remove the handler for the given channel, returning it if it existed.
+    pub fn unregister(&mut self, channel: &Identifier) -> Option<CustomPayloadHandler> {
+        self.handlers.remove(channel)
+    }
+    pub fn get(&self, channel: &Identifier) -> Option<&CustomPayloadHandler> {
+        self.handlers.get(channel)
+    }
+    pub fn contains(&self, channel: &Identifier) -> bool {
+        self.handlers.contains_key(channel)
+    }
+}
+impl fmt::Debug for CustomPayloadHandlers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.handlers.keys()).finish()
+    }
+}
+
+pub fn handle_custom_payload(
+    custom_payload: On<CustomPayloadEvent>,
+    handlers: Res<CustomPayloadHandlers>,
+    mut commands: Commands,
+) {
+    if let Some(handler) = handlers.get(&custom_payload.channel) {
+        handler(&mut commands, custom_payload.entity, &custom_payload.data);
+    } else {
+        trace!(
+            "No handler registered for custom payload channel {}",
+            custom_payload.channel
+        );
+    }
+}
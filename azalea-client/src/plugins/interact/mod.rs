@@ -139,8 +139,18 @@ impl BlockStatePredictionHandler {
         }
     }
 
-    pub fn end_prediction_up_to(&mut self, seq: u32, world: &World) {
+    /// Stop predicting the blocks that were changed up to the given sequence
+    /// number, and roll them back to the state that the server last sent us.
+    ///
+    /// Returns the blocks that were rolled back, as `(pos, predicted_state,
+    /// server_state)`.
+    pub fn end_prediction_up_to(
+        &mut self,
+        seq: u32,
+        world: &World,
+    ) -> Vec<(BlockPos, BlockState, BlockState)> {
         let mut to_remove = Vec::new();
+        let mut rolled_back = Vec::new();
         for (pos, state) in &self.server_state {
             if state.seq > seq {
                 continue;
@@ -154,6 +164,7 @@ impl BlockStatePredictionHandler {
                 continue;
             }
             world.set_block_state(*pos, server_block_state);
+            rolled_back.push((*pos, client_block_state, server_block_state));
             // TODO: implement these two functions
             // if is_colliding(player, *pos, server_block_state) {
             //     abs_snap_to(state.player_pos);
@@ -163,6 +174,8 @@ impl BlockStatePredictionHandler {
         for pos in to_remove {
             self.server_state.remove(&pos);
         }
+
+        rolled_back
     }
 }
 
@@ -1,5 +1,9 @@
-use std::sync::Arc;
+use std::{
+    sync::Arc,
+    time::{Duration, Instant},
+};
 
+use azalea_chat::FormattedText;
 use azalea_entity::{LocalEntity, indexing::EntityUuidIndex};
 use azalea_protocol::{
     address::ResolvedAddr,
@@ -20,9 +24,11 @@ use tokio::sync::mpsc;
 use tracing::{debug, warn};
 
 use crate::{
-    LocalPlayerBundle,
+    InConfigState, LocalPlayerBundle,
     account::Account,
+    brand::ClientBrand,
     connection::RawConnection,
+    disconnect::DisconnectEvent,
     local_player::WorldHolder,
     packet::login::{InLoginState, SendLoginPacketEvent},
 };
@@ -40,6 +46,14 @@ impl Plugin for JoinPlugin {
                     poll_create_connection_task,
                 )
                     .chain(),
+            )
+            .add_systems(
+                Update,
+                (
+                    start_connection_phase_timer,
+                    disconnect_on_connection_phase_timeout,
+                )
+                    .chain(),
             );
     }
 }
@@ -75,6 +89,43 @@ pub struct ConnectOpts {
     /// This is useful to set if a server has `prevent-proxy-connections`
     /// enabled.
     pub sessionserver_proxy: Option<Proxy>,
+    /// How long each phase of joining the server is allowed to take before we
+    /// give up.
+    pub timeouts: ConnectTimeouts,
+    /// The minimum capacity of the buffer that packets are read into.
+    ///
+    /// See [`Connection::set_read_buffer_capacity`].
+    pub read_buffer_capacity: usize,
+}
+
+/// The maximum amount of time that each phase of joining a server may take.
+///
+/// If the handshake times out, a [`ConnectionFailedEvent`] is sent with
+/// [`ConnectionError::Timeout`]. If the login or configuration phase times
+/// out, the client is disconnected and a [`DisconnectEvent`] is sent.
+///
+/// All of the timeouts default to 30 seconds.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ConnectTimeouts {
+    /// The time allowed for opening the connection (including connecting to
+    /// the proxy, if any) and sending the handshake packet.
+    pub handshake: Duration,
+    /// The time allowed for the login phase, which ends when the server sends
+    /// the login finished packet.
+    pub login: Duration,
+    /// The time allowed for the configuration phase, which ends when the
+    /// server finishes configuration and we switch to the game state.
+    pub configuration: Duration,
+}
+impl Default for ConnectTimeouts {
+    fn default() -> Self {
+        let timeout = Duration::from_secs(30);
+        Self {
+            handshake: timeout,
+            login: timeout,
+            configuration: timeout,
+        }
+    }
 }
 
 /// An event that's sent when creating the TCP connection and sending the first
@@ -141,6 +192,7 @@ pub fn handle_start_join_server_event(
             LocalEntity,
             // this is inserted early so the user can always access and modify it
             ClientInformation::default(),
+            ClientBrand::default(),
             // ConnectOpts is inserted as a component here
             event.connect_opts.clone(),
             // we don't insert InLoginState until we actually create the connection. note that
@@ -160,12 +212,34 @@ pub fn handle_start_join_server_event(
 
 async fn create_conn_and_send_intention_packet(
     opts: ConnectOpts,
+) -> Result<LoginConn, ConnectionError> {
+    with_handshake_timeout(
+        opts.timeouts.handshake,
+        create_conn_and_send_intention_packet_inner(opts),
+    )
+    .await
+}
+
+/// Wait for a handshake to finish, failing with [`ConnectionError::Timeout`]
+/// if it takes longer than the given timeout.
+pub async fn with_handshake_timeout<T>(
+    timeout: Duration,
+    handshake: impl Future<Output = Result<T, ConnectionError>>,
+) -> Result<T, ConnectionError> {
+    tokio::time::timeout(timeout, handshake)
+        .await
+        .map_err(|_| ConnectionError::Timeout(timeout))?
+}
+
+async fn create_conn_and_send_intention_packet_inner(
+    opts: ConnectOpts,
 ) -> Result<LoginConn, ConnectionError> {
     let mut conn = if let Some(proxy) = opts.server_proxy {
         Connection::new_with_proxy(&opts.address.socket, proxy).await?
     } else {
         Connection::new(&opts.address.socket).await?
     };
+    conn.set_read_buffer_capacity(opts.read_buffer_capacity);
 
     conn.write(ServerboundIntention {
         protocol_version: PROTOCOL_VERSION,
@@ -241,3 +315,66 @@ pub fn poll_create_connection_task(
         }
     }
 }
+
+/// The time when the client entered the login or configuration state.
+///
+/// This is used for disconnecting the client if the phase takes longer than
+/// allowed by [`ConnectTimeouts`].
+#[derive(Clone, Component, Copy, Debug)]
+pub struct ConnectionPhaseStartedAt(pub Instant);
+
+#[allow(clippy::type_complexity)]
+pub fn start_connection_phase_timer(
+    mut commands: Commands,
+    query: Query<Entity, Or<(Added<InLoginState>, Added<InConfigState>)>>,
+    finished_query: Query<
+        Entity,
+        (
+            With<ConnectionPhaseStartedAt>,
+            Without<InLoginState>,
+            Without<InConfigState>,
+        ),
+    >,
+) {
+    for entity in &query {
+        commands
+            .entity(entity)
+            .insert(ConnectionPhaseStartedAt(Instant::now()));
+    }
+    for entity in &finished_query {
+        commands.entity(entity).remove::<ConnectionPhaseStartedAt>();
+    }
+}
+
+#[allow(clippy::type_complexity)]
+pub fn disconnect_on_connection_phase_timeout(
+    mut commands: Commands,
+    query: Query<(
+        Entity,
+        &ConnectionPhaseStartedAt,
+        &ConnectOpts,
+        Has<InLoginState>,
+        Has<InConfigState>,
+    )>,
+    mut disconnect_events: MessageWriter<DisconnectEvent>,
+) {
+    for (entity, started_at, connect_opts, in_login, in_config) in &query {
+        let (timeout, phase) = if in_login {
+            (connect_opts.timeouts.login, "login")
+        } else if in_config {
+            (connect_opts.timeouts.configuration, "configuration")
+        } else {
+            continue;
+        };
+        if started_at.0.elapsed() < timeout {
+            continue;
+        }
+
+        warn!("{entity:?} timed out during {phase} after {timeout:?}");
+        commands.entity(entity).remove::<ConnectionPhaseStartedAt>();
+        disconnect_events.write(DisconnectEvent {
+            entity,
+            reason: Some(FormattedText::from(format!("Timed out during {phase}"))),
+        });
+    }
+}
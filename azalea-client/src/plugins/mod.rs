@@ -10,6 +10,7 @@ pub mod client_chat;
 pub mod client_information;
 pub mod connection;
 pub mod cookies;
+pub mod custom_payload;
 pub mod disconnect;
 pub mod interact;
 pub mod inventory;
@@ -19,6 +20,8 @@ pub mod login;
 pub mod mining;
 pub mod movement;
 pub mod packet;
+#[cfg(feature = "packet-metrics")]
+pub mod packet_metrics;
 pub mod pong;
 pub mod respawn;
 pub mod task_pool;
@@ -59,7 +62,8 @@ impl PluginGroup for DefaultPlugins {
             .add(connection::ConnectionPlugin)
             .add(login::LoginPlugin)
             .add(join::JoinPlugin)
-            .add(cookies::CookiesPlugin);
+            .add(cookies::CookiesPlugin)
+            .add(custom_payload::CustomPayloadPlugin);
         #[cfg(feature = "online-mode")]
         {
             group = group.add(chat_signing::ChatSigningPlugin);
@@ -24,7 +24,7 @@ use azalea_protocol::{
     packets::{
         Packet,
         game::{
-            ServerboundPlayerCommand, ServerboundPlayerInput,
+            ServerboundGamePacket, ServerboundPlayerCommand, ServerboundPlayerInput,
             s_move_player_pos::ServerboundMovePlayerPos,
             s_move_player_pos_rot::ServerboundMovePlayerPosRot,
             s_move_player_rot::ServerboundMovePlayerRot,
@@ -119,78 +119,46 @@ pub fn send_position(
         mut last_direction,
     ) in query.iter_mut()
     {
-        let packet = {
-            // TODO: the camera being able to be controlled by other entities isn't
-            // implemented yet if !self.is_controlled_camera() { return };
-
-            let x_delta = position.x - last_sent_position.x;
-            let y_delta = position.y - last_sent_position.y;
-            let z_delta = position.z - last_sent_position.z;
-            let y_rot_delta = (direction.y_rot() - last_direction.y_rot) as f64;
-            let x_rot_delta = (direction.x_rot() - last_direction.x_rot) as f64;
-
-            physics_state.position_remainder += 1;
-
-            // boolean sendingPosition = Mth.lengthSquared(xDelta, yDelta, zDelta) >
-            // Mth.square(2.0E-4D) || this.positionReminder >= 20;
-            let is_delta_large_enough =
-                (x_delta.powi(2) + y_delta.powi(2) + z_delta.powi(2)) > 2.0e-4f64.powi(2);
-            let sending_position = is_delta_large_enough || physics_state.position_remainder >= 20;
-            let sending_direction = y_rot_delta != 0.0 || x_rot_delta != 0.0;
-
-            // if self.is_passenger() {
-            //   TODO: posrot packet for being a passenger
-            // }
-            let flags = MoveFlags {
-                on_ground: physics.on_ground(),
-                horizontal_collision: physics.horizontal_collision,
-            };
-            let packet = if sending_position && sending_direction {
-                Some(
-                    ServerboundMovePlayerPosRot {
-                        pos: **position,
-                        look_direction: *direction,
-                        flags,
-                    }
-                    .into_variant(),
-                )
-            } else if sending_position {
-                Some(
-                    ServerboundMovePlayerPos {
-                        pos: **position,
-                        flags,
-                    }
-                    .into_variant(),
-                )
-            } else if sending_direction {
-                Some(
-                    ServerboundMovePlayerRot {
-                        look_direction: *direction,
-                        flags,
-                    }
-                    .into_variant(),
-                )
-            } else if physics.last_on_ground() != physics.on_ground() {
-                Some(ServerboundMovePlayerStatusOnly { flags }.into_variant())
-            } else {
-                None
-            };
-
-            if sending_position {
-                **last_sent_position = **position;
-                physics_state.position_remainder = 0;
-            }
-            if sending_direction {
-                last_direction.y_rot = direction.y_rot();
-                last_direction.x_rot = direction.x_rot();
-            }
+        // TODO: the camera being able to be controlled by other entities isn't
+        // implemented yet if !self.is_controlled_camera() { return };
+
+        physics_state.position_remainder += 1;
+
+        let packet = make_move_player_packet(
+            **position,
+            *direction,
+            **last_sent_position,
+            &last_direction,
+            &physics,
+            physics_state.position_remainder >= 20,
+        );
 
-            let on_ground = physics.on_ground();
-            physics.set_last_on_ground(on_ground);
-            // minecraft checks for autojump here, but also autojump is bad so
+        let sending_position = matches!(
+            packet,
+            Some(
+                ServerboundGamePacket::MovePlayerPos(_)
+                    | ServerboundGamePacket::MovePlayerPosRot(_)
+            )
+        );
+        let sending_direction = matches!(
+            packet,
+            Some(
+                ServerboundGamePacket::MovePlayerRot(_)
+                    | ServerboundGamePacket::MovePlayerPosRot(_)
+            )
+        );
+        if sending_position {
+            **last_sent_position = **position;
+            physics_state.position_remainder = 0;
+        }
+        if sending_direction {
+            last_direction.y_rot = direction.y_rot();
+            last_direction.x_rot = direction.x_rot();
+        }
 
-            packet
-        };
+        let on_ground = physics.on_ground();
+        physics.set_last_on_ground(on_ground);
+        // minecraft checks for autojump here, but also autojump is bad so
 
         if let Some(packet) = packet {
             commands.trigger(SendGamePacketEvent {
@@ -201,6 +169,77 @@ pub fn send_position(
     }
 }
 
+/// Create the movement packet that vanilla would send to the server, based on
+/// our current and last sent position and rotation.
+///
+/// The smallest packet that contains the changes is picked, so for example
+/// only changing the rotation results in a [`ServerboundMovePlayerRot`]. The
+/// `on_ground` flag is taken from the [`Physics`] component.
+///
+/// If `force_send_position` is true, the position will be sent even if it
+/// didn't change. Vanilla does this at least once every 20 ticks.
+///
+/// Returns `None` if nothing changed and no packet has to be sent.
+pub fn make_move_player_packet(
+    position: Vec3,
+    direction: LookDirection,
+    last_sent_position: Vec3,
+    last_sent_direction: &LastSentLookDirection,
+    physics: &Physics,
+    force_send_position: bool,
+) -> Option<ServerboundGamePacket> {
+    let x_delta = position.x - last_sent_position.x;
+    let y_delta = position.y - last_sent_position.y;
+    let z_delta = position.z - last_sent_position.z;
+    let y_rot_delta = (direction.y_rot() - last_sent_direction.y_rot) as f64;
+    let x_rot_delta = (direction.x_rot() - last_sent_direction.x_rot) as f64;
+
+    // boolean sendingPosition = Mth.lengthSquared(xDelta, yDelta, zDelta) >
+    // Mth.square(2.0E-4D) || this.positionReminder >= 20;
+    let is_delta_large_enough =
+        (x_delta.powi(2) + y_delta.powi(2) + z_delta.powi(2)) > 2.0e-4f64.powi(2);
+    let sending_position = is_delta_large_enough || force_send_position;
+    let sending_direction = y_rot_delta != 0.0 || x_rot_delta != 0.0;
+
+    // if self.is_passenger() {
+    //   TODO: posrot packet for being a passenger
+    // }
+    let flags = MoveFlags {
+        on_ground: physics.on_ground(),
+        horizontal_collision: physics.horizontal_collision,
+    };
+    if sending_position && sending_direction {
+        Some(
+            ServerboundMovePlayerPosRot {
+                pos: position,
+                look_direction: direction,
+                flags,
+            }
+            .into_variant(),
+        )
+    } else if sending_position {
+        Some(
+            ServerboundMovePlayerPos {
+                pos: position,
+                flags,
+            }
+            .into_variant(),
+        )
+    } else if sending_direction {
+        Some(
+            ServerboundMovePlayerRot {
+                look_direction: direction,
+                flags,
+            }
+            .into_variant(),
+        )
+    } else if physics.last_on_ground() != physics.on_ground() {
+        Some(ServerboundMovePlayerStatusOnly { flags }.into_variant())
+    } else {
+        None
+    }
+}
+
 #[derive(Clone, Component, Debug, Default, Eq, PartialEq)]
 pub struct LastSentInput(pub ServerboundPlayerInput);
 pub fn send_player_input_packet(
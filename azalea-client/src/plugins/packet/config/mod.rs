@@ -16,6 +16,7 @@ use crate::{
     client::InConfigState,
     connection::RawConnection,
     cookies::{RequestCookieEvent, StoreCookieEvent},
+    custom_payload::CustomPayloadEvent,
     disconnect::DisconnectEvent,
     local_player::WorldHolder,
     packet::game::{KeepAliveEvent, ResourcePackEvent},
@@ -82,6 +83,14 @@ impl ConfigPacketHandler<'_> {
 
     pub fn custom_payload(&mut self, p: &ClientboundCustomPayload) {
         debug!("Got custom payload packet {p:?}");
+
+        as_system::<Commands>(self.ecs, |mut commands| {
+            commands.trigger(CustomPayloadEvent {
+                entity: self.player,
+                channel: p.identifier.clone(),
+                data: p.data.to_vec(),
+            });
+        });
     }
 
     pub fn disconnect(&mut self, p: &ClientboundDisconnect) {
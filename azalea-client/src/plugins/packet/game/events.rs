@@ -1,6 +1,7 @@
 use std::sync::{Arc, Weak};
 
 use azalea_chat::FormattedText;
+use azalea_core::game_type::GameMode;
 use azalea_protocol::packets::{
     Packet,
     game::{ClientboundGamePacket, ClientboundPlayerCombatKill, ServerboundGamePacket},
@@ -111,6 +112,18 @@ pub struct DeathEvent {
     pub packet: Option<ClientboundPlayerCombatKill>,
 }
 
+/// The game mode of a local player was changed by the server.
+///
+/// This happens when the server tells us that our game mode changed (like
+/// after a `/gamemode` command), or when we respawn in a different game mode.
+/// The new game mode is also available as the [`GameMode`] component.
+#[derive(Clone, Debug, Message)]
+pub struct GameModeChangeEvent {
+    pub entity: Entity,
+    pub old_game_mode: GameMode,
+    pub new_game_mode: GameMode,
+}
+
 /// A KeepAlive packet is sent from the server to verify that the client is
 /// still connected.
 #[derive(Clone, Debug, EntityEvent)]
@@ -31,15 +31,18 @@ use tracing::{debug, error, warn};
 
 use crate::{
     ClientInformation,
-    block_update::QueuedServerBlockUpdates,
+    block_update::{BlockUpdateEvent, QueuedServerBlockUpdates},
     chunks,
     client_chat::{ChatPacket, ChatReceivedEvent},
     connection::RawConnection,
     cookies::{RequestCookieEvent, StoreCookieEvent},
+    custom_payload::CustomPayloadEvent,
     disconnect::DisconnectEvent,
     interact::BlockStatePredictionHandler,
     inventory::{ClientsideCloseContainerEvent, MenuOpenedEvent, SetContainerContentEvent},
-    local_player::{Experience, Hunger, PreviousGameMode, TabList, TabListResource, WorldHolder},
+    local_player::{
+        Experience, Hunger, PreviousGameMode, TabList, TabListResource, WorldBorder, WorldHolder,
+    },
     movement::{KnockbackData, KnockbackEvent},
     packet::{
         as_system, declare_packet_handlers,
@@ -369,6 +372,14 @@ impl GamePacketHandler<'_> {
 
     pub fn custom_payload(&mut self, p: &ClientboundCustomPayload) {
         debug!("Got custom payload packet {p:?}");
+
+        as_system::<Commands>(self.ecs, |mut commands| {
+            commands.trigger(CustomPayloadEvent {
+                entity: self.player,
+                channel: p.identifier.clone(),
+                data: p.data.to_vec(),
+            });
+        });
     }
 
     pub fn change_difficulty(&mut self, p: &ClientboundChangeDifficulty) {
@@ -762,6 +773,24 @@ impl GamePacketHandler<'_> {
 
     pub fn initialize_border(&mut self, p: &ClientboundInitializeBorder) {
         debug!("Got initialize border packet {p:?}");
+
+        self.update_world_border(|world_border| {
+            *world_border = WorldBorder {
+                center_x: p.new_center_x,
+                center_z: p.new_center_z,
+                size: p.new_size,
+                absolute_max_size: p.new_absolute_max_size,
+                warning_blocks: p.warning_blocks,
+                warning_time: p.warning_time,
+            };
+        });
+    }
+
+    fn update_world_border(&mut self, f: impl FnOnce(&mut WorldBorder)) {
+        as_system::<Query<&mut WorldBorder>>(self.ecs, |mut query| {
+            let mut world_border = query.get_mut(self.player).unwrap();
+            f(&mut world_border);
+        });
     }
 
     pub fn set_time(&mut self, _p: &ClientboundSetTime) {
@@ -1044,12 +1073,22 @@ impl GamePacketHandler<'_> {
         #[allow(clippy::single_match)]
         match p.event {
             EventType::ChangeGameMode => {
-                as_system::<Query<&mut GameMode>>(self.ecs, |mut query| {
-                    let mut game_mode = query.get_mut(self.player).unwrap();
-                    if let Some(new_game_mode) = GameMode::from_id(p.param as u8) {
-                        *game_mode = new_game_mode;
-                    }
-                });
+                as_system::<(Query<&mut GameMode>, MessageWriter<_>)>(
+                    self.ecs,
+                    |(mut query, mut events)| {
+                        let mut game_mode = query.get_mut(self.player).unwrap();
+                        if let Some(new_game_mode) = GameMode::from_id(p.param as u8)
+                            && *game_mode != new_game_mode
+                        {
+                            events.write(GameModeChangeEvent {
+                                entity: self.player,
+                                old_game_mode: *game_mode,
+                                new_game_mode,
+                            });
+                            *game_mode = new_game_mode;
+                        }
+                    },
+                );
             }
             _ => {}
         }
@@ -1105,14 +1144,25 @@ impl GamePacketHandler<'_> {
     pub fn award_stats(&mut self, _p: &ClientboundAwardStats) {}
 
     pub fn block_changed_ack(&mut self, p: &ClientboundBlockChangedAck) {
-        as_system::<Query<(&WorldHolder, &mut BlockStatePredictionHandler)>>(
-            self.ecs,
-            |mut query| {
-                let (local_player, mut prediction_handler) = query.get_mut(self.player).unwrap();
-                let world = local_player.shared.read();
-                prediction_handler.end_prediction_up_to(p.seq, &world);
-            },
-        );
+        as_system::<(
+            Query<(&WorldHolder, &mut BlockStatePredictionHandler)>,
+            MessageWriter<BlockUpdateEvent>,
+        )>(self.ecs, |(mut query, mut block_update_events)| {
+            let (local_player, mut prediction_handler) = query.get_mut(self.player).unwrap();
+            let world = local_player.shared.read();
+            // the server's state of the block may have changed while we were predicting it,
+            // and those changes don't send an event until now
+            for (pos, old_state, new_state) in
+                prediction_handler.end_prediction_up_to(p.seq, &world)
+            {
+                block_update_events.write(BlockUpdateEvent {
+                    entity: self.player,
+                    pos,
+                    old_state,
+                    new_state,
+                });
+            }
+        });
     }
 
     pub fn block_destruction(&mut self, _p: &ClientboundBlockDestruction) {}
@@ -1370,17 +1420,31 @@ impl GamePacketHandler<'_> {
                     &GameProfileComponent,
                     &ClientInformation,
                     Option<&mut WorldName>,
+                    Option<&GameMode>,
                 ),
                 With<LocalEntity>,
             >,
             MessageWriter<_>,
+            MessageWriter<_>,
             ResMut<Worlds>,
             Query<&mut LoadedBy, Without<LocalEntity>>,
         )>(
             self.ecs,
-            |(mut commands, mut query, mut events, mut worlds, mut loaded_by_query)| {
-                let Ok((mut world_holder, game_profile, client_information, world_name)) =
-                    query.get_mut(self.player)
+            |(
+                mut commands,
+                mut query,
+                mut events,
+                mut game_mode_events,
+                mut worlds,
+                mut loaded_by_query,
+            )| {
+                let Ok((
+                    mut world_holder,
+                    game_profile,
+                    client_information,
+                    world_name,
+                    old_game_mode,
+                )) = query.get_mut(self.player)
                 else {
                     warn!("Got respawn packet but player doesn't have the required components");
                     return;
@@ -1440,6 +1504,16 @@ impl GamePacketHandler<'_> {
                     EntityKind::Player,
                     new_world_name,
                 );
+                if let Some(&old_game_mode) = old_game_mode
+                    && old_game_mode != p.common.game_type
+                {
+                    game_mode_events.write(GameModeChangeEvent {
+                        entity: self.player,
+                        old_game_mode,
+                        new_game_mode: p.common.game_type,
+                    });
+                }
+
                 // update the local gamemode and metadata things
                 commands.entity(self.player).insert((
                     p.common.game_type,
@@ -1545,11 +1619,24 @@ impl GamePacketHandler<'_> {
 
     pub fn select_advancements_tab(&mut self, _p: &ClientboundSelectAdvancementsTab) {}
     pub fn set_action_bar_text(&mut self, _p: &ClientboundSetActionBarText) {}
-    pub fn set_border_center(&mut self, _p: &ClientboundSetBorderCenter) {}
-    pub fn set_border_lerp_size(&mut self, _p: &ClientboundSetBorderLerpSize) {}
-    pub fn set_border_size(&mut self, _p: &ClientboundSetBorderSize) {}
-    pub fn set_border_warning_delay(&mut self, _p: &ClientboundSetBorderWarningDelay) {}
-    pub fn set_border_warning_distance(&mut self, _p: &ClientboundSetBorderWarningDistance) {}
+    pub fn set_border_center(&mut self, p: &ClientboundSetBorderCenter) {
+        self.update_world_border(|world_border| {
+            world_border.center_x = p.new_center_x;
+            world_border.center_z = p.new_center_z;
+        });
+    }
+    pub fn set_border_lerp_size(&mut self, p: &ClientboundSetBorderLerpSize) {
+        self.update_world_border(|world_border| world_border.size = p.new_size);
+    }
+    pub fn set_border_size(&mut self, p: &ClientboundSetBorderSize) {
+        self.update_world_border(|world_border| world_border.size = p.size);
+    }
+    pub fn set_border_warning_delay(&mut self, p: &ClientboundSetBorderWarningDelay) {
+        self.update_world_border(|world_border| world_border.warning_time = p.warning_delay);
+    }
+    pub fn set_border_warning_distance(&mut self, p: &ClientboundSetBorderWarningDistance) {
+        self.update_world_border(|world_border| world_border.warning_blocks = p.warning_blocks);
+    }
     pub fn set_camera(&mut self, _p: &ClientboundSetCamera) {}
     pub fn set_display_objective(&mut self, _p: &ClientboundSetDisplayObjective) {}
     pub fn set_objective(&mut self, _p: &ClientboundSetObjective) {}
@@ -17,8 +17,13 @@ use tracing::{debug, error};
 
 use super::as_system;
 use crate::{
-    InConfigState, account::Account, connection::RawConnection, cookies::RequestCookieEvent,
-    disconnect::DisconnectEvent, packet::declare_packet_handlers, player::GameProfileComponent,
+    InConfigState,
+    account::Account,
+    connection::{CompressionThreshold, RawConnection},
+    cookies::RequestCookieEvent,
+    disconnect::DisconnectEvent,
+    packet::declare_packet_handlers,
+    player::GameProfileComponent,
 };
 
 pub fn process_packet(ecs: &mut World, player: Entity, packet: &ClientboundLoginPacket) {
@@ -106,13 +111,20 @@ impl LoginPacketHandler<'_> {
     pub fn login_compression(&mut self, p: &ClientboundLoginCompression) {
         debug!("Got compression request {p:?}");
 
-        as_system::<Query<&mut RawConnection>>(self.ecs, |mut query| {
+        // a negative threshold means that compression is disabled
+        let threshold = (p.compression_threshold >= 0).then_some(p.compression_threshold);
+
+        as_system::<(Commands, Query<&mut RawConnection>)>(self.ecs, |(mut commands, mut query)| {
             let mut conn = query
                 .get_mut(self.player)
                 .expect("RawConnection component should be present when receiving packets");
             if let Some(net_conn) = &mut conn.net_conn() {
-                net_conn.set_compression_threshold(Some(p.compression_threshold as u32));
+                net_conn.set_compression_threshold(threshold.map(|t| t as u32));
             }
+
+            commands
+                .entity(self.player)
+                .insert(CompressionThreshold(threshold));
         })
     }
     pub fn custom_query(&mut self, p: &ClientboundCustomQuery) {
@@ -48,6 +48,7 @@ impl Plugin for PacketPlugin {
         .add_message::<game::UpdatePlayerEvent>()
         .add_message::<ChatReceivedEvent>()
         .add_message::<game::DeathEvent>()
+        .add_message::<game::GameModeChangeEvent>()
         .add_message::<game::ResourcePackEvent>()
         .add_message::<game::WorldLoadedEvent>()
         .add_message::<login::ReceiveCustomQueryEvent>();
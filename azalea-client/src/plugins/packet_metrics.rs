@@ -0,0 +1,50 @@
+//! Per-packet-type byte counters, only available with the `packet-metrics`
+//! feature.
+
+use std::collections::HashMap;
+
+use bevy_ecs::prelude::*;
+
+/// The number of bytes and packets that we've received for each type of game
+/// packet.
+///
+/// This is inserted on the client entity when the first game packet is
+/// received, and keeps counting for as long as the connection is open. The
+/// sizes are of the uncompressed and decrypted packets, including the packet
+/// id.
+#[derive(Clone, Component, Debug, Default)]
+pub struct PacketMetrics {
+    packets: HashMap<&'static str, PacketTypeMetrics>,
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct PacketTypeMetrics {
+    /// The number of packets of this type that were received.
+    pub count: u64,
+    /// The total size of every packet of this type that was received.
+    pub bytes: u64,
+}
+
+impl PacketMetrics {
+    pub fn record(&mut self, packet_name: &'static str, size: usize) {
+        let metrics = self.packets.entry(packet_name).or_default();
+        metrics.count += 1;
+        metrics.bytes += size as u64;
+    }
+
+    /// Get the metrics for the packet with the given name (like
+    /// `"set_health"`), or `None` if we haven't received any of that packet.
+    pub fn get(&self, packet_name: &str) -> Option<PacketTypeMetrics> {
+        self.packets.get(packet_name).copied()
+    }
+
+    /// Iterate over the metrics for every type of packet that we've received.
+    pub fn iter(&self) -> impl Iterator<Item = (&'static str, PacketTypeMetrics)> {
+        self.packets.iter().map(|(name, metrics)| (*name, *metrics))
+    }
+
+    /// The total number of bytes received across all packet types.
+    pub fn total_bytes(&self) -> u64 {
+        self.packets.values().map(|metrics| metrics.bytes).sum()
+    }
+}
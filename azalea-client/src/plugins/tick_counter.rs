@@ -17,7 +17,7 @@ pub struct TickCounterPlugin;
 
 impl Plugin for TickCounterPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(
+        app.add_message::<GameTickEvent>().add_systems(
             GameTick,
             increment_counter
                 .before(PhysicsSystems)
@@ -27,10 +27,31 @@ impl Plugin for TickCounterPlugin {
     }
 }
 
-/// Increment the [`TicksConnected`] component for every entity that's in any
+/// A message that's sent once per game tick for every client that's in a
 /// world.
-pub fn increment_counter(mut query: Query<&mut TicksConnected, With<WorldName>>) {
-    for mut counter in &mut query {
+///
+/// This is sent at the start of the tick, before physics and movement.
+#[derive(Clone, Debug, Message)]
+pub struct GameTickEvent {
+    pub entity: Entity,
+    /// The value of the client's [`TicksConnected`] counter for this tick.
+    ///
+    /// This starts at 1 for the first tick after joining and increases by one
+    /// every tick.
+    pub tick: u64,
+}
+
+/// Increment the [`TicksConnected`] component for every entity that's in any
+/// world, and send a [`GameTickEvent`] for each of them.
+pub fn increment_counter(
+    mut query: Query<(Entity, &mut TicksConnected), With<WorldName>>,
+    mut game_tick_events: MessageWriter<GameTickEvent>,
+) {
+    for (entity, mut counter) in &mut query {
         counter.0 += 1;
+        game_tick_events.write(GameTickEvent {
+            entity,
+            tick: counter.0,
+        });
     }
 }
@@ -0,0 +1,73 @@
+use std::sync::Arc;
+
+use azalea_client::{packet::game::GameModeChangeEvent, test_utils::prelude::*};
+use azalea_core::game_type::GameMode;
+use azalea_protocol::packets::{
+    ConnectionProtocol,
+    game::{ClientboundGameEvent, c_game_event::EventType},
+};
+use azalea_registry::{DataRegistry, data::DimensionKind, identifier::Identifier};
+use bevy_app::Update;
+use bevy_ecs::message::MessageReader;
+use parking_lot::Mutex;
+
+#[test]
+fn test_change_game_mode() {
+    let _lock = init();
+
+    let mut simulation = Simulation::new(ConnectionProtocol::Game);
+
+    let changes = Arc::new(Mutex::new(Vec::new()));
+    let changes_clone = changes.clone();
+    simulation.app.add_systems(
+        Update,
+        move |mut events: MessageReader<GameModeChangeEvent>| {
+            for event in events.read() {
+                changes_clone
+                    .lock()
+                    .push((event.old_game_mode, event.new_game_mode));
+            }
+        },
+    );
+
+    simulation.receive_packet(default_login_packet());
+    simulation.tick();
+    assert_eq!(simulation.component::<GameMode>(), GameMode::Survival);
+    // the initial game mode isn't a change
+    assert!(changes.lock().is_empty());
+
+    simulation.receive_packet(ClientboundGameEvent {
+        event: EventType::ChangeGameMode,
+        param: GameMode::Creative.to_id() as f32,
+    });
+    simulation.tick();
+    assert_eq!(simulation.component::<GameMode>(), GameMode::Creative);
+    assert_eq!(
+        *changes.lock(),
+        vec![(GameMode::Survival, GameMode::Creative)]
+    );
+
+    // setting the same game mode again doesn't send another event
+    simulation.receive_packet(ClientboundGameEvent {
+        event: EventType::ChangeGameMode,
+        param: GameMode::Creative.to_id() as f32,
+    });
+    simulation.tick();
+    assert_eq!(changes.lock().len(), 1);
+
+    // respawning in a different game mode counts as a change too
+    // (the basic respawn packet is always in survival)
+    simulation.receive_packet(make_basic_respawn_packet(
+        DimensionKind::new_raw(0), // overworld
+        Identifier::new("minecraft:overworld"),
+    ));
+    simulation.tick();
+    assert_eq!(simulation.component::<GameMode>(), GameMode::Survival);
+    assert_eq!(
+        *changes.lock(),
+        vec![
+            (GameMode::Survival, GameMode::Creative),
+            (GameMode::Creative, GameMode::Survival)
+        ]
+    );
+}
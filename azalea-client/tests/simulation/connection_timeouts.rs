@@ -0,0 +1,90 @@
+use std::time::Duration;
+
+use azalea_client::{
+    InConfigState,
+    connection::RawConnection,
+    join::{ConnectOpts, ConnectTimeouts, ConnectionPhaseStartedAt, with_handshake_timeout},
+    test_utils::prelude::*,
+};
+use azalea_protocol::{
+    address::{ResolvedAddr, ServerAddr},
+    connect::ConnectionError,
+    packets::{ConnectionProtocol, game::ClientboundStartConfiguration},
+};
+use tokio::io::AsyncReadExt;
+use tracing::Level;
+
+fn connect_opts(timeouts: ConnectTimeouts) -> ConnectOpts {
+    ConnectOpts {
+        address: ResolvedAddr {
+            server: ServerAddr {
+                host: "localhost".to_owned(),
+                port: 25565,
+            },
+            socket: "127.0.0.1:25565".parse().unwrap(),
+        },
+        server_proxy: None,
+        sessionserver_proxy: None,
+        timeouts,
+        read_buffer_capacity: 0,
+    }
+}
+
+#[tokio::test]
+async fn test_handshake_timeout() {
+    tokio::time::pause();
+
+    // a proxy that accepts the connection but never replies, so the handshake
+    // can never finish
+    let (mut stream, _proxy_stream) = tokio::io::duplex(64);
+    let handshake = tokio::spawn(with_handshake_timeout(Duration::from_secs(5), async move {
+        let mut reply = [0; 2];
+        stream.read_exact(&mut reply).await?;
+        Ok(())
+    }));
+    // let the handshake start before moving the clock
+    tokio::task::yield_now().await;
+
+    tokio::time::advance(Duration::from_secs(4)).await;
+    assert!(!handshake.is_finished());
+
+    tokio::time::advance(Duration::from_secs(1)).await;
+    let res = handshake.await.unwrap();
+    assert!(
+        matches!(res, Err(ConnectionError::Timeout(timeout)) if timeout == Duration::from_secs(5)),
+        "expected a timeout, got {res:?}"
+    );
+}
+
+#[test]
+fn test_configuration_timeout() {
+    // timing out logs a warning
+    let _lock = init_with_level(Level::ERROR);
+
+    let mut simulation = Simulation::new(ConnectionProtocol::Game);
+    simulation
+        .app
+        .world_mut()
+        .entity_mut(simulation.entity)
+        .insert(connect_opts(ConnectTimeouts::default()));
+    simulation.receive_packet(default_login_packet());
+    simulation.tick();
+    assert!(!simulation.has_component::<ConnectionPhaseStartedAt>());
+
+    // the server switches us back to the configuration state and then never
+    // sends us anything, so configuration never finishes
+    simulation.receive_packet(ClientboundStartConfiguration);
+    simulation.tick();
+    assert!(simulation.has_component::<InConfigState>());
+    assert!(simulation.has_component::<ConnectionPhaseStartedAt>());
+    simulation.tick();
+    assert!(simulation.has_component::<RawConnection>());
+
+    // once the phase has taken longer than the timeout, we get disconnected
+    simulation.with_component_mut::<ConnectOpts>(|opts| {
+        opts.timeouts.configuration = Duration::ZERO;
+    });
+    simulation.tick();
+    simulation.tick();
+    assert!(!simulation.has_component::<RawConnection>());
+}
@@ -0,0 +1,50 @@
+use std::sync::Arc;
+
+use azalea_chat::{
+    FormattedText,
+    style::{Style, TextColor},
+    text_component::TextComponent,
+};
+use azalea_client::{disconnect::DisconnectEvent, test_utils::prelude::*};
+use azalea_protocol::packets::{ConnectionProtocol, game::ClientboundDisconnect};
+use azalea_world::WorldName;
+use bevy_app::Update;
+use bevy_ecs::message::MessageReader;
+use parking_lot::Mutex;
+use tracing::Level;
+
+#[test]
+fn test_disconnect_reason() {
+    // the client warns when it gets kicked
+    let _lock = init_with_level(Level::ERROR);
+
+    let mut simulation = Simulation::new(ConnectionProtocol::Game);
+
+    let reasons = Arc::new(Mutex::new(Vec::new()));
+    let reasons_clone = reasons.clone();
+    simulation
+        .app
+        .add_systems(Update, move |mut events: MessageReader<DisconnectEvent>| {
+            for event in events.read() {
+                reasons_clone.lock().push(event.reason.clone());
+            }
+        });
+
+    simulation.receive_packet(default_login_packet());
+    simulation.tick();
+
+    let reason = FormattedText::Text(
+        TextComponent::new("Flying is not enabled").with_style(Style::new().color(TextColor::RED)),
+    );
+    simulation.receive_packet(ClientboundDisconnect {
+        reason: reason.clone(),
+    });
+    simulation.tick();
+
+    assert!(!simulation.has_component::<WorldName>());
+    assert_eq!(*reasons.lock(), vec![Some(reason.clone())]);
+    assert_eq!(
+        reasons.lock()[0].as_ref().unwrap().to_string(),
+        "Flying is not enabled"
+    );
+}
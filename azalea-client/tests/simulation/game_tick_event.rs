@@ -0,0 +1,44 @@
+use std::sync::Arc;
+
+use azalea_client::{
+    test_utils::prelude::*,
+    tick_counter::{GameTickEvent, increment_counter},
+};
+use azalea_core::tick::GameTick;
+use azalea_protocol::packets::ConnectionProtocol;
+use bevy_ecs::{message::MessageReader, schedule::IntoScheduleConfigs};
+use parking_lot::Mutex;
+
+#[test]
+fn game_tick_event_is_sent_every_tick() {
+    let _lock = init();
+
+    let mut simulation = Simulation::new(ConnectionProtocol::Game);
+
+    let received = Arc::new(Mutex::new(Vec::new()));
+    let received_clone = received.clone();
+    simulation.app.add_systems(
+        GameTick,
+        (move |mut events: MessageReader<GameTickEvent>| {
+            for event in events.read() {
+                received_clone.lock().push((event.entity, event.tick));
+            }
+        })
+        .after(increment_counter),
+    );
+
+    // no events before we're in a world
+    simulation.tick();
+    assert!(received.lock().is_empty());
+
+    simulation.receive_packet(default_login_packet());
+    for _ in 0..5 {
+        simulation.tick();
+    }
+
+    let entity = simulation.entity;
+    assert_eq!(
+        *received.lock(),
+        (1..=5).map(|tick| (entity, tick)).collect::<Vec<_>>()
+    );
+}
@@ -0,0 +1,33 @@
+#![cfg(feature = "packet-metrics")]
+
+use azalea_client::{packet_metrics::PacketMetrics, test_utils::prelude::*};
+use azalea_protocol::packets::{ConnectionProtocol, game::ClientboundSetHealth};
+
+#[test]
+fn test_packet_metrics() {
+    let _lock = init();
+
+    let mut simulation = Simulation::new(ConnectionProtocol::Game);
+    simulation.receive_packet(default_login_packet());
+    simulation.tick();
+
+    for _ in 0..3 {
+        simulation.receive_packet(ClientboundSetHealth {
+            health: 15.,
+            food: 20,
+            saturation: 20.,
+        });
+    }
+    simulation.tick();
+
+    let metrics = simulation.component::<PacketMetrics>();
+    let login = metrics.get("login").unwrap();
+    assert_eq!(login.count, 1);
+    assert!(login.bytes > 0);
+    let set_health = metrics.get("set_health").unwrap();
+    assert_eq!(set_health.count, 3);
+    // packet id + float + varint + float
+    assert_eq!(set_health.bytes, 3 * (1 + 4 + 1 + 4));
+    assert_eq!(metrics.get("set_time"), None);
+    assert!(metrics.total_bytes() >= login.bytes + set_health.bytes);
+}
@@ -0,0 +1,144 @@
+use std::sync::Arc;
+
+use azalea_block::BlockState;
+use azalea_client::{
+    block_update::{BlockUpdateEvent, handle_block_update_event},
+    interact::BlockStatePredictionHandler,
+    local_player::WorldHolder,
+    test_utils::prelude::*,
+};
+use azalea_core::position::{BlockPos, ChunkPos, ChunkSectionBlockPos, ChunkSectionPos, Vec3};
+use azalea_protocol::packets::{
+    ConnectionProtocol,
+    game::{
+        ClientboundBlockChangedAck, ClientboundBlockUpdate, ClientboundSectionBlocksUpdate,
+        c_section_blocks_update::BlockStateWithPosition,
+    },
+};
+use azalea_registry::builtin::BlockKind;
+use bevy_app::Update;
+use bevy_ecs::{message::MessageReader, schedule::IntoScheduleConfigs};
+use parking_lot::Mutex;
+
+type BlockUpdates = Arc<Mutex<Vec<(BlockPos, BlockState, BlockState)>>>;
+
+fn record_block_updates(simulation: &mut Simulation) -> BlockUpdates {
+    let updates = BlockUpdates::default();
+    let updates_clone = updates.clone();
+    simulation.app.add_systems(
+        Update,
+        (move |mut events: MessageReader<BlockUpdateEvent>| {
+            for event in events.read() {
+                updates_clone
+                    .lock()
+                    .push((event.pos, event.old_state, event.new_state));
+            }
+        })
+        .after(handle_block_update_event),
+    );
+    updates
+}
+
+#[test]
+fn test_receive_block_update() {
+    let _lock = init();
+
+    let mut simulation = Simulation::new(ConnectionProtocol::Game);
+    let updates = record_block_updates(&mut simulation);
+
+    simulation.receive_packet(default_login_packet());
+    simulation.receive_packet(make_basic_empty_chunk(ChunkPos::new(0, 0), (384 + 64) / 16));
+    simulation.tick();
+
+    let air = BlockState::from(BlockKind::Air);
+    let stone = BlockState::from(BlockKind::Stone);
+    let dirt = BlockState::from(BlockKind::Dirt);
+
+    let pos = BlockPos::new(1, 2, 3);
+    simulation.receive_packet(ClientboundBlockUpdate {
+        pos,
+        block_state: stone,
+    });
+    simulation.tick();
+    assert_eq!(*updates.lock(), vec![(pos, air, stone)]);
+
+    // updating a block to the state it already has isn't a change
+    simulation.receive_packet(ClientboundBlockUpdate {
+        pos,
+        block_state: stone,
+    });
+    simulation.tick();
+    assert_eq!(updates.lock().len(), 1);
+    updates.lock().clear();
+
+    // every changed block in a section update gets its own event
+    simulation.receive_packet(ClientboundSectionBlocksUpdate {
+        section_pos: ChunkSectionPos::new(0, 0, 0),
+        states: vec![
+            BlockStateWithPosition {
+                pos: ChunkSectionBlockPos::new(1, 2, 3),
+                state: dirt,
+            },
+            BlockStateWithPosition {
+                pos: ChunkSectionBlockPos::new(4, 5, 6),
+                state: stone,
+            },
+            BlockStateWithPosition {
+                pos: ChunkSectionBlockPos::new(7, 8, 9),
+                state: air,
+            },
+        ],
+    });
+    simulation.tick();
+    assert_eq!(
+        *updates.lock(),
+        vec![(pos, stone, dirt), (BlockPos::new(4, 5, 6), air, stone)]
+    );
+    assert_eq!(simulation.get_block_state(pos), Some(dirt));
+}
+
+#[test]
+fn test_block_update_while_predicting() {
+    let _lock = init();
+
+    let mut simulation = Simulation::new(ConnectionProtocol::Game);
+    let updates = record_block_updates(&mut simulation);
+
+    simulation.receive_packet(default_login_packet());
+    simulation.receive_packet(make_basic_empty_chunk(ChunkPos::new(0, 0), (384 + 64) / 16));
+    simulation.tick();
+
+    let air = BlockState::from(BlockKind::Air);
+    let stone = BlockState::from(BlockKind::Stone);
+    let dirt = BlockState::from(BlockKind::Dirt);
+
+    // predict that we placed stone
+    let pos = BlockPos::new(1, 2, 3);
+    let mut seq = 0;
+    simulation.with_component_mut::<BlockStatePredictionHandler>(|prediction_handler| {
+        seq = prediction_handler.start_predicting();
+        prediction_handler.retain_known_server_state(pos, air, Vec3::ZERO);
+    });
+    simulation
+        .component::<WorldHolder>()
+        .shared
+        .read()
+        .set_block_state(pos, stone);
+
+    // the server changes the block while we're predicting it, which doesn't
+    // change our prediction yet
+    simulation.receive_packet(ClientboundBlockUpdate {
+        pos,
+        block_state: dirt,
+    });
+    simulation.tick();
+    assert!(updates.lock().is_empty());
+    assert_eq!(simulation.get_block_state(pos), Some(stone));
+
+    // and once the server acknowledges our prediction, we roll back to the
+    // server's state
+    simulation.receive_packet(ClientboundBlockChangedAck { seq });
+    simulation.tick();
+    assert_eq!(*updates.lock(), vec![(pos, stone, dirt)]);
+    assert_eq!(simulation.get_block_state(pos), Some(dirt));
+}
@@ -0,0 +1,74 @@
+use std::sync::Arc;
+
+use azalea_buf::AzBuf;
+use azalea_client::{
+    brand::ServerBrand,
+    custom_payload::{CustomPayloadEvent, CustomPayloadHandlers},
+    test_utils::prelude::*,
+};
+use azalea_protocol::packets::{ConnectionProtocol, config};
+use azalea_registry::identifier::Identifier;
+use bevy_ecs::observer::On;
+use parking_lot::Mutex;
+
+#[test]
+fn receive_custom_payload() {
+    let _lock = init();
+
+    let mut simulation = Simulation::new(ConnectionProtocol::Configuration);
+
+    let received_channels = Arc::new(Mutex::new(Vec::new()));
+    let received_channels_clone = received_channels.clone();
+    simulation
+        .app
+        .add_observer(move |custom_payload: On<CustomPayloadEvent>| {
+            received_channels_clone
+                .lock()
+                .push(custom_payload.channel.clone());
+        });
+
+    let mut brand_data = Vec::new();
+    "Paper".to_owned().azalea_write(&mut brand_data).unwrap();
+    simulation.receive_packet(config::ClientboundCustomPayload {
+        identifier: Identifier::new("minecraft:brand"),
+        data: brand_data.into(),
+    });
+    simulation.tick();
+    assert_eq!(
+        simulation.component::<ServerBrand>(),
+        ServerBrand("Paper".to_owned())
+    );
+
+    // channels without a handler shouldn't cause any problems
+    simulation.receive_packet(config::ClientboundCustomPayload {
+        identifier: Identifier::new("unknown:channel"),
+        data: vec![1, 2, 3].into(),
+    });
+    simulation.tick();
+
+    let hello_data = Arc::new(Mutex::new(None));
+    let hello_data_clone = hello_data.clone();
+    simulation.with_resource_mut::<CustomPayloadHandlers>(|mut handlers| {
+        handlers.register(
+            Identifier::new("mymod:hello"),
+            move |_commands, _entity, data| {
+                *hello_data_clone.lock() = Some(data.to_vec());
+            },
+        );
+    });
+    simulation.receive_packet(config::ClientboundCustomPayload {
+        identifier: Identifier::new("mymod:hello"),
+        data: vec![4, 5, 6].into(),
+    });
+    simulation.tick();
+    assert_eq!(*hello_data.lock(), Some(vec![4, 5, 6]));
+
+    assert_eq!(
+        *received_channels.lock(),
+        vec![
+            Identifier::new("minecraft:brand"),
+            Identifier::new("unknown:channel"),
+            Identifier::new("mymod:hello"),
+        ]
+    );
+}
@@ -0,0 +1,40 @@
+use azalea_client::{connection::CompressionThreshold, packet::login, test_utils::prelude::*};
+use azalea_protocol::packets::{
+    ConnectionProtocol,
+    login::{ClientboundLoginCompression, ClientboundLoginPacket},
+};
+
+#[test]
+fn receive_login_compression() {
+    let _lock = init();
+
+    let mut simulation = Simulation::new(ConnectionProtocol::Configuration);
+    assert!(!simulation.has_component::<CompressionThreshold>());
+
+    login::process_packet(
+        simulation.app.world_mut(),
+        simulation.entity,
+        &ClientboundLoginPacket::LoginCompression(ClientboundLoginCompression {
+            compression_threshold: 256,
+        }),
+    );
+    simulation.tick();
+    assert_eq!(
+        simulation.component::<CompressionThreshold>(),
+        CompressionThreshold(Some(256))
+    );
+
+    // negative thresholds disable compression
+    login::process_packet(
+        simulation.app.world_mut(),
+        simulation.entity,
+        &ClientboundLoginPacket::LoginCompression(ClientboundLoginCompression {
+            compression_threshold: -1,
+        }),
+    );
+    simulation.tick();
+    assert_eq!(
+        simulation.component::<CompressionThreshold>(),
+        CompressionThreshold(None)
+    );
+}
@@ -0,0 +1,49 @@
+use azalea_client::{local_player::WorldBorder, test_utils::prelude::*};
+use azalea_core::position::Vec3;
+use azalea_protocol::packets::{
+    ConnectionProtocol,
+    game::{ClientboundInitializeBorder, ClientboundSetBorderCenter, ClientboundSetBorderSize},
+};
+
+#[test]
+fn test_receive_world_border() {
+    let _lock = init();
+
+    let mut simulation = Simulation::new(ConnectionProtocol::Game);
+    simulation.receive_packet(default_login_packet());
+    simulation.tick();
+    assert_eq!(
+        simulation.component::<WorldBorder>(),
+        WorldBorder::default()
+    );
+
+    simulation.receive_packet(ClientboundInitializeBorder {
+        new_center_x: 100.,
+        new_center_z: -50.,
+        old_size: 20.,
+        new_size: 20.,
+        lerp_time: 0,
+        new_absolute_max_size: 29_999_984,
+        warning_blocks: 3,
+        warning_time: 10,
+    });
+    simulation.tick();
+    let world_border = simulation.component::<WorldBorder>();
+    assert_eq!(world_border.warning_blocks, 3);
+    assert!(world_border.contains(Vec3::new(100., 64., -50.)));
+    assert!(world_border.contains(Vec3::new(90., -64., -59.5)));
+    // the max edge is exclusive
+    assert!(!world_border.contains(Vec3::new(110., 64., -50.)));
+    assert!(!world_border.contains(Vec3::new(0., 64., 0.)));
+
+    simulation.receive_packet(ClientboundSetBorderCenter {
+        new_center_x: 0.,
+        new_center_z: 0.,
+    });
+    simulation.receive_packet(ClientboundSetBorderSize { size: 200. });
+    simulation.tick();
+    let world_border = simulation.component::<WorldBorder>();
+    assert!(world_border.contains(Vec3::new(0., 64., 0.)));
+    assert!(world_border.contains(Vec3::new(-99., 64., 99.)));
+    assert!(!world_border.contains(Vec3::new(100., 64., -50.)));
+}
@@ -0,0 +1,49 @@
+use std::sync::Arc;
+
+use azalea_client::{
+    brand::ClientBrand,
+    packet::{config::SendConfigPacketEvent, login::InLoginState},
+    test_utils::prelude::*,
+};
+use azalea_protocol::packets::{ConnectionProtocol, config::ServerboundConfigPacket};
+use azalea_registry::identifier::Identifier;
+use bevy_ecs::observer::On;
+use parking_lot::Mutex;
+
+#[test]
+fn send_brand_on_join() {
+    let _lock = init();
+
+    let mut simulation = Simulation::new(ConnectionProtocol::Configuration);
+
+    let sent_brand = Arc::new(Mutex::new(None));
+    let sent_brand_clone = sent_brand.clone();
+    simulation
+        .app
+        .add_observer(move |send_config_packet: On<SendConfigPacketEvent>| {
+            if send_config_packet.sent_by == simulation.entity
+                && let ServerboundConfigPacket::CustomPayload(packet) = &send_config_packet.packet
+            {
+                *sent_brand_clone.lock() = Some(packet.clone());
+            }
+        });
+
+    // the brand is sent when we switch from the login state to the config state
+    simulation
+        .app
+        .world_mut()
+        .entity_mut(simulation.entity)
+        .insert((InLoginState, ClientBrand("azalea".to_owned())));
+    simulation.tick();
+    simulation
+        .app
+        .world_mut()
+        .entity_mut(simulation.entity)
+        .remove::<InLoginState>();
+    simulation.tick();
+
+    let sent_brand = sent_brand.lock().take().expect("brand should've been sent");
+    assert_eq!(sent_brand.identifier, Identifier::new("minecraft:brand"));
+    // the string is prefixed with its length as a varint
+    assert_eq!(*sent_brand.data, *b"\x06azalea");
+}
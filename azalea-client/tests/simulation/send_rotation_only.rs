@@ -0,0 +1,68 @@
+use azalea_client::test_utils::prelude::*;
+use azalea_core::position::{BlockPos, ChunkPos, Vec3};
+use azalea_entity::LookDirection;
+use azalea_protocol::{
+    common::movements::{MoveFlags, PositionMoveRotation, RelativeMovements},
+    packets::{
+        ConnectionProtocol,
+        game::{
+            ClientboundBlockUpdate, ClientboundPlayerPosition, ClientboundSetChunkCacheCenter,
+            ServerboundGamePacket, ServerboundMovePlayerRot,
+        },
+    },
+};
+use azalea_registry::builtin::BlockKind;
+
+#[test]
+fn test_send_rotation_only() {
+    let _lock = init();
+
+    let mut simulation = Simulation::new(ConnectionProtocol::Game);
+    let sent_packets = SentPackets::new(&mut simulation);
+
+    simulation.receive_packet(default_login_packet());
+    simulation.tick();
+
+    // receive a chunk so the player is "loaded" now
+    simulation.receive_packet(ClientboundSetChunkCacheCenter { x: 1, z: 23 });
+    simulation.receive_packet(make_basic_empty_chunk(
+        ChunkPos::new(1, 23),
+        (384 + 64) / 16,
+    ));
+    simulation.receive_packet(ClientboundBlockUpdate {
+        pos: BlockPos::new(31, 63, 370),
+        block_state: BlockKind::Stone.into(),
+    });
+    simulation.receive_packet(ClientboundPlayerPosition {
+        id: 1,
+        change: PositionMoveRotation {
+            pos: Vec3::new(31.5, 64., 370.5),
+            delta: Vec3::ZERO,
+            look_direction: LookDirection::default(),
+        },
+        relative: RelativeMovements::all_absolute(),
+    });
+    simulation.tick();
+    simulation.tick();
+
+    // only rotating shouldn't send our position
+    sent_packets.clear();
+    simulation.with_component_mut::<LookDirection>(|look_direction| {
+        look_direction.update(LookDirection::new(90., 0.));
+    });
+    simulation.tick();
+    sent_packets.expect("MovePlayerRot { y_rot: 90., ... }", |p| {
+        matches!(
+            p,
+            ServerboundGamePacket::MovePlayerRot(ServerboundMovePlayerRot {
+                look_direction,
+                flags: MoveFlags {
+                    on_ground: true,
+                    horizontal_collision: false
+                }
+            }) if look_direction.y_rot() == 90.
+        )
+    });
+    sent_packets.expect_tick_end();
+    sent_packets.expect_empty();
+}
@@ -0,0 +1,147 @@
+use azalea_auth::certs::Certificates;
+use azalea_client::{
+    account::{Account, AccountTrait},
+    chat_signing::{ChatSigningSession, RequestCertsTask},
+    client_chat::{ChatSigningMode, SendChatEvent},
+    login::IsAuthenticated,
+    test_utils::prelude::*,
+};
+use azalea_protocol::packets::{
+    ConnectionProtocol,
+    game::{ServerboundChat, ServerboundChatCommand, ServerboundGamePacket},
+};
+use chrono::{TimeDelta, Utc};
+use rsa::RsaPrivateKey;
+use uuid::Uuid;
+
+/// An account that has chat signing certificates, without needing to fetch
+/// them from Mojang.
+#[derive(Debug)]
+struct SigningAccount {
+    certs: Certificates,
+}
+impl AccountTrait for SigningAccount {
+    fn username(&self) -> &str {
+        "azalea"
+    }
+    fn uuid(&self) -> Uuid {
+        Uuid::nil()
+    }
+    fn access_token(&self) -> Option<String> {
+        Some("token".to_owned())
+    }
+    fn certs(&self) -> Option<Certificates> {
+        Some(self.certs.clone())
+    }
+}
+
+fn signing_account() -> Account {
+    let private_key = RsaPrivateKey::new(&mut rand::rng(), 2048).unwrap();
+    SigningAccount {
+        certs: Certificates {
+            private_key,
+            public_key_der: Vec::new(),
+            signature_v1: Vec::new(),
+            signature_v2: Vec::new(),
+            expires_at: Utc::now() + TimeDelta::days(1),
+            refresh_after: Utc::now() + TimeDelta::days(1),
+        },
+    }
+    .into()
+}
+
+#[test]
+fn test_send_unsigned_chat() {
+    let _lock = init();
+
+    let mut simulation = Simulation::new(ConnectionProtocol::Game);
+    let sent_packets = SentPackets::new(&mut simulation);
+
+    simulation.receive_packet(default_login_packet());
+    simulation.tick();
+
+    let entity = simulation.entity;
+    simulation.app.world_mut().entity_mut(entity).insert((
+        signing_account(),
+        ChatSigningSession {
+            session_id: Uuid::nil(),
+            messages_sent: 0,
+        },
+    ));
+
+    // messages are signed by default when we have a chat session
+    sent_packets.clear();
+    simulation.write_message(SendChatEvent {
+        entity,
+        content: "hello".to_owned(),
+    });
+    simulation.update();
+    sent_packets.expect("Chat { message: \"hello\", signature: Some, ... }", |p| {
+        matches!(
+            p,
+            ServerboundGamePacket::Chat(ServerboundChat {
+                message,
+                signature: Some(_),
+                ..
+            }) if message == "hello"
+        )
+    });
+    sent_packets.expect_empty();
+
+    simulation
+        .app
+        .world_mut()
+        .entity_mut(entity)
+        .insert(ChatSigningMode::Unsigned);
+
+    simulation.write_message(SendChatEvent {
+        entity,
+        content: "hello".to_owned(),
+    });
+    simulation.write_message(SendChatEvent {
+        entity,
+        content: "/help me".to_owned(),
+    });
+    simulation.update();
+
+    sent_packets.expect("Chat { message: \"hello\", signature: None, ... }", |p| {
+        matches!(
+            p,
+            ServerboundGamePacket::Chat(ServerboundChat {
+                message,
+                signature: None,
+                ..
+            }) if message == "hello"
+        )
+    });
+    sent_packets.expect("ChatCommand { command: \"help me\" }", |p| {
+        matches!(
+            p,
+            ServerboundGamePacket::ChatCommand(ServerboundChatCommand { command })
+                if command == "help me"
+        )
+    });
+    sent_packets.expect_empty();
+}
+
+#[test]
+fn test_unsigned_chat_doesnt_request_certs() {
+    let _lock = init();
+
+    let mut simulation = Simulation::new(ConnectionProtocol::Game);
+    simulation.receive_packet(default_login_packet());
+    simulation.tick();
+
+    // without a chat session, an authenticated client with an access token
+    // would normally request certs from Mojang right away
+    let entity = simulation.entity;
+    simulation.app.world_mut().entity_mut(entity).insert((
+        signing_account(),
+        IsAuthenticated,
+        ChatSigningMode::Unsigned,
+    ));
+    simulation.update();
+
+    assert!(!simulation.has_component::<RequestCertsTask>());
+    assert!(!simulation.has_component::<ChatSigningSession>());
+}
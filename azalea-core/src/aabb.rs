@@ -102,6 +102,28 @@ impl Aabb {
         Aabb { min, max }
     }
 
+    /// Get the smallest box that contains both this box and the other one.
+    ///
+    /// This is the same as [`Self::minmax`].
+    pub fn union(&self, other: &Aabb) -> Aabb {
+        self.minmax(other)
+    }
+
+    /// Get the region where this box and the other one overlap, or `None` if
+    /// they don't.
+    ///
+    /// Boxes that are only touching (i.e. their overlap would have no volume)
+    /// are not considered to overlap, matching [`Self::intersects_aabb`]. Use
+    /// [`Self::intersect`] if you want the result even if the boxes don't
+    /// overlap.
+    pub fn intersection(&self, other: &Aabb) -> Option<Aabb> {
+        if self.intersects_aabb(other) {
+            Some(self.intersect(other))
+        } else {
+            None
+        }
+    }
+
     pub fn move_relative(&self, delta: Vec3) -> Aabb {
         Aabb {
             min: self.min + delta,
@@ -156,10 +178,20 @@ impl Aabb {
     }
 
     pub fn clip(&self, min: Vec3, max: Vec3) -> Option<Vec3> {
+        let t = self.clip_ray(min, max)?;
+        Some(min + ((max - min) * t))
+    }
+
+    /// Get how far along the ray from `from` to `to` it enters this box.
+    ///
+    /// The returned value is between 0 and 1, where 0 is `from` and 1 is `to`.
+    /// This returns `None` if the ray misses the box, doesn't reach it, or
+    /// starts inside of it.
+    pub fn clip_ray(&self, from: Vec3, to: Vec3) -> Option<f64> {
         let mut t = 1.0;
-        let delta = max - min;
-        let _dir = Self::get_direction_aabb(self, min, &mut t, None, delta)?;
-        Some(min + (delta * t))
+        let delta = to - from;
+        let _dir = self.get_direction_aabb(from, &mut t, None, delta)?;
+        Some(t)
     }
 
     pub fn clip_with_from_and_to(min: Vec3, max: Vec3, from: Vec3, to: Vec3) -> Option<Vec3> {
@@ -390,6 +422,19 @@ impl Aabb {
         }
     }
 
+    /// Create a box with the given width and height, that's horizontally
+    /// centered on `center` and has its bottom at `center.y`.
+    ///
+    /// This is how Minecraft makes the bounding boxes for entities, where
+    /// `center` is the entity's position.
+    pub fn from_center_size(center: Vec3, width: f64, height: f64) -> Aabb {
+        let radius = width / 2.0;
+        Aabb {
+            min: Vec3::new(center.x - radius, center.y, center.z - radius),
+            max: Vec3::new(center.x + radius, center.y + height, center.z + radius),
+        }
+    }
+
     pub fn max(&self, axis: &Axis) -> f64 {
         axis.choose(self.max.x, self.max.y, self.max.z)
     }
@@ -471,4 +516,106 @@ mod tests {
             None
         );
     }
+
+    #[test]
+    fn test_aabb_union_and_intersection() {
+        let a = Aabb {
+            min: Vec3::new(0., 0., 0.),
+            max: Vec3::new(2., 2., 2.),
+        };
+        let overlapping = Aabb {
+            min: Vec3::new(1., 1., 1.),
+            max: Vec3::new(3., 3., 3.),
+        };
+        let touching = Aabb {
+            min: Vec3::new(2., 0., 0.),
+            max: Vec3::new(3., 1., 1.),
+        };
+        let disjoint = Aabb {
+            min: Vec3::new(5., 5., 5.),
+            max: Vec3::new(6., 6., 6.),
+        };
+
+        assert_eq!(
+            a.union(&overlapping),
+            Aabb {
+                min: Vec3::new(0., 0., 0.),
+                max: Vec3::new(3., 3., 3.),
+            }
+        );
+        assert_eq!(
+            a.intersection(&overlapping),
+            Some(Aabb {
+                min: Vec3::new(1., 1., 1.),
+                max: Vec3::new(2., 2., 2.),
+            })
+        );
+
+        assert_eq!(
+            a.union(&touching),
+            Aabb {
+                min: Vec3::new(0., 0., 0.),
+                max: Vec3::new(3., 2., 2.),
+            }
+        );
+        assert_eq!(a.intersection(&touching), None);
+
+        let union = a.union(&disjoint);
+        assert_eq!(union.min, a.min);
+        assert_eq!(union.max, disjoint.max);
+        assert_eq!(a.intersection(&disjoint), None);
+        assert_eq!(disjoint.intersection(&a), None);
+    }
+
+    #[test]
+    fn test_aabb_clip_ray() {
+        let aabb = Aabb {
+            min: Vec3::new(0., 0., 0.),
+            max: Vec3::new(1., 1., 1.),
+        };
+
+        // straight along each axis
+        assert_eq!(
+            aabb.clip_ray(Vec3::new(-1., 0.5, 0.5), Vec3::new(3., 0.5, 0.5)),
+            Some(0.25)
+        );
+        assert_eq!(
+            aabb.clip_ray(Vec3::new(0.5, 3., 0.5), Vec3::new(0.5, -1., 0.5)),
+            Some(0.5)
+        );
+        assert_eq!(
+            aabb.clip_ray(Vec3::new(0.5, 0.5, -2.), Vec3::new(0.5, 0.5, 2.)),
+            Some(0.5)
+        );
+        // diagonally through a corner
+        assert_eq!(
+            aabb.clip_ray(Vec3::new(-1., -1., -1.), Vec3::new(1., 1., 1.)),
+            Some(0.5)
+        );
+
+        // missing the box entirely
+        assert_eq!(
+            aabb.clip_ray(Vec3::new(-1., 2., 0.5), Vec3::new(3., 2., 0.5)),
+            None
+        );
+        // not reaching the box
+        assert_eq!(
+            aabb.clip_ray(Vec3::new(-3., 0.5, 0.5), Vec3::new(-1., 0.5, 0.5)),
+            None
+        );
+        // starting inside of the box
+        assert_eq!(
+            aabb.clip_ray(Vec3::new(0.5, 0.5, 0.5), Vec3::new(3., 0.5, 0.5)),
+            None
+        );
+    }
+
+    #[test]
+    fn test_aabb_from_center_size() {
+        // a player
+        let aabb = Aabb::from_center_size(Vec3::new(0.5, 64., -10.5), 0.6, 1.8);
+        assert_eq!(aabb.min, Vec3::new(0.2, 64., -10.8));
+        assert_eq!(aabb.max, Vec3::new(0.8, 65.8, -10.2));
+        assert!((aabb.get_size(Axis::Y) - 1.8).abs() < 1e-9);
+    }
 }
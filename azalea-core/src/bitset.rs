@@ -6,6 +6,11 @@ use std::{
 use azalea_buf::{AzBuf, BufReadError};
 
 /// Represents Java's BitSet, a list of bits.
+///
+/// This is sent over the network as a VarInt number of longs followed by the
+/// longs themselves, with the first bit being the lowest bit of the first
+/// long. Use [`FixedBitSet`] for bitsets that are sent with a fixed size
+/// instead.
 #[derive(AzBuf, Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct BitSet {
     data: Box<[u64]>,
@@ -306,6 +311,53 @@ mod tests {
         assert!(!bitset.index(2));
     }
 
+    #[test]
+    fn test_bitset_azbuf() {
+        let mut bitset = BitSet::new(130);
+        assert_eq!(bitset.len(), 192);
+        for i in [0, 5, 63, 64, 129] {
+            bitset.set(i);
+        }
+
+        let mut buf = Vec::new();
+        bitset.azalea_write(&mut buf).unwrap();
+        // the number of longs, then each long
+        assert_eq!(buf[0], 3);
+        assert_eq!(buf.len(), 1 + 3 * 8);
+        assert_eq!(buf[1..9], ((1u64 << 63) | (1 << 5) | 1).to_be_bytes());
+
+        let read = BitSet::azalea_read(&mut Cursor::new(&buf[..])).unwrap();
+        assert_eq!(read, bitset);
+        assert_eq!(read.iter_ones().collect::<Vec<_>>(), [0, 5, 63, 64, 129]);
+        assert_eq!(read.get(1), Some(false));
+        assert_eq!(read.get(192), None);
+    }
+
+    #[test]
+    fn test_bitset_read_empty() {
+        let bitset = BitSet::azalea_read(&mut Cursor::new(&[0][..])).unwrap();
+        assert!(bitset.is_empty());
+        assert_eq!(bitset.get(0), None);
+    }
+
+    #[test]
+    fn test_fixed_bitset_azbuf() {
+        let mut bitset = FixedBitSet::<20>::new();
+        bitset.set(0);
+        bitset.set(9);
+        bitset.set(19);
+
+        let mut buf = Vec::new();
+        bitset.azalea_write(&mut buf).unwrap();
+        // no length prefix, just enough bytes for 20 bits
+        assert_eq!(buf, [0b0000_0001, 0b0000_0010, 0b0000_1000]);
+
+        let read = FixedBitSet::<20>::azalea_read(&mut Cursor::new(&buf[..])).unwrap();
+        for i in 0..20 {
+            assert_eq!(read.index(i), [0, 9, 19].contains(&i));
+        }
+    }
+
     #[test]
     fn test_clear() {
         let mut bitset = BitSet::new(128);
@@ -5,10 +5,17 @@ use std::{
 
 use azalea_buf::{AzBuf, BufReadError};
 
-#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+/// The difficulty of a world.
+///
+/// Difficulties are ordered by their id, so `Difficulty::Peaceful` is the
+/// lowest and `Difficulty::Hard` is the highest.
+///
+/// The default is `Difficulty::Normal`, like in vanilla.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub enum Difficulty {
     Peaceful = 0,
     Easy = 1,
+    #[default]
     Normal = 2,
     Hard = 3,
 }
@@ -101,4 +108,24 @@ mod tests {
         assert_eq!("normal", Difficulty::Normal.name());
         assert_eq!("hard", Difficulty::Hard.name());
     }
+
+    #[test]
+    fn test_difficulty_default() {
+        assert_eq!(Difficulty::default(), Difficulty::Normal);
+    }
+
+    #[test]
+    fn test_difficulty_ordering() {
+        assert!(Difficulty::Hard > Difficulty::Easy);
+        assert!(Difficulty::Normal >= Difficulty::Normal);
+        assert!(Difficulty::Peaceful < Difficulty::Easy);
+        let mut difficulties = [
+            Difficulty::Hard,
+            Difficulty::Peaceful,
+            Difficulty::Normal,
+            Difficulty::Easy,
+        ];
+        difficulties.sort();
+        assert_eq!(difficulties.map(|d| d.id()), [0, 1, 2, 3],);
+    }
 }
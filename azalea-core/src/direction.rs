@@ -15,6 +15,14 @@ pub enum Direction {
 }
 
 impl Direction {
+    pub const ALL: [Direction; 6] = [
+        Direction::Down,
+        Direction::Up,
+        Direction::North,
+        Direction::South,
+        Direction::West,
+        Direction::East,
+    ];
     pub const HORIZONTAL: [Direction; 4] = [
         Direction::North,
         Direction::East,
@@ -27,16 +35,7 @@ impl Direction {
         let mut best_direction = Direction::North;
         let mut best_direction_amount = 0.0;
 
-        for dir in [
-            Direction::Down,
-            Direction::Up,
-            Direction::North,
-            Direction::South,
-            Direction::West,
-            Direction::East,
-        ]
-        .iter()
-        {
+        for dir in Direction::ALL.iter() {
             let amount = dir.normal_vec3().dot(vec);
             if amount > best_direction_amount {
                 best_direction = *dir;
@@ -75,6 +74,21 @@ impl Direction {
         }
     }
 
+    /// The axis that this direction points along.
+    pub fn axis(self) -> Axis {
+        match self {
+            Direction::Down | Direction::Up => Axis::Y,
+            Direction::North | Direction::South => Axis::Z,
+            Direction::West | Direction::East => Axis::X,
+        }
+    }
+
+    /// Whether this direction points towards the positive end of its axis
+    /// (up, south, or east).
+    pub fn is_positive(self) -> bool {
+        matches!(self, Direction::Up | Direction::South | Direction::East)
+    }
+
     pub fn x(self) -> i32 {
         self.normal().x
     }
@@ -100,14 +114,18 @@ pub enum CardinalDirection {
 }
 
 /// A 3D axis like x, y, z.
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum Axis {
     X = 0,
     Y = 1,
     Z = 2,
 }
 
-#[derive(Clone, Copy, Debug)]
+/// A rotation of the x, y, and z axes.
+///
+/// This is used in collision code so the same logic can be written once for
+/// the x axis, and then reused for the other axes by cycling them.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum AxisCycle {
     None = 0,
     Forward = 1,
@@ -203,9 +221,12 @@ impl AxisCycle {
             _ => panic!("invalid ordinal"),
         }
     }
+    /// Get the cycle that turns `axis0` into `axis1` when passed to
+    /// [`Self::cycle`].
     pub fn between(axis0: Axis, axis1: Axis) -> Self {
         Self::from_ordinal(i32::rem_euclid(axis1 as i32 - axis0 as i32, 3) as u32)
     }
+    /// Get the cycle that undoes this one.
     pub fn inverse(self) -> Self {
         match self {
             Self::None => Self::None,
@@ -213,6 +234,8 @@ impl AxisCycle {
             Self::Backward => Self::Forward,
         }
     }
+    /// Rotate the given axis. [`Self::Forward`] turns x into y, y into z, and z
+    /// into x.
     pub fn cycle(self, axis: Axis) -> Axis {
         match self {
             Self::None => axis,
@@ -220,6 +243,21 @@ impl AxisCycle {
             Self::Backward => Axis::from_ordinal(i32::rem_euclid(axis as i32 - 1, 3) as u32),
         }
     }
+    /// Permute the coordinates of the vector, in the same way as
+    /// [`Self::cycle_xyz`].
+    ///
+    /// ```
+    /// # use azalea_core::{direction::AxisCycle, position::Vec3};
+    /// let v = Vec3::new(1., 2., 3.);
+    /// assert_eq!(AxisCycle::Forward.cycle_vec3(v), Vec3::new(3., 1., 2.));
+    /// ```
+    pub fn cycle_vec3(self, v: Vec3) -> Vec3 {
+        match self {
+            Self::None => v,
+            Self::Forward => Vec3::new(v.z, v.x, v.y),
+            Self::Backward => Vec3::new(v.y, v.z, v.x),
+        }
+    }
     pub fn cycle_xyz(self, pos: Vec3i, axis: Axis) -> i32 {
         match self {
             Self::None => axis.choose(pos.x, pos.y, pos.z),
@@ -228,3 +266,34 @@ impl AxisCycle {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_axis_cycle() {
+        let v = Vec3::new(1., 2., 3.);
+        for cycle in [AxisCycle::None, AxisCycle::Forward, AxisCycle::Backward] {
+            let cycled = cycle.cycle_vec3(cycle.cycle_vec3(cycle.cycle_vec3(v)));
+            assert_eq!(cycled, v);
+            assert_eq!(cycle.inverse().cycle_vec3(cycle.cycle_vec3(v)), v);
+
+            // cycle_vec3 should agree with cycle_xyz
+            let cycled = cycle.cycle_vec3(v);
+            let pos = Vec3i::new(1, 2, 3);
+            for axis in [Axis::X, Axis::Y, Axis::Z] {
+                assert_eq!(
+                    axis.choose(cycled.x, cycled.y, cycled.z) as i32,
+                    cycle.cycle_xyz(pos, axis)
+                );
+            }
+        }
+
+        for from in [Axis::X, Axis::Y, Axis::Z] {
+            for to in [Axis::X, Axis::Y, Axis::Z] {
+                assert_eq!(AxisCycle::between(from, to).cycle(from), to);
+            }
+        }
+    }
+}
@@ -103,6 +103,14 @@ impl BlockHitResult {
     pub fn with_position(&self, block_pos: BlockPos) -> Self {
         Self { block_pos, ..*self }
     }
+
+    /// Get the distance from the given position (usually the start of the
+    /// ray) to [`Self::location`].
+    ///
+    /// This is useful for sorting hits by how close they are to the origin.
+    pub fn distance_to(&self, from: Vec3) -> f64 {
+        from.distance_to(self.location)
+    }
 }
 #[cfg(feature = "bevy_ecs")]
 impl From<BlockHitResult> for HitResult {
@@ -124,3 +132,16 @@ impl From<EntityHitResult> for HitResult {
         HitResult::Entity(value)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_block_hit_result_distance_to() {
+        let hit = BlockHitResult::miss(Vec3::new(3., 4., 0.), Direction::North);
+        assert_eq!(hit.distance_to(Vec3::ZERO), 5.);
+        assert_eq!(hit.distance_to(Vec3::new(3., 4., 0.)), 0.);
+        assert_eq!(hit.distance_to(Vec3::new(3., 4., -2.)), 2.);
+    }
+}
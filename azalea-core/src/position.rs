@@ -467,6 +467,20 @@ impl BlockPos {
         }
     }
 
+    /// Get the block position that contains the given point by flooring each
+    /// coordinate. This is the same as `BlockPos::from(pos)`.
+    ///
+    /// ```
+    /// # use azalea_core::position::{BlockPos, Vec3};
+    /// assert_eq!(
+    ///     BlockPos::from_vec3_floor(Vec3::new(-0.5, 1.5, -2.0)),
+    ///     BlockPos::new(-1, 1, -2)
+    /// );
+    /// ```
+    pub fn from_vec3_floor(pos: Vec3) -> Self {
+        BlockPos::from(&pos)
+    }
+
     /// Get the distance of this vector from the origin by doing `x + y + z`.
     pub fn length_manhattan(&self) -> u32 {
         (self.x.abs() + self.y.abs() + self.z.abs()) as u32
@@ -487,6 +501,39 @@ impl BlockPos {
         self + direction.normal()
     }
 
+    /// Get the six positions that share a face with this one, in the same
+    /// order as [`Direction::ALL`].
+    ///
+    /// ```
+    /// # use azalea_core::position::BlockPos;
+    /// let pos = BlockPos::new(10, 10, 10);
+    /// assert!(pos.neighbors().contains(&BlockPos::new(10, 11, 10)));
+    /// ```
+    pub fn neighbors(self) -> [BlockPos; 6] {
+        Direction::ALL.map(|direction| self.offset_with_direction(direction))
+    }
+
+    /// Get the 26 positions in the 3x3x3 cube around this one, which includes
+    /// the positions that only share an edge or a corner with it.
+    ///
+    /// The position itself isn't included.
+    pub fn neighbors_including_diagonals(self) -> [BlockPos; 26] {
+        let mut neighbors = [self; 26];
+        let mut i = 0;
+        for y in -1..=1 {
+            for z in -1..=1 {
+                for x in -1..=1 {
+                    if x == 0 && y == 0 && z == 0 {
+                        continue;
+                    }
+                    neighbors[i] = self + BlockPos::new(x, y, z);
+                    i += 1;
+                }
+            }
+        }
+        neighbors
+    }
+
     /// Get the distance (as an f64) of this BlockPos to the origin by
     /// doing `sqrt(x^2 + y^2 + z^2)`.
     pub fn length(&self) -> f64 {
@@ -502,6 +549,38 @@ impl BlockPos {
     pub fn distance_to(self, other: Self) -> f64 {
         (self - other).length()
     }
+
+    /// Get the x and z coordinates of the chunk that this block is in.
+    ///
+    /// This is the same as converting to a [`ChunkPos`], but returned as a
+    /// tuple.
+    pub fn chunk_section_pos(&self) -> (i32, i32) {
+        let ChunkPos { x, z } = ChunkPos::from(self);
+        (x, z)
+    }
+
+    /// Get the y coordinate of the chunk section that this block is in.
+    ///
+    /// Note that this isn't an index into a chunk's sections, since worlds can
+    /// have a negative minimum y.
+    pub fn section_y(&self) -> i32 {
+        ChunkSectionPos::block_to_section_coord(self.y)
+    }
+
+    /// Get the coordinates of this block relative to the chunk section that
+    /// it's in. Each coordinate is in the range `0..16`.
+    ///
+    /// ```
+    /// # use azalea_core::position::BlockPos;
+    /// assert_eq!(
+    ///     BlockPos::new(-1, -1, -1).local_within_section(),
+    ///     (15, 15, 15)
+    /// );
+    /// ```
+    pub fn local_within_section(&self) -> (u8, u8, u8) {
+        let ChunkSectionBlockPos { x, y, z } = ChunkSectionBlockPos::from(*self);
+        (x, y, z)
+    }
 }
 #[cfg(feature = "serde")]
 impl serde::Serialize for BlockPos {
@@ -896,6 +975,20 @@ impl From<Vec3> for BlockPos {
         BlockPos::from(&pos)
     }
 }
+/// Get the minimum corner of the block, equivalent to
+/// [`BlockPos::to_vec3_floored`].
+impl From<BlockPos> for Vec3 {
+    #[inline]
+    fn from(pos: BlockPos) -> Self {
+        pos.to_vec3_floored()
+    }
+}
+impl From<&BlockPos> for Vec3 {
+    #[inline]
+    fn from(pos: &BlockPos) -> Self {
+        pos.to_vec3_floored()
+    }
+}
 
 impl From<&Vec3> for ChunkPos {
     fn from(pos: &Vec3) -> Self {
@@ -1100,6 +1193,34 @@ impl FromStr for Vec3 {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_block_pos_neighbors() {
+        assert_eq!(
+            BlockPos::ZERO.neighbors(),
+            [
+                BlockPos::new(0, -1, 0),
+                BlockPos::new(0, 1, 0),
+                BlockPos::new(0, 0, -1),
+                BlockPos::new(0, 0, 1),
+                BlockPos::new(-1, 0, 0),
+                BlockPos::new(1, 0, 0),
+            ]
+        );
+
+        let pos = BlockPos::new(5, -3, 100);
+        let neighbors = pos.neighbors_including_diagonals();
+        assert!(!neighbors.contains(&pos));
+        for neighbor in pos.neighbors() {
+            assert!(neighbors.contains(&neighbor));
+        }
+        for (i, neighbor) in neighbors.iter().enumerate() {
+            let offset = *neighbor - pos;
+            assert!(offset.x.abs() <= 1 && offset.y.abs() <= 1 && offset.z.abs() <= 1);
+            // no duplicates
+            assert!(!neighbors[..i].contains(neighbor));
+        }
+    }
+
     #[test]
     fn test_from_block_pos_to_chunk_pos() {
         let block_pos = BlockPos::new(5, 78, -2);
@@ -1136,6 +1257,24 @@ mod tests {
         assert_eq!(chunk_pos, ChunkPos::new(1, -2));
     }
 
+    #[test]
+    fn test_block_pos_vec3_conversions() {
+        assert_eq!(
+            Vec3::from(BlockPos::new(-3, 0, 5)),
+            Vec3::new(-3.0, 0.0, 5.0)
+        );
+        assert_eq!(
+            BlockPos::from_vec3_floor(Vec3::new(-0.01, -1.0, -1.99)),
+            BlockPos::new(-1, -1, -2)
+        );
+        assert_eq!(
+            BlockPos::from_vec3_floor(Vec3::new(0.99, 1.0, 15.5)),
+            BlockPos::new(0, 1, 15)
+        );
+        let pos = BlockPos::new(-7, -64, 12);
+        assert_eq!(BlockPos::from_vec3_floor(Vec3::from(pos)), pos);
+    }
+
     #[test]
     fn test_read_blockpos_from() {
         let mut buf = Vec::new();
@@ -1154,6 +1293,24 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_block_pos_section_helpers() {
+        let pos = BlockPos::new(-1, -1, -1);
+        assert_eq!(pos.chunk_section_pos(), (-1, -1));
+        assert_eq!(pos.section_y(), -1);
+        assert_eq!(pos.local_within_section(), (15, 15, 15));
+
+        let pos = BlockPos::new(-16, -17, -33);
+        assert_eq!(pos.chunk_section_pos(), (-1, -3));
+        assert_eq!(pos.section_y(), -2);
+        assert_eq!(pos.local_within_section(), (0, 15, 15));
+
+        let pos = BlockPos::new(17, 64, 31);
+        assert_eq!(pos.chunk_section_pos(), (1, 1));
+        assert_eq!(pos.section_y(), 4);
+        assert_eq!(pos.local_within_section(), (1, 0, 15));
+    }
+
     #[test]
     fn test_read_chunk_pos_from() {
         let mut buf = Vec::new();
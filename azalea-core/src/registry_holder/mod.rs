@@ -108,6 +108,31 @@ macro_rules! registry_holder {
                     .and_then(|r| r.map.get_index(index))
                     .map(|(k, _)| k)
             }
+
+            /// Get the protocol ID for an entry in a registry from its name.
+            ///
+            /// This is the inverse of [`Self::protocol_id_to_identifier`].
+            pub fn identifier_to_protocol_id(
+                &self,
+                registry: Identifier,
+                name: &Identifier,
+            ) -> Option<u32> {
+                if registry.namespace() == "minecraft" {
+                    match registry.path() {
+                        $(
+                            stringify!($registry) => {
+                                return self.$registry.map.get_index_of(name).map(|i| i as u32);
+                            }
+                        )*
+                        _ => {}
+                    }
+                }
+
+                self.extra
+                    .get(&registry)
+                    .and_then(|r| r.map.get_index_of(name))
+                    .map(|i| i as u32)
+            }
         }
     };
 }
@@ -226,3 +251,45 @@ pub fn get_in_compound<T: FromNbtTag>(
     let value = compound.get(key).ok_or(DeserializeError::MissingField)?;
     T::from_nbt_tag(value).ok_or(DeserializeError::MissingField)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn protocol_id_lookup() {
+        let mut registries = RegistryHolder::default();
+        registries.append(
+            Identifier::new("worldgen/biome"),
+            vec![
+                (Identifier::new("plains"), Some(NbtCompound::new())),
+                (Identifier::new("desert"), Some(NbtCompound::new())),
+            ],
+        );
+        registries.append(
+            Identifier::new("enchantment"),
+            vec![(Identifier::new("sharpness"), None)],
+        );
+
+        let biome = Identifier::new("worldgen/biome");
+        assert_eq!(
+            registries.identifier_to_protocol_id(biome.clone(), &Identifier::new("desert")),
+            Some(1)
+        );
+        assert_eq!(
+            registries.protocol_id_to_identifier(biome.clone(), 1),
+            Some(&Identifier::new("desert"))
+        );
+        assert_eq!(
+            registries.identifier_to_protocol_id(biome, &Identifier::new("ocean")),
+            None
+        );
+        assert_eq!(
+            registries.identifier_to_protocol_id(
+                Identifier::new("enchantment"),
+                &Identifier::new("sharpness")
+            ),
+            None
+        );
+    }
+}
@@ -89,17 +89,53 @@ pub fn create_cipher(key: &[u8]) -> (Aes128CfbEnc, Aes128CfbDec) {
     )
 }
 
+/// Encrypt the data in place.
+///
+/// Since AES/CFB8 works on one byte at a time, this accepts data of any
+/// length, and encrypting an empty slice does nothing.
 pub fn encrypt_packet(cipher: &mut Aes128CfbEnc, packet: &mut [u8]) {
+    if packet.is_empty() {
+        return;
+    }
     let (chunks, rest) = InOutBuf::from(packet).into_chunks();
     assert!(rest.is_empty());
     cipher.encrypt_blocks_inout(chunks);
 }
+/// Decrypt the data in place.
+///
+/// Since AES/CFB8 works on one byte at a time, this accepts data of any
+/// length, and decrypting an empty slice does nothing.
 pub fn decrypt_packet(cipher: &mut Aes128CfbDec, packet: &mut [u8]) {
+    if packet.is_empty() {
+        return;
+    }
     let (chunks, rest) = InOutBuf::from(packet).into_chunks();
     assert!(rest.is_empty());
     cipher.decrypt_blocks_inout(chunks);
 }
 
+/// Encrypt `len` bytes of the buffer in place, starting at `start`, and leave
+/// the rest of the buffer unchanged.
+///
+/// # Panics
+///
+/// Panics if the range is out of bounds for the buffer.
+pub fn encrypt_packet_range(cipher: &mut Aes128CfbEnc, buf: &mut [u8], start: usize, len: usize) {
+    encrypt_packet(cipher, &mut buf[start..start + len]);
+}
+/// Decrypt `len` bytes of the buffer in place, starting at `start`, and leave
+/// the rest of the buffer unchanged.
+///
+/// This is useful for decrypting only the payload of a framed buffer without
+/// copying it.
+///
+/// # Panics
+///
+/// Panics if the range is out of bounds for the buffer.
+pub fn decrypt_packet_range(cipher: &mut Aes128CfbDec, buf: &mut [u8], start: usize, len: usize) {
+    decrypt_packet(cipher, &mut buf[start..start + len]);
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -173,4 +209,32 @@ mod tests {
             vec![0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09]
         );
     }
+
+    #[test]
+    fn encrypt_decrypt_empty() {
+        let (mut enc_cipher, mut dec_cipher) = create_cipher(b"1234567890123456");
+        let mut empty = Vec::new();
+        encrypt_packet(&mut enc_cipher, &mut empty);
+        decrypt_packet(&mut dec_cipher, &mut empty);
+        assert!(empty.is_empty());
+
+        // the cipher state wasn't changed by the empty packets
+        let mut packet = vec![0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09];
+        encrypt_packet(&mut enc_cipher, &mut packet);
+        assert_eq!(packet, vec![117, 151, 183, 45, 229, 232, 43, 181, 121, 16]);
+    }
+
+    #[test]
+    fn encrypt_decrypt_range() {
+        let (mut enc_cipher, mut dec_cipher) = create_cipher(b"1234567890123456");
+        let mut buf = vec![0xff, 0xff, 0x00, 0x01, 0x02, 0x03, 0x04, 0xff];
+        encrypt_packet_range(&mut enc_cipher, &mut buf, 2, 5);
+        assert_eq!(buf, vec![0xff, 0xff, 117, 151, 183, 45, 229, 0xff]);
+
+        decrypt_packet_range(&mut dec_cipher, &mut buf, 2, 5);
+        assert_eq!(buf, vec![0xff, 0xff, 0x00, 0x01, 0x02, 0x03, 0x04, 0xff]);
+
+        // empty ranges are fine too
+        encrypt_packet_range(&mut enc_cipher, &mut buf, 8, 0);
+    }
 }
@@ -41,12 +41,7 @@ impl EntityDimensions {
     }
 
     pub fn make_bounding_box(&self, pos: Vec3) -> Aabb {
-        let radius = (self.width / 2.0) as f64;
-        let height = self.height as f64;
-        Aabb {
-            min: Vec3::new(pos.x - radius, pos.y, pos.z - radius),
-            max: Vec3::new(pos.x + radius, pos.y + height, pos.z + radius),
-        }
+        Aabb::from_center_size(pos, self.width as f64, self.height as f64)
     }
 }
 
@@ -112,6 +112,26 @@ impl ItemStack {
         }
     }
 
+    /// Whether this item is the same as another item, ignoring the count.
+    ///
+    /// Two empty slots are considered to be the same.
+    ///
+    /// Also see [`ItemStackData::is_same_item_and_components`].
+    pub fn is_same_item_and_components(&self, other: &ItemStack) -> bool {
+        self.kind() == other.kind() && self.component_patch() == other.component_patch()
+    }
+
+    /// Whether some of the items in `other` could be added to this stack.
+    ///
+    /// This is true if both stacks are present, they have the same item and
+    /// components, the item is stackable, and this stack isn't already full.
+    pub fn is_stackable_with(&self, other: &ItemStack) -> bool {
+        match (self, other) {
+            (ItemStack::Present(a), ItemStack::Present(b)) => a.is_stackable_with(b),
+            _ => false,
+        }
+    }
+
     pub fn component_patch(&self) -> &DataComponentPatch {
         self.as_present()
             .map_or_else(|| &*EMPTY_DATA_COMPONENT_PATCH, |i| &i.component_patch)
@@ -209,6 +229,25 @@ impl ItemStackData {
         self.kind == other.kind && self.component_patch == other.component_patch
     }
 
+    /// Whether some of the items in `other` could be added to this stack.
+    ///
+    /// See [`ItemStack::is_stackable_with`].
+    pub fn is_stackable_with(&self, other: &ItemStackData) -> bool {
+        if self.is_empty() || other.is_empty() || !self.is_same_item_and_components(other) {
+            return false;
+        }
+        let max_stack_size = self.max_stack_size();
+        max_stack_size > 1 && self.count < max_stack_size
+    }
+
+    /// Get the maximum number of this item that can fit in one stack,
+    /// taking the [`MaxStackSize`](components::MaxStackSize) component into
+    /// account.
+    pub fn max_stack_size(&self) -> i32 {
+        self.get_component::<components::MaxStackSize>()
+            .map_or(64, |c| c.count)
+    }
+
     /// Get the value of a data component for this item.
     ///
     /// This is used for things like getting the damage of an item, or seeing
@@ -525,4 +564,36 @@ mod tests {
         let map_id = item.get_component::<MapId>().unwrap();
         assert_eq!(map_id.id, 1);
     }
+
+    #[test]
+    fn test_same_item_and_components() {
+        let a = ItemStack::new(ItemKind::Map, 1).with_component(MapId { id: 1 });
+        let b = ItemStack::new(ItemKind::Map, 5).with_component(MapId { id: 1 });
+        let c = ItemStack::new(ItemKind::Map, 1).with_component(MapId { id: 2 });
+
+        assert!(a.is_same_item_and_components(&b));
+        assert!(!a.is_same_item_and_components(&c));
+        assert!(!a.is_same_item_and_components(&ItemStack::new(ItemKind::Map, 1)));
+        assert!(ItemStack::Empty.is_same_item_and_components(&ItemStack::Empty));
+
+        assert!(a.is_stackable_with(&b));
+        assert!(!a.is_stackable_with(&c));
+        assert!(!a.is_stackable_with(&ItemStack::Empty));
+    }
+
+    #[test]
+    fn test_stackable_with_max_stack_size() {
+        let full = ItemStack::new(ItemKind::Stone, 64);
+        let partial = ItemStack::new(ItemKind::Stone, 10);
+        assert!(!full.is_stackable_with(&partial));
+        assert!(partial.is_stackable_with(&full));
+
+        let sword = ItemStack::new(ItemKind::DiamondSword, 1);
+        assert!(!sword.is_stackable_with(&sword.clone()));
+
+        let pearl = ItemStack::new(ItemKind::EnderPearl, 15);
+        assert!(pearl.is_stackable_with(&pearl.clone()));
+        let pearl = ItemStack::new(ItemKind::EnderPearl, 16);
+        assert!(!pearl.is_stackable_with(&pearl.clone()));
+    }
 }
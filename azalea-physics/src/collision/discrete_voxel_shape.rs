@@ -1,5 +1,9 @@
-use std::cmp;
+use std::{
+    cmp,
+    io::{self, Cursor, Write},
+};
 
+use azalea_buf::{AzBuf, AzBufVar, BufReadError};
 use azalea_core::{
     bitset::BitSet,
     direction::{Axis, AxisCycle},
@@ -10,7 +14,7 @@ use super::mergers::IndexMerger;
 
 pub trait IntLineConsumer = FnMut(u32, u32, u32, u32, u32, u32);
 
-#[derive(Clone, Debug, Eq, PartialEq)]
+#[derive(AzBuf, Clone, Debug, Eq, PartialEq)]
 pub enum DiscreteVoxelShape {
     BitSet(BitSetDiscreteVoxelShape),
 }
@@ -310,6 +314,56 @@ impl BitSetDiscreteVoxelShape {
     }
 }
 
+impl AzBuf for BitSetDiscreteVoxelShape {
+    fn azalea_read(buf: &mut Cursor<&[u8]>) -> Result<Self, BufReadError> {
+        let x_size = u32::azalea_read_var(buf)?;
+        let y_size = u32::azalea_read_var(buf)?;
+        let z_size = u32::azalea_read_var(buf)?;
+        let storage = BitSet::azalea_read(buf)?;
+        let min = Vec3i::azalea_read(buf)?;
+        let max = Vec3i::azalea_read(buf)?;
+
+        let num_bits = (x_size as u64) * (y_size as u64) * (z_size as u64);
+        if (storage.len() as u64) < num_bits {
+            return Err(BufReadError::Custom(format!(
+                "Voxel shape of size {x_size}x{y_size}x{z_size} needs {num_bits} bits, but only {} were given",
+                storage.len()
+            )));
+        }
+        let size = Vec3i::new(x_size as i32, y_size as i32, z_size as i32);
+        for bound in [min, max] {
+            if bound.x < 0
+                || bound.y < 0
+                || bound.z < 0
+                || bound.x > size.x
+                || bound.y > size.y
+                || bound.z > size.z
+            {
+                return Err(BufReadError::Custom(format!(
+                    "Voxel shape bound {bound:?} is outside of its size {size:?}"
+                )));
+            }
+        }
+
+        Ok(Self {
+            x_size,
+            y_size,
+            z_size,
+            storage,
+            min,
+            max,
+        })
+    }
+    fn azalea_write(&self, buf: &mut impl Write) -> io::Result<()> {
+        self.x_size.azalea_write_var(buf)?;
+        self.y_size.azalea_write_var(buf)?;
+        self.z_size.azalea_write_var(buf)?;
+        self.storage.azalea_write(buf)?;
+        self.min.azalea_write(buf)?;
+        self.max.azalea_write(buf)
+    }
+}
+
 impl From<&DiscreteVoxelShape> for BitSetDiscreteVoxelShape {
     fn from(shape: &DiscreteVoxelShape) -> Self {
         let x_size = shape.size(Axis::X);
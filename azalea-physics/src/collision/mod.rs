@@ -393,48 +393,7 @@ fn collide_bounding_box(
     let block_collisions =
         get_block_collisions(world, &entity_bounding_box.expand_towards(movement));
     collision_boxes.extend(block_collisions);
-    collide_with_shapes(movement, *entity_bounding_box, &collision_boxes)
-}
-
-fn collide_with_shapes(
-    mut movement: Vec3,
-    mut entity_box: Aabb,
-    collision_boxes: &[VoxelShape],
-) -> Vec3 {
-    if collision_boxes.is_empty() {
-        return movement;
-    }
-
-    if movement.y != 0. {
-        movement.y = Shapes::collide(Axis::Y, &entity_box, collision_boxes, movement.y);
-        if movement.y != 0. {
-            entity_box = entity_box.move_relative(Vec3::new(0., movement.y, 0.));
-        }
-    }
-
-    // whether the player is moving more in the z axis than x
-    // this is done to fix a movement bug, minecraft does this too
-    let more_z_movement = movement.x.abs() < movement.z.abs();
-
-    if more_z_movement && movement.z != 0. {
-        movement.z = Shapes::collide(Axis::Z, &entity_box, collision_boxes, movement.z);
-        if movement.z != 0. {
-            entity_box = entity_box.move_relative(Vec3::new(0., 0., movement.z));
-        }
-    }
-
-    if movement.x != 0. {
-        movement.x = Shapes::collide(Axis::X, &entity_box, collision_boxes, movement.x);
-        if movement.x != 0. {
-            entity_box = entity_box.move_relative(Vec3::new(movement.x, 0., 0.));
-        }
-    }
-
-    if !more_z_movement && movement.z != 0. {
-        movement.z = Shapes::collide(Axis::Z, &entity_box, collision_boxes, movement.z);
-    }
-
-    movement
+    Shapes::collide_all(entity_bounding_box, &collision_boxes, movement)
 }
 
 /// Get the [`VoxelShape`] for the given fluid state.
@@ -1,5 +1,11 @@
-use std::{cmp, num::NonZeroU32, sync::LazyLock};
+use std::{
+    cmp,
+    io::{self, Cursor, Write},
+    num::NonZeroU32,
+    sync::{LazyLock, OnceLock},
+};
 
+use azalea_buf::{AzBuf, AzBufVar, BufReadError};
 use azalea_core::{
     direction::{Axis, AxisCycle, Direction},
     hit_result::BlockHitResult,
@@ -116,6 +122,85 @@ impl Shapes {
         movement
     }
 
+    /// Collide a moving box with the given shapes on every axis, and return
+    /// the movement clamped so the box doesn't go inside any of them.
+    ///
+    /// The axes are resolved in the same order as vanilla: Y first, then
+    /// whichever of X and Z has the larger movement, and then the other one.
+    /// Every axis is collided after the box has already been moved on the
+    /// previous ones.
+    pub fn collide_all(
+        entity_box: &Aabb,
+        collision_boxes: &[VoxelShape],
+        mut movement: Vec3,
+    ) -> Vec3 {
+        if collision_boxes.is_empty() {
+            return movement;
+        }
+
+        let mut entity_box = *entity_box;
+
+        if movement.y != 0. {
+            movement.y = Self::collide(Axis::Y, &entity_box, collision_boxes, movement.y);
+            if movement.y != 0. {
+                entity_box = entity_box.move_relative(Vec3::new(0., movement.y, 0.));
+            }
+        }
+
+        // whether the player is moving more in the z axis than x
+        // this is done to fix a movement bug, minecraft does this too
+        let more_z_movement = movement.x.abs() < movement.z.abs();
+
+        if more_z_movement && movement.z != 0. {
+            movement.z = Self::collide(Axis::Z, &entity_box, collision_boxes, movement.z);
+            if movement.z != 0. {
+                entity_box = entity_box.move_relative(Vec3::new(0., 0., movement.z));
+            }
+        }
+
+        if movement.x != 0. {
+            movement.x = Self::collide(Axis::X, &entity_box, collision_boxes, movement.x);
+            if movement.x != 0. {
+                entity_box = entity_box.move_relative(Vec3::new(movement.x, 0., 0.));
+            }
+        }
+
+        if !more_z_movement && movement.z != 0. {
+            movement.z = Self::collide(Axis::Z, &entity_box, collision_boxes, movement.z);
+        }
+
+        movement
+    }
+
+    /// Sweep a box along `movement` and find the first of the given shapes
+    /// that it hits.
+    ///
+    /// Returns the fraction of the movement (from 0 to 1) that can be done
+    /// before the box touches a shape, and the normal of the face that was
+    /// hit. For example, moving in the positive X direction into a wall gives
+    /// [`Direction::West`].
+    ///
+    /// Shapes that the box is already inside of are ignored, but shapes that
+    /// it's touching and moving towards are hit immediately.
+    pub fn sweep(
+        entity_box: &Aabb,
+        collision_boxes: &[VoxelShape],
+        movement: Vec3,
+    ) -> Option<(f64, Direction)> {
+        let mut closest: Option<(f64, Direction)> = None;
+        for shape in collision_boxes {
+            for aabb in shape.to_aabbs() {
+                let Some((t, direction)) = sweep_aabb(entity_box, &aabb, movement) else {
+                    continue;
+                };
+                if closest.is_none_or(|(closest_t, _)| t < closest_t) {
+                    closest = Some((t, direction));
+                }
+            }
+        }
+        closest
+    }
+
     pub fn join(a: VoxelShape, b: VoxelShape, op: fn(bool, bool) -> bool) -> VoxelShape {
         Self::join_unoptimized(a, b, op).optimize()
     }
@@ -128,9 +213,15 @@ impl Shapes {
         if op(false, false) {
             panic!("Illegal operation");
         };
-        // if (a == b) {
-        //     return if op(true, true) { a } else { empty_shape() };
-        // }
+        // vanilla only checks if they're the same object, but shapes that are
+        // structurally equal are also geometrically equal so this is fine
+        if a == b {
+            return if op(true, true) {
+                a
+            } else {
+                EMPTY_SHAPE.clone()
+            };
+        }
         let op_true_false = op(true, false);
         let op_false_true = op(false, true);
         if a.is_empty() {
@@ -319,12 +410,67 @@ impl Shapes {
     }
 }
 
-#[derive(Clone, Debug, PartialEq)]
+/// A shape made out of boxes, used for collisions.
+///
+/// This can be serialized with [`AzBuf`], or with the [`Self::to_bytes`] and
+/// [`Self::from_bytes`] shortcuts if you want to cache shapes.
+#[derive(AzBuf, Clone, Debug, PartialEq)]
 pub enum VoxelShape {
     Array(ArrayVoxelShape),
     Cube(CubeVoxelShape),
 }
 
+/// Find when the moving box first touches the target box, and the normal of
+/// the face of the target that it touches.
+fn sweep_aabb(moving: &Aabb, target: &Aabb, movement: Vec3) -> Option<(f64, Direction)> {
+    let mut entry = f64::NEG_INFINITY;
+    let mut exit = f64::INFINITY;
+    let mut entry_direction = None;
+
+    for axis in [Axis::X, Axis::Y, Axis::Z] {
+        let delta = axis.choose(movement.x, movement.y, movement.z);
+        if delta.abs() < EPSILON {
+            // not moving on this axis, so the boxes have to already overlap on it
+            if moving.max(&axis) <= target.min(&axis) + EPSILON
+                || moving.min(&axis) >= target.max(&axis) - EPSILON
+            {
+                return None;
+            }
+            continue;
+        }
+
+        let (axis_entry, axis_exit) = if delta > 0. {
+            (
+                (target.min(&axis) - moving.max(&axis)) / delta,
+                (target.max(&axis) - moving.min(&axis)) / delta,
+            )
+        } else {
+            (
+                (target.max(&axis) - moving.min(&axis)) / delta,
+                (target.min(&axis) - moving.max(&axis)) / delta,
+            )
+        };
+        if axis_entry > entry {
+            entry = axis_entry;
+            entry_direction = Some(match (axis, delta > 0.) {
+                (Axis::X, true) => Direction::West,
+                (Axis::X, false) => Direction::East,
+                (Axis::Y, true) => Direction::Down,
+                (Axis::Y, false) => Direction::Up,
+                (Axis::Z, true) => Direction::North,
+                (Axis::Z, false) => Direction::South,
+            });
+        }
+        exit = exit.min(axis_exit);
+    }
+
+    // if the entry is too far in the past, the box was already inside the target
+    if entry >= exit || !(-EPSILON..=1.).contains(&entry) {
+        return None;
+    }
+    Some((entry.max(0.), entry_direction?))
+}
+
 impl VoxelShape {
     fn min(&self, axis: Axis) -> f64 {
         let first_full = self.shape().first_full(axis);
@@ -362,6 +508,130 @@ impl VoxelShape {
         self.shape().is_empty()
     }
 
+    /// Serialize this shape into a compact binary format.
+    ///
+    /// Use [`Self::from_bytes`] to read it back.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut buf = Vec::new();
+        self.azalea_write(&mut buf)
+            .expect("writing to a Vec should never fail");
+        buf
+    }
+
+    /// Read a shape that was serialized with [`Self::to_bytes`].
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, BufReadError> {
+        Self::azalea_read(&mut Cursor::new(bytes))
+    }
+
+    /// Returns whether the two shapes fill exactly the same region.
+    ///
+    /// Unlike `==`, this doesn't care about how the shapes are represented
+    /// internally, so shapes that were built in different ways can still be
+    /// considered equal.
+    pub fn geometrically_eq(&self, other: &VoxelShape) -> bool {
+        !Shapes::matches_anywhere(self, other, |a, b| a != b)
+    }
+
+    /// Returns whether the shape fills exactly the full block, from `(0, 0, 0)`
+    /// to `(1, 1, 1)`.
+    ///
+    /// This is equivalent to `Block.isShapeFullBlock` in vanilla.
+    pub fn is_full_cube(&self) -> bool {
+        self.geometrically_eq(&BLOCK_SHAPE)
+    }
+
+    /// Get the part of this shape that's touching the given side of the block,
+    /// projected onto that side.
+    ///
+    /// This is used for face culling, and is equivalent to
+    /// `VoxelShape.getFaceShape` in vanilla. The result is cached, so calling
+    /// this repeatedly on the same shape is cheap.
+    pub fn face_shape(&self, direction: Direction) -> VoxelShape {
+        if self.is_empty() || self == &*BLOCK_SHAPE {
+            return self.clone();
+        }
+
+        let faces = match self {
+            VoxelShape::Array(s) => &s.faces,
+            VoxelShape::Cube(s) => &s.faces,
+        };
+        faces
+            .0
+            .get_or_init(|| Box::new(Direction::ALL.map(|d| self.calculate_face(d))))
+            [direction as usize]
+            .clone()
+    }
+
+    fn calculate_face(&self, direction: Direction) -> VoxelShape {
+        let axis = direction.axis();
+        if self.is_cube_like_along(axis) {
+            return self.clone();
+        }
+        let index = self.find_index(
+            axis,
+            if direction.is_positive() {
+                1. - EPSILON
+            } else {
+                EPSILON
+            },
+        );
+        let slice = self.slice(axis, index);
+        if slice.is_empty() {
+            EMPTY_SHAPE.clone()
+        } else if [Axis::X, Axis::Y, Axis::Z]
+            .into_iter()
+            .all(|axis| slice.is_cube_like_along(axis))
+        {
+            BLOCK_SHAPE.clone()
+        } else {
+            slice
+        }
+    }
+
+    fn is_cube_like_along(&self, axis: Axis) -> bool {
+        let coords = self.get_coords(axis);
+        coords.len() == 2 && coords[0].abs() < EPSILON && (coords[1] - 1.).abs() < EPSILON
+    }
+
+    /// Get the one voxel thick layer of this shape at the given index on the
+    /// axis, stretched to fill the block on that axis.
+    fn slice(&self, axis: Axis, index: i32) -> VoxelShape {
+        let parent = self.shape();
+        let x_size = axis.choose(1, parent.size(Axis::X), parent.size(Axis::X));
+        let y_size = axis.choose(parent.size(Axis::Y), 1, parent.size(Axis::Y));
+        let z_size = axis.choose(parent.size(Axis::Z), parent.size(Axis::Z), 1);
+
+        let mut shape = BitSetDiscreteVoxelShape::new(x_size, y_size, z_size);
+        for x in 0..x_size {
+            for y in 0..y_size {
+                for z in 0..z_size {
+                    let parent_pos = Vec3i::new(
+                        axis.choose(index, x as i32, x as i32),
+                        axis.choose(y as i32, index, y as i32),
+                        axis.choose(z as i32, z as i32, index),
+                    );
+                    if parent.is_full_wide(parent_pos) {
+                        shape.fill(x, y, z);
+                    }
+                }
+            }
+        }
+
+        let coords = |a: Axis| -> CompactArray<f64> {
+            if a == axis {
+                [0., 1.].into()
+            } else {
+                self.get_coords(a).iter().copied().collect()
+            }
+        };
+        VoxelShape::Array(ArrayVoxelShape::new(
+            DiscreteVoxelShape::BitSet(shape),
+            coords(Axis::X),
+            coords(Axis::Y),
+            coords(Axis::Z),
+        ))
+    }
+
     #[must_use]
     pub fn move_relative(&self, delta: Vec3) -> VoxelShape {
         if self.shape().is_empty() {
@@ -513,7 +783,11 @@ impl VoxelShape {
         movement
     }
 
-    fn optimize(&self) -> VoxelShape {
+    /// Rebuild this shape with as few cells as possible, by merging boxes
+    /// that share exact coordinates.
+    ///
+    /// This is equivalent to `VoxelShape.optimize` in vanilla.
+    pub fn optimize(&self) -> VoxelShape {
         let mut shape = EMPTY_SHAPE.clone();
         self.for_all_boxes(|var1x, var3, var5, var7, var9, var11| {
             shape = Shapes::join_unoptimized(
@@ -525,6 +799,49 @@ impl VoxelShape {
         shape
     }
 
+    /// Like [`Self::optimize`], but coordinates that are within `epsilon` of
+    /// each other are treated as being the same.
+    ///
+    /// This is useful for shapes that were built from boxes with slightly
+    /// imprecise coordinates. Gaps that are larger than `epsilon` are kept.
+    pub fn optimize_with_tolerance(&self, epsilon: f64) -> VoxelShape {
+        let snap_coords = |axis: Axis| {
+            let mut anchor = f64::NEG_INFINITY;
+            self.get_coords(axis)
+                .iter()
+                .map(|&coord| {
+                    if coord - anchor > epsilon {
+                        anchor = coord;
+                    }
+                    anchor
+                })
+                .collect::<Vec<_>>()
+        };
+        let x_coords = snap_coords(Axis::X);
+        let y_coords = snap_coords(Axis::Y);
+        let z_coords = snap_coords(Axis::Z);
+
+        let mut shape = EMPTY_SHAPE.clone();
+        self.shape().for_all_boxes(
+            |min_x, min_y, min_z, max_x, max_y, max_z| {
+                let (min_x, max_x) = (x_coords[min_x as usize], x_coords[max_x as usize]);
+                let (min_y, max_y) = (y_coords[min_y as usize], y_coords[max_y as usize]);
+                let (min_z, max_z) = (z_coords[min_z as usize], z_coords[max_z as usize]);
+                // the box may have been collapsed by the snapping
+                if max_x - min_x < EPSILON || max_y - min_y < EPSILON || max_z - min_z < EPSILON {
+                    return;
+                }
+                shape = Shapes::join_unoptimized(
+                    shape.clone(),
+                    box_shape(min_x, min_y, min_z, max_x, max_y, max_z),
+                    |a, b| a || b,
+                );
+            },
+            true,
+        );
+        shape.optimize()
+    }
+
     pub fn for_all_boxes(&self, mut consumer: impl FnMut(f64, f64, f64, f64, f64, f64))
     where
         Self: Sized,
@@ -583,9 +900,7 @@ impl From<Aabb> for VoxelShape {
 #[derive(Clone, Debug, PartialEq)]
 pub struct ArrayVoxelShape {
     shape: DiscreteVoxelShape,
-    // TODO: check where faces is used in minecraft
-    #[allow(dead_code)]
-    faces: Option<Box<[VoxelShape]>>,
+    faces: FaceCache,
 
     xs: CompactArray<f64>,
     ys: CompactArray<f64>,
@@ -595,15 +910,25 @@ pub struct ArrayVoxelShape {
 #[derive(Clone, Debug, PartialEq)]
 pub struct CubeVoxelShape {
     shape: DiscreteVoxelShape,
-    // TODO: check where faces is used in minecraft
-    #[allow(dead_code)]
-    faces: Option<Vec<VoxelShape>>,
+    faces: FaceCache,
 
     x_coords: CompactArray<f64>,
     y_coords: CompactArray<f64>,
     z_coords: CompactArray<f64>,
 }
 
+/// The lazily calculated results of [`VoxelShape::face_shape`], indexed by
+/// [`Direction`].
+///
+/// This is ignored when comparing shapes, since it's only a cache.
+#[derive(Clone, Debug, Default)]
+struct FaceCache(OnceLock<Box<[VoxelShape; 6]>>);
+impl PartialEq for FaceCache {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
 impl ArrayVoxelShape {
     pub fn new(
         shape: DiscreteVoxelShape,
@@ -621,7 +946,7 @@ impl ArrayVoxelShape {
         debug_assert_eq!(z_size, zs.len() as u32);
 
         Self {
-            faces: None,
+            faces: FaceCache::default(),
             shape,
             xs,
             ys,
@@ -639,6 +964,31 @@ impl ArrayVoxelShape {
     }
 }
 
+impl AzBuf for ArrayVoxelShape {
+    fn azalea_read(buf: &mut Cursor<&[u8]>) -> Result<Self, BufReadError> {
+        let shape = DiscreteVoxelShape::azalea_read(buf)?;
+        let xs = CompactArray::<f64>::azalea_read(buf)?;
+        let ys = CompactArray::<f64>::azalea_read(buf)?;
+        let zs = CompactArray::<f64>::azalea_read(buf)?;
+        for (axis, coords) in [(Axis::X, &xs), (Axis::Y, &ys), (Axis::Z, &zs)] {
+            let expected_len = shape.size(axis) as usize + 1;
+            if coords.len() != expected_len {
+                return Err(BufReadError::Custom(format!(
+                    "Expected {expected_len} coordinates on the {axis:?} axis, but got {}",
+                    coords.len()
+                )));
+            }
+        }
+        Ok(Self::new(shape, xs, ys, zs))
+    }
+    fn azalea_write(&self, buf: &mut impl Write) -> io::Result<()> {
+        self.shape.azalea_write(buf)?;
+        self.xs.azalea_write(buf)?;
+        self.ys.azalea_write(buf)?;
+        self.zs.azalea_write(buf)
+    }
+}
+
 impl CubeVoxelShape {
     pub fn new(shape: DiscreteVoxelShape) -> Self {
         let x_coords = Self::calculate_coords(&shape, Axis::X);
@@ -647,7 +997,7 @@ impl CubeVoxelShape {
 
         Self {
             shape,
-            faces: None,
+            faces: FaceCache::default(),
             x_coords,
             y_coords,
             z_coords,
@@ -678,6 +1028,16 @@ impl CubeVoxelShape {
     }
 }
 
+impl AzBuf for CubeVoxelShape {
+    fn azalea_read(buf: &mut Cursor<&[u8]>) -> Result<Self, BufReadError> {
+        // the coordinates can be calculated from the shape
+        Ok(Self::new(DiscreteVoxelShape::azalea_read(buf)?))
+    }
+    fn azalea_write(&self, buf: &mut impl Write) -> io::Result<()> {
+        self.shape.azalea_write(buf)
+    }
+}
+
 #[derive(Debug)]
 pub struct CubePointRange {
     /// Needs at least 1 part
@@ -716,6 +1076,18 @@ impl<T: Clone + Copy> CompactArray<T> {
         }
     }
 }
+impl<T: AzBuf + Clone + Copy> AzBuf for CompactArray<T> {
+    fn azalea_read(buf: &mut Cursor<&[u8]>) -> Result<Self, BufReadError> {
+        Ok(Self::from(Box::<[T]>::azalea_read(buf)?))
+    }
+    fn azalea_write(&self, buf: &mut impl Write) -> io::Result<()> {
+        (self.len() as u32).azalea_write_var(buf)?;
+        for item in self.as_slice() {
+            item.azalea_write(buf)?;
+        }
+        Ok(())
+    }
+}
 impl<T: Clone + Copy> From<Box<[T]>> for CompactArray<T> {
     fn from(value: Box<[T]>) -> Self {
         if value.len() == 1 {
@@ -780,6 +1152,22 @@ mod tests {
         assert_eq!(shape.get_coords(Axis::Z).len(), 2);
     }
 
+    #[test]
+    fn test_shape_bytes_roundtrip() {
+        let slab = box_shape(0., 0., 0., 1., 0.5, 1.);
+        let odd_box = box_shape(0.1, 0.2, 0.3, 0.7, 0.8, 0.9);
+        let stairs = Shapes::or(slab.clone(), box_shape(0., 0.5, 0.5, 1., 1., 1.));
+        for shape in [&*BLOCK_SHAPE, &*EMPTY_SHAPE, &slab, &odd_box, &stairs] {
+            let read_shape = VoxelShape::from_bytes(&shape.to_bytes()).unwrap();
+            assert_eq!(&read_shape, shape);
+            assert!(read_shape.geometrically_eq(shape));
+        }
+        assert!(matches!(odd_box, VoxelShape::Array(_)));
+
+        let bytes = slab.to_bytes();
+        assert!(VoxelShape::from_bytes(&bytes[..bytes.len() - 1]).is_err());
+    }
+
     #[test]
     fn test_join_is_not_empty() {
         let shape = box_shape(0., 0., 0., 1., 1., 1.);
@@ -789,6 +1177,111 @@ mod tests {
         assert!(joined, "Shapes should intersect");
     }
 
+    #[test]
+    fn test_geometrically_eq() {
+        let slab = box_shape(0., 0., 0., 1., 0.5, 1.);
+        let joined_slab = Shapes::join_unoptimized(
+            box_shape(0., 0., 0., 0.5, 0.5, 1.),
+            box_shape(0.5, 0., 0., 1., 0.5, 1.),
+            |a, b| a || b,
+        );
+        assert_ne!(slab, joined_slab);
+        assert!(slab.geometrically_eq(&joined_slab));
+        assert!(joined_slab.geometrically_eq(&slab));
+
+        let top_slab = box_shape(0., 0.5, 0., 1., 1., 1.);
+        assert!(!slab.geometrically_eq(&top_slab));
+        assert!(!slab.geometrically_eq(&BLOCK_SHAPE));
+        assert!(!slab.geometrically_eq(&EMPTY_SHAPE));
+        assert!(EMPTY_SHAPE.geometrically_eq(&EMPTY_SHAPE));
+    }
+
+    #[test]
+    fn test_is_full_cube() {
+        assert!(BLOCK_SHAPE.is_full_cube());
+        assert!(box_shape(0., 0., 0., 1., 1., 1.).is_full_cube());
+        assert!(
+            Shapes::join_unoptimized(
+                box_shape(0., 0., 0., 1., 0.5, 1.),
+                box_shape(0., 0.5, 0., 1., 1., 1.),
+                |a, b| a || b,
+            )
+            .is_full_cube()
+        );
+
+        assert!(!box_shape(0., 0., 0., 1., 0.5, 1.).is_full_cube());
+        assert!(!EMPTY_SHAPE.is_full_cube());
+        // same bounds as a full block, but with a hole in the middle
+        assert!(
+            !Shapes::join_unoptimized(
+                BLOCK_SHAPE.clone(),
+                box_shape(0.25, 0.25, 0.25, 0.75, 0.75, 0.75),
+                |a, b| a && !b,
+            )
+            .is_full_cube()
+        );
+    }
+
+    #[test]
+    fn test_face_shape() {
+        for direction in Direction::ALL {
+            assert!(BLOCK_SHAPE.face_shape(direction).is_full_cube());
+        }
+
+        let bottom_slab = box_shape(0., 0., 0., 1., 0.5, 1.);
+        assert!(bottom_slab.face_shape(Direction::Down).is_full_cube());
+        assert!(bottom_slab.face_shape(Direction::Up).is_empty());
+        // the sides are only half covered, stretched across the face
+        let north = bottom_slab.face_shape(Direction::North);
+        assert!(!north.is_full_cube());
+        assert!(north.geometrically_eq(&box_shape(0., 0., 0., 1., 0.5, 1.)));
+        // cached results are the same
+        assert_eq!(bottom_slab.face_shape(Direction::North), north);
+
+        let centered = box_shape(0.25, 0.25, 0.25, 0.75, 0.75, 0.75);
+        assert!(centered.face_shape(Direction::East).is_empty());
+    }
+
+    #[test]
+    fn test_optimize() {
+        let joined = Shapes::join_unoptimized(
+            box_shape(0., 0., 0., 1., 0.5, 1.),
+            box_shape(0., 0.5, 0., 1., 1., 1.),
+            |a, b| a || b,
+        );
+        assert_eq!(joined.get_coords(Axis::Y).len(), 3);
+
+        let optimized = joined.optimize();
+        assert_eq!(optimized.get_coords(Axis::Y).len(), 2);
+        assert_eq!(optimized.to_aabbs().len(), 1);
+        assert!(optimized.is_full_cube());
+    }
+
+    #[test]
+    fn test_optimize_with_tolerance() {
+        let almost_touching = Shapes::join_unoptimized(
+            box_shape(0., 0., 0., 1., 0.5, 1.),
+            box_shape(0., 0.500001, 0., 1., 1., 1.),
+            |a, b| a || b,
+        );
+        // the exact optimization keeps the tiny gap
+        assert_eq!(almost_touching.optimize().to_aabbs().len(), 2);
+
+        let optimized = almost_touching.optimize_with_tolerance(1e-4);
+        assert_eq!(optimized.to_aabbs().len(), 1);
+        assert!(optimized.is_full_cube());
+
+        // real gaps aren't merged
+        let gap = Shapes::join_unoptimized(
+            box_shape(0., 0., 0., 1., 0.4, 1.),
+            box_shape(0., 0.6, 0., 1., 1., 1.),
+            |a, b| a || b,
+        );
+        let optimized = gap.optimize_with_tolerance(1e-4);
+        assert_eq!(optimized.to_aabbs().len(), 2);
+        assert!(optimized.geometrically_eq(&gap));
+    }
+
     #[test]
     fn clip_in_front_of_block() {
         let block_shape = &*BLOCK_SHAPE;
@@ -816,4 +1309,82 @@ mod tests {
             }
         );
     }
+
+    #[test]
+    fn collide_all_into_corner() {
+        let collision_boxes = [
+            // the floor
+            BLOCK_SHAPE.move_relative(Vec3::new(0., -1., 0.)),
+            // a wall in front of us on the x axis
+            BLOCK_SHAPE.move_relative(Vec3::new(2., 0., 0.)),
+            // and a wall on the z axis, that's only reached after moving on x
+            BLOCK_SHAPE.move_relative(Vec3::new(1., 0., 2.)),
+        ];
+        let entity_box = Aabb {
+            min: Vec3::new(0.2, 0., 0.2),
+            max: Vec3::new(0.8, 0.9, 0.8),
+        };
+
+        let movement = Shapes::collide_all(&entity_box, &collision_boxes, Vec3::new(3., -1., 2.));
+        assert_eq!(movement, Vec3::new(1.2, 0., 1.2));
+
+        // nothing to collide with
+        assert_eq!(
+            Shapes::collide_all(&entity_box, &[], Vec3::new(3., -1., 2.)),
+            Vec3::new(3., -1., 2.)
+        );
+    }
+
+    #[test]
+    fn join_identical_shapes() {
+        let shape = box_shape(0., 0., 0., 1., 0.5, 1.);
+
+        let joined = Shapes::join_unoptimized(shape.clone(), shape.clone(), |a, b| a || b);
+        assert_eq!(joined, shape);
+        let joined = Shapes::join_unoptimized(shape.clone(), shape.clone(), |a, b| a && b);
+        assert_eq!(joined, shape);
+        // only true when exactly one of them is
+        let joined = Shapes::join_unoptimized(shape.clone(), shape.clone(), |a, b| a != b);
+        assert!(joined.is_empty());
+
+        // shapes that aren't identical still get merged
+        let top = box_shape(0., 0.5, 0., 1., 1., 1.);
+        assert!(Shapes::or(shape, top).geometrically_eq(&BLOCK_SHAPE));
+    }
+
+    #[test]
+    fn test_sweep() {
+        let player = Aabb::from_center_size(Vec3::new(0.5, 0., 0.5), 0.6, 1.8);
+        // a wall at x=2
+        let wall = [box_shape(0., 0., 0., 1., 1., 1.).move_relative(Vec3::new(2., 0., 0.))];
+
+        // walking into the wall hits its west face after 1.2 blocks
+        let (t, direction) = Shapes::sweep(&player, &wall, Vec3::new(2., 0., 0.)).unwrap();
+        assert!((t - 0.6).abs() < 1e-9);
+        assert_eq!(direction, Direction::West);
+
+        // diagonally still hits the same face
+        let (_, direction) = Shapes::sweep(&player, &wall, Vec3::new(2., 0., 0.2)).unwrap();
+        assert_eq!(direction, Direction::West);
+
+        // not far enough, walking away, or walking parallel to the wall
+        assert_eq!(Shapes::sweep(&player, &wall, Vec3::new(1., 0., 0.)), None);
+        assert_eq!(Shapes::sweep(&player, &wall, Vec3::new(-2., 0., 0.)), None);
+        assert_eq!(Shapes::sweep(&player, &wall, Vec3::new(0., 0., 2.)), None);
+
+        // falling onto the ground hits the top face
+        let ground = box_shape(0., 0., 0., 1., 1., 1.).move_relative(Vec3::new(0., -2., 0.));
+        let (t, direction) =
+            Shapes::sweep(&player, &[wall[0].clone(), ground], Vec3::new(0., -2., 0.)).unwrap();
+        assert!((t - 0.5).abs() < 1e-9);
+        assert_eq!(direction, Direction::Up);
+
+        // already touching the ground and moving into it
+        let standing = Aabb::from_center_size(Vec3::new(0.5, -1., 0.5), 0.6, 1.8);
+        let ground = box_shape(0., 0., 0., 1., 1., 1.).move_relative(Vec3::new(0., -2., 0.));
+        assert_eq!(
+            Shapes::sweep(&standing, &[ground], Vec3::new(0., -0.1, 0.)),
+            Some((0., Direction::Up))
+        );
+    }
 }
@@ -123,12 +123,22 @@ struct PacketList {
 
 impl Parse for PacketList {
     fn parse(input: ParseStream) -> Result<Self> {
-        let mut packets = vec![];
+        let mut packets: Vec<Ident> = vec![];
 
         // example:
         // change_difficulty,
         // keep_alive,
         while let Ok(packet_name) = input.parse::<Ident>() {
+            // packet ids are assigned based on their position in the list, so a
+            // duplicate would make every packet after it decode as the wrong one
+            if let Some(previous) = packets.iter().find(|p| **p == packet_name) {
+                let mut err = syn::Error::new(
+                    packet_name.span(),
+                    format!("Packet `{packet_name}` is declared more than once"),
+                );
+                err.combine(syn::Error::new(previous.span(), "first declared here"));
+                return Err(err);
+            }
             packets.push(packet_name);
             if input.parse::<Token![,]>().is_err() {
                 break;
@@ -528,3 +538,27 @@ pub fn declare_packet_handlers(input: TokenStream) -> TokenStream {
     }
     .into()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_duplicate_packet_is_error() {
+        let err = syn::parse_str::<DeclareStatePackets>(
+            "Game, Clientbound => [keep_alive, ping, keep_alive], Serverbound => [pong]",
+        )
+        .unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("`keep_alive` is declared more than once")
+        );
+
+        let packets = syn::parse_str::<DeclareStatePackets>(
+            "Game, Clientbound => [keep_alive, ping], Serverbound => [keep_alive, pong]",
+        )
+        .unwrap();
+        assert_eq!(packets.clientbound.packets.len(), 2);
+        assert_eq!(packets.serverbound.packets.len(), 2);
+    }
+}
@@ -0,0 +1,130 @@
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    hint::black_box,
+    io::Cursor,
+    sync::{
+        Arc,
+        atomic::{AtomicUsize, Ordering},
+    },
+};
+
+use azalea_protocol::{
+    packets::{
+        Packet,
+        game::{
+            ClientboundGamePacket, ClientboundLevelChunkWithLight,
+            c_level_chunk_with_light::ClientboundLevelChunkPacketData,
+            c_light_update::ClientboundLightUpdatePacketData,
+        },
+    },
+    read::{
+        PacketDecodeBuffers, deserialize_packet, read_raw_packet_from_buffer,
+        read_raw_packet_from_buffer_into,
+    },
+    write::{encode_to_network_packet, serialize_packet},
+};
+use criterion::{Criterion, criterion_group, criterion_main};
+
+/// Counts allocations so we can compare how many each way of decoding does.
+struct CountingAllocator;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        unsafe { System.alloc(layout) }
+    }
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        unsafe { System.realloc(ptr, layout, new_size) }
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+const COMPRESSION_THRESHOLD: u32 = 256;
+
+/// The bytes of a chunk packet, like how they'd be received from the network.
+fn chunk_packet_bytes() -> Vec<u8> {
+    let packet = ClientboundLevelChunkWithLight {
+        x: 0,
+        z: 0,
+        chunk_data: ClientboundLevelChunkPacketData {
+            heightmaps: Vec::new(),
+            data: Arc::new((0..64 * 1024).map(|i| (i % 7) as u8).collect()),
+            block_entities: Vec::new(),
+        },
+        light_data: ClientboundLightUpdatePacketData {
+            sky_updates: Arc::new(vec![vec![0xff; 2048].into(); 26].into()),
+            ..Default::default()
+        },
+    }
+    .into_variant();
+    let raw_packet = serialize_packet(&packet).unwrap();
+    encode_to_network_packet(&raw_packet, Some(COMPRESSION_THRESHOLD), &mut None)
+}
+
+fn decode(bytes: &[u8]) -> ClientboundGamePacket {
+    let mut buffer = Cursor::new(bytes.to_vec());
+    let raw_packet = read_raw_packet_from_buffer::<&[u8]>(&mut buffer, Some(COMPRESSION_THRESHOLD))
+        .unwrap()
+        .unwrap();
+    deserialize_packet(&mut Cursor::new(&raw_packet)).unwrap()
+}
+
+fn decode_with_buffers(
+    bytes: &[u8],
+    buffer: &mut Cursor<Vec<u8>>,
+    decode_buffers: &mut PacketDecodeBuffers,
+) -> ClientboundGamePacket {
+    buffer.get_mut().extend_from_slice(bytes);
+    assert!(
+        read_raw_packet_from_buffer_into(buffer, Some(COMPRESSION_THRESHOLD), decode_buffers)
+            .unwrap()
+    );
+    deserialize_packet(&mut Cursor::new(decode_buffers.packet())).unwrap()
+}
+
+fn count_allocations<T>(f: impl FnOnce() -> T) -> usize {
+    let before = ALLOCATIONS.load(Ordering::Relaxed);
+    black_box(f());
+    ALLOCATIONS.load(Ordering::Relaxed) - before
+}
+
+fn benchmark(c: &mut Criterion) {
+    let bytes = chunk_packet_bytes();
+
+    let mut buffer = Cursor::new(Vec::new());
+    let mut decode_buffers = PacketDecodeBuffers::new();
+    // warm up the reusable buffers first, since the first packet always has to
+    // allocate them
+    decode_with_buffers(&bytes, &mut buffer, &mut decode_buffers);
+    println!(
+        "allocations per chunk packet: {} without decode buffers, {} with decode buffers",
+        count_allocations(|| decode(&bytes)),
+        count_allocations(|| decode_with_buffers(&bytes, &mut buffer, &mut decode_buffers))
+    );
+
+    let mut group = c.benchmark_group("decode chunk packet");
+    group.bench_function("without decode buffers", |b| {
+        b.iter(|| black_box(decode(&bytes)));
+    });
+    group.bench_function("with decode buffers", |b| {
+        b.iter(|| {
+            black_box(decode_with_buffers(
+                &bytes,
+                &mut buffer,
+                &mut decode_buffers,
+            ))
+        });
+    });
+    group.finish();
+}
+
+criterion_group!(benches, benchmark);
+criterion_main!(benches);
@@ -0,0 +1,36 @@
+use std::hint::black_box;
+
+use azalea_core::position::Vec3;
+use azalea_protocol::{
+    common::movements::MoveFlags,
+    packets::{Packet, game::ServerboundMovePlayerPos},
+    write::{serialize_packet, serialize_packet_into},
+};
+use criterion::{Criterion, criterion_group, criterion_main};
+
+fn benchmark(c: &mut Criterion) {
+    let packet = ServerboundMovePlayerPos {
+        pos: Vec3::new(1.5, 67., -3.25),
+        flags: MoveFlags {
+            on_ground: true,
+            horizontal_collision: false,
+        },
+    }
+    .into_variant();
+
+    c.bench_function("s_move_player_pos (new buffer)", |b| {
+        b.iter(|| {
+            black_box(serialize_packet(black_box(&packet)).unwrap());
+        });
+    });
+    c.bench_function("s_move_player_pos (reused buffer)", |b| {
+        let mut buf = Vec::new();
+        b.iter(|| {
+            serialize_packet_into(black_box(&packet), &mut buf).unwrap();
+            black_box(&buf);
+        });
+    });
+}
+
+criterion_group!(benches, benchmark);
+criterion_main!(benches);
@@ -5,6 +5,7 @@ use std::{
     io::{self, Cursor},
     marker::PhantomData,
     net::SocketAddr,
+    time::Duration,
 };
 
 #[cfg(feature = "online-mode")]
@@ -36,13 +37,25 @@ use crate::{
         login::{ClientboundLoginPacket, ServerboundLoginPacket},
         status::{ClientboundStatusPacket, ServerboundStatusPacket},
     },
-    read::{ReadPacketError, deserialize_packet, read_raw_packet, try_read_raw_packet},
+    read::{
+        PacketDecodeBuffers, ReadPacketError, deserialize_packet,
+        read_raw_packet_into_with_capacity, try_read_raw_packet_into_with_capacity,
+    },
     write::{serialize_packet, write_raw_packet, write_raw_packets},
 };
 
 pub struct RawReadConnection {
     pub read_stream: OwnedReadHalf,
     pub buffer: Cursor<Vec<u8>>,
+    /// The minimum capacity that [`Self::buffer`] has when we start reading
+    /// into it.
+    ///
+    /// See [`Connection::set_read_buffer_capacity`].
+    pub read_buffer_capacity: usize,
+    /// Reusable buffers that packets are decoded into, if enabled.
+    ///
+    /// See [`Connection::set_reuse_decode_buffers`].
+    pub decode_buffers: Option<PacketDecodeBuffers>,
     pub compression_threshold: Option<u32>,
     pub dec_cipher: Option<Aes128CfbDec>,
 }
@@ -148,22 +161,90 @@ pub struct Connection<R: ProtocolPacket, W: ProtocolPacket> {
 
 impl RawReadConnection {
     pub async fn read(&mut self) -> Result<Box<[u8]>, Box<ReadPacketError>> {
-        read_raw_packet::<_>(
+        self.reserve_read_buffer();
+        let mut decode_buffers = PacketDecodeBuffers::new();
+        read_raw_packet_into_with_capacity::<_>(
             &mut self.read_stream,
             &mut self.buffer,
             self.compression_threshold,
             &mut self.dec_cipher,
+            &mut decode_buffers,
+            self.read_buffer_capacity,
         )
-        .await
+        .await?;
+        Ok(decode_buffers.take_packet())
     }
 
     pub fn try_read(&mut self) -> Result<Option<Box<[u8]>>, Box<ReadPacketError>> {
-        try_read_raw_packet::<_>(
+        self.reserve_read_buffer();
+        let mut decode_buffers = PacketDecodeBuffers::new();
+        if try_read_raw_packet_into_with_capacity::<_>(
             &mut self.read_stream,
             &mut self.buffer,
             self.compression_threshold,
             &mut self.dec_cipher,
+            &mut decode_buffers,
+            self.read_buffer_capacity,
+        )? {
+            Ok(Some(decode_buffers.take_packet()))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Read a packet into [`Self::decode_buffers`] instead of allocating a new
+    /// buffer for it, and return its bytes.
+    ///
+    /// The decode buffers are created if they don't exist yet.
+    pub async fn read_into_decode_buffers(&mut self) -> Result<&[u8], Box<ReadPacketError>> {
+        self.reserve_read_buffer();
+        let decode_buffers = self
+            .decode_buffers
+            .get_or_insert_with(PacketDecodeBuffers::new);
+        read_raw_packet_into_with_capacity::<_>(
+            &mut self.read_stream,
+            &mut self.buffer,
+            self.compression_threshold,
+            &mut self.dec_cipher,
+            decode_buffers,
+            self.read_buffer_capacity,
         )
+        .await?;
+        Ok(decode_buffers.packet())
+    }
+
+    /// Like [`Self::read_into_decode_buffers`], but returns `Ok(None)` if
+    /// there's no packet to read yet.
+    pub fn try_read_into_decode_buffers(&mut self) -> Result<Option<&[u8]>, Box<ReadPacketError>> {
+        self.reserve_read_buffer();
+        let decode_buffers = self
+            .decode_buffers
+            .get_or_insert_with(PacketDecodeBuffers::new);
+        if try_read_raw_packet_into_with_capacity::<_>(
+            &mut self.read_stream,
+            &mut self.buffer,
+            self.compression_threshold,
+            &mut self.dec_cipher,
+            decode_buffers,
+            self.read_buffer_capacity,
+        )? {
+            Ok(Some(decode_buffers.packet()))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Make sure the buffer has at least [`Self::read_buffer_capacity`] bytes
+    /// of capacity, if it's empty.
+    ///
+    /// The buffer is never shrunk below this capacity after reading a large
+    /// packet, so this only reallocates the first time we read or after the
+    /// capacity is changed.
+    fn reserve_read_buffer(&mut self) {
+        let buffer = self.buffer.get_mut();
+        if buffer.is_empty() && buffer.capacity() < self.read_buffer_capacity {
+            buffer.reserve(self.read_buffer_capacity);
+        }
     }
 }
 
@@ -222,6 +303,10 @@ where
 {
     /// Read a packet from the stream.
     pub async fn read(&mut self) -> Result<R, Box<ReadPacketError>> {
+        if self.raw.decode_buffers.is_some() {
+            let raw_packet = self.raw.read_into_decode_buffers().await?;
+            return deserialize_packet(&mut Cursor::new(raw_packet));
+        }
         let raw_packet = self.raw.read().await?;
         deserialize_packet(&mut Cursor::new(&raw_packet))
     }
@@ -229,6 +314,12 @@ where
     /// Try to read a packet from the stream, or return Ok(None) if there's no
     /// packet.
     pub fn try_read(&mut self) -> Result<Option<R>, Box<ReadPacketError>> {
+        if self.raw.decode_buffers.is_some() {
+            let Some(raw_packet) = self.raw.try_read_into_decode_buffers()? else {
+                return Ok(None);
+            };
+            return Ok(Some(deserialize_packet(&mut Cursor::new(raw_packet))?));
+        }
         let Some(raw_packet) = self.raw.try_read()? else {
             return Ok(None);
         };
@@ -310,6 +401,8 @@ where
 pub enum ConnectionError {
     #[error("{0}")]
     Io(#[from] io::Error),
+    #[error("Timed out after {0:?}")]
+    Timeout(Duration),
 }
 
 use socks5_impl::protocol::UserKey;
@@ -384,6 +477,8 @@ impl Connection<ClientboundHandshakePacket, ServerboundHandshakePacket> {
                 raw: RawReadConnection {
                     read_stream,
                     buffer: Cursor::new(Vec::new()),
+                    read_buffer_capacity: 0,
+                    decode_buffers: None,
                     compression_threshold: None,
                     dec_cipher: None,
                 },
@@ -660,6 +755,34 @@ where
         }
     }
 
+    /// Set the minimum capacity of the buffer that packets are read into.
+    ///
+    /// Setting this to around the size of the largest packets you expect to
+    /// receive (like chunk packets) avoids reallocating the buffer while
+    /// they're being read. The default is 0, which means the buffer only
+    /// grows when it needs to.
+    pub fn set_read_buffer_capacity(&mut self, capacity: usize) {
+        self.reader.raw.read_buffer_capacity = capacity;
+    }
+
+    /// Set whether packets should be decoded into buffers that are reused for
+    /// every packet, instead of allocating new ones each time.
+    ///
+    /// This reduces the number of allocations when receiving lots of large
+    /// packets (like chunks), at the cost of keeping around buffers as large
+    /// as the largest packet that was received. The packets that are read are
+    /// the same either way. This is disabled by default.
+    ///
+    /// See [`PacketDecodeBuffers`].
+    pub fn set_reuse_decode_buffers(&mut self, enabled: bool) {
+        let decode_buffers = &mut self.reader.raw.decode_buffers;
+        if !enabled {
+            *decode_buffers = None;
+        } else if decode_buffers.is_none() {
+            *decode_buffers = Some(PacketDecodeBuffers::new());
+        }
+    }
+
     /// Convert an existing `TcpStream` into a `Connection`. Useful for servers.
     pub fn wrap(stream: TcpStream) -> Connection<R1, W1> {
         let (read_stream, write_stream) = stream.into_split();
@@ -669,6 +792,8 @@ where
                 raw: RawReadConnection {
                     read_stream,
                     buffer: Cursor::new(Vec::new()),
+                    read_buffer_capacity: 0,
+                    decode_buffers: None,
                     compression_threshold: None,
                     dec_cipher: None,
                 },
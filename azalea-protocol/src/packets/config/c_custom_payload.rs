@@ -5,5 +5,6 @@ use azalea_protocol_macros::ClientboundConfigPacket;
 #[derive(AzBuf, ClientboundConfigPacket, Clone, Debug, PartialEq)]
 pub struct ClientboundCustomPayload {
     pub identifier: Identifier,
+    #[limit(1048576)]
     pub data: UnsizedByteArray,
 }
@@ -5,5 +5,6 @@ use azalea_protocol_macros::ServerboundConfigPacket;
 #[derive(AzBuf, Clone, Debug, PartialEq, ServerboundConfigPacket)]
 pub struct ServerboundCustomPayload {
     pub identifier: Identifier,
+    #[limit(32767)]
     pub data: UnsizedByteArray,
 }
@@ -5,5 +5,43 @@ use azalea_protocol_macros::ClientboundGamePacket;
 #[derive(AzBuf, ClientboundGamePacket, Clone, Debug, PartialEq)]
 pub struct ClientboundCustomPayload {
     pub identifier: Identifier,
+    #[limit(1048576)]
     pub data: UnsizedByteArray,
 }
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use azalea_buf::{AzBuf, BufReadError};
+
+    use super::*;
+
+    fn write_payload(len: usize) -> Vec<u8> {
+        let mut buf = Vec::new();
+        ClientboundCustomPayload {
+            identifier: Identifier::new("azalea:test"),
+            data: vec![0; len].into(),
+        }
+        .azalea_write(&mut buf)
+        .unwrap();
+        buf
+    }
+
+    #[test]
+    fn oversized_payload() {
+        let buf = write_payload(1048576);
+        let packet = ClientboundCustomPayload::azalea_read(&mut Cursor::new(&buf)).unwrap();
+        assert_eq!(packet.data.len(), 1048576);
+
+        let buf = write_payload(1048577);
+        let err = ClientboundCustomPayload::azalea_read(&mut Cursor::new(&buf)).unwrap_err();
+        assert!(matches!(
+            err.root_cause(),
+            BufReadError::VecLengthTooLong {
+                length: 1048577,
+                max_length: 1048576
+            }
+        ));
+    }
+}
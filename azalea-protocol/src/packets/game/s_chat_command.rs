@@ -5,3 +5,24 @@ use azalea_protocol_macros::ServerboundGamePacket;
 pub struct ServerboundChatCommand {
     pub command: String,
 }
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use azalea_buf::AzBuf;
+
+    use super::*;
+
+    #[test]
+    fn test_read_long_command() {
+        // commands can be up to 32767 characters long
+        let packet = ServerboundChatCommand {
+            command: "a".repeat(20000),
+        };
+        let mut buf = Vec::new();
+        packet.azalea_write(&mut buf).unwrap();
+        let mut cursor = Cursor::new(&buf[..]);
+        assert_eq!(ServerboundChatCommand::azalea_read(&mut cursor).unwrap(), packet);
+    }
+}
@@ -18,3 +18,38 @@ pub struct ArgumentSignature {
     pub name: String,
     pub signature: MessageSignature,
 }
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use azalea_buf::BufReadError;
+
+    use super::*;
+
+    #[test]
+    fn test_read_truncated_has_context() {
+        let packet = ServerboundChatCommandSigned {
+            command: "msg py5 hi".to_owned(),
+            timestamp: 0,
+            salt: 0,
+            argument_signatures: vec![],
+            last_seen_messages: LastSeenMessagesUpdate::default(),
+        };
+        let mut buf = Vec::new();
+        packet.azalea_write(&mut buf).unwrap();
+        // cut off the checksum at the end
+        buf.pop();
+
+        let err = ServerboundChatCommandSigned::azalea_read(&mut Cursor::new(&buf[..])).unwrap_err();
+        let message = err.to_string();
+        assert!(
+            message.starts_with(
+                "while reading field `last_seen_messages` of `ServerboundChatCommandSigned`: \
+                while reading field `checksum` of `LastSeenMessagesUpdate`: "
+            ),
+            "{message}"
+        );
+        assert!(matches!(err.root_cause(), BufReadError::Io { .. }));
+    }
+}
@@ -7,3 +7,27 @@ pub struct ServerboundCommandSuggestion {
     pub id: u32,
     pub command: String,
 }
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use azalea_buf::AzBuf;
+
+    use super::*;
+
+    #[test]
+    fn test_read_long_command() {
+        let packet = ServerboundCommandSuggestion {
+            id: 1,
+            command: format!("/say {}", "a".repeat(20000)),
+        };
+        let mut buf = Vec::new();
+        packet.azalea_write(&mut buf).unwrap();
+        let mut cursor = Cursor::new(&buf[..]);
+        assert_eq!(
+            ServerboundCommandSuggestion::azalea_read(&mut cursor).unwrap(),
+            packet
+        );
+    }
+}
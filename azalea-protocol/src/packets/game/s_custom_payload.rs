@@ -5,5 +5,6 @@ use azalea_protocol_macros::ServerboundGamePacket;
 #[derive(AzBuf, Clone, Debug, PartialEq, ServerboundGamePacket)]
 pub struct ServerboundCustomPayload {
     pub identifier: Identifier,
+    #[limit(32767)]
     pub data: UnsizedByteArray,
 }
@@ -3,6 +3,7 @@ use std::io::{self, Cursor, Write};
 use azalea_buf::{AzBuf, BufReadError};
 use azalea_chat::FormattedText;
 use azalea_protocol_macros::ClientboundStatusPacket;
+use base64::Engine;
 use serde::{Deserialize, Serialize};
 use serde_json::value::Serializer;
 
@@ -41,6 +42,30 @@ pub struct ClientboundStatusResponse {
     pub enforces_secure_chat: Option<bool>,
 }
 
+/// The typed response to a server list ping, containing the server's version,
+/// player counts, MOTD, and favicon.
+///
+/// This is the same as [`ClientboundStatusResponse`], since the packet is just
+/// the status JSON. It can be deserialized directly from a status JSON string
+/// with `serde_json::from_str`.
+pub type ServerStatus = ClientboundStatusResponse;
+
+impl ClientboundStatusResponse {
+    /// Decode the server's favicon into the bytes of a PNG image.
+    ///
+    /// The favicon is sent as a `data:image/png;base64,...` URL. This returns
+    /// `None` if the server didn't send a favicon or if it's malformed.
+    pub fn favicon_bytes(&self) -> Option<Vec<u8>> {
+        let data = self
+            .favicon
+            .as_deref()?
+            .strip_prefix("data:image/png;base64,")?;
+        // some servers include line breaks in the base64
+        let data = data.replace(['\n', '\r'], "");
+        base64::engine::general_purpose::STANDARD.decode(data).ok()
+    }
+}
+
 impl AzBuf for ClientboundStatusResponse {
     fn azalea_read(buf: &mut Cursor<&[u8]>) -> Result<ClientboundStatusResponse, BufReadError> {
         let status_string = String::azalea_read(buf)?;
@@ -56,3 +81,82 @@ impl AzBuf for ClientboundStatusResponse {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deserialize_server_status() {
+        let json = r#"{
+            "version": { "name": "1.21.4", "protocol": 769 },
+            "players": {
+                "max": 20,
+                "online": 1,
+                "sample": [
+                    { "name": "py5", "id": "e536bfed-8695-48fd-83a1-ecd24cf2a0fd" }
+                ]
+            },
+            "description": {
+                "text": "",
+                "extra": [{ "text": "A Minecraft ", "color": "gold" }, "Server"]
+            },
+            "favicon": "data:image/png;base64,iVBORw0KGgo=",
+            "enforcesSecureChat": true
+        }"#;
+        let status: ServerStatus = serde_json::from_str(json).unwrap();
+
+        assert_eq!(
+            status.version,
+            Version {
+                name: "1.21.4".to_owned(),
+                protocol: 769
+            }
+        );
+        assert_eq!(status.players.max, 20);
+        assert_eq!(status.players.online, 1);
+        assert_eq!(status.players.sample[0].name, "py5");
+        assert_eq!(status.description.to_string(), "A Minecraft Server");
+        assert_eq!(
+            status.favicon.as_deref(),
+            Some("data:image/png;base64,iVBORw0KGgo=")
+        );
+        assert_eq!(status.enforces_secure_chat, Some(true));
+
+        // the description can also just be a string, and the sample and favicon
+        // are optional
+        let json = r#"{
+            "version": { "name": "1.21.4", "protocol": 769 },
+            "players": { "max": 20, "online": 0 },
+            "description": "A Minecraft Server"
+        }"#;
+        let status: ServerStatus = serde_json::from_str(json).unwrap();
+        assert_eq!(status.description.to_string(), "A Minecraft Server");
+        assert!(status.players.sample.is_empty());
+        assert_eq!(status.favicon, None);
+    }
+
+    #[test]
+    fn test_favicon_bytes() {
+        let mut status: ServerStatus = serde_json::from_str(
+            r#"{
+                "version": { "name": "1.21.4", "protocol": 769 },
+                "players": { "max": 20, "online": 0 },
+                "description": "",
+                "favicon": "data:image/png;base64,iVBORw0KGgoAAAANSUhEUgAAAAEAAAABCAYAAAAfFcSJAAAADUlEQVR42mNk+M9QDwADhgGAWjR9awAAAABJRU5ErkJggg=="
+            }"#,
+        )
+        .unwrap();
+        let bytes = status.favicon_bytes().unwrap();
+        // png signature
+        assert_eq!(bytes[..8], [0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A]);
+        assert_eq!(bytes.len(), 70);
+
+        status.favicon = Some("data:image/png;base64,not base64!".to_owned());
+        assert_eq!(status.favicon_bytes(), None);
+        status.favicon = Some("iVBORw0KGgo=".to_owned());
+        assert_eq!(status.favicon_bytes(), None);
+        status.favicon = None;
+        assert_eq!(status.favicon_bytes(), None);
+    }
+}
@@ -3,15 +3,15 @@
 use std::{
     backtrace::Backtrace,
     env,
-    fmt::Debug,
-    io,
-    io::{Cursor, Read},
+    fmt::{self, Debug},
+    io::{self, Cursor},
+    mem,
     sync::LazyLock,
 };
 
 use azalea_buf::{AzBufVar, BufReadError};
 use azalea_crypto::Aes128CfbDec;
-use flate2::read::ZlibDecoder;
+use flate2::{Decompress, FlushDecompress, Status};
 use futures::StreamExt;
 use futures_lite::future;
 use thiserror::Error;
@@ -82,9 +82,19 @@ pub enum FrameSplitterError {
     ConnectionClosed,
 }
 
-/// Read a length, then read that amount of bytes from the `Cursor<Vec<u8>>`. If
-/// there's not enough data, `None` is returned.
-fn parse_frame(buffer: &mut Cursor<Vec<u8>>) -> Result<Box<[u8]>, FrameSplitterError> {
+/// Read a length, then read that amount of bytes from the `Cursor<Vec<u8>>`
+/// into `out`, replacing its previous contents. If there's not enough data,
+/// an error is returned and `out` is left empty.
+///
+/// Once the buffer is fully read, its capacity is capped to
+/// `read_buffer_capacity` or 64KB, whichever is larger.
+fn parse_frame_into(
+    buffer: &mut Cursor<Vec<u8>>,
+    out: &mut Vec<u8>,
+    read_buffer_capacity: usize,
+) -> Result<(), FrameSplitterError> {
+    out.clear();
+
     // copy the buffer first and read from the copy, then once we make sure
     // the packet is all good we read it fully
     let mut buffer_copy = Cursor::new(&buffer.get_ref()[buffer.position() as usize..]);
@@ -111,8 +121,10 @@ fn parse_frame(buffer: &mut Cursor<Vec<u8>>) -> Result<Box<[u8]>, FrameSplitterE
     let varint_length = buffer.remaining() - buffer_copy.remaining();
 
     buffer.advance(varint_length);
-    let data =
-        buffer.get_ref()[buffer.position() as usize..buffer.position() as usize + length].to_vec();
+    out.reserve_exact(length);
+    out.extend_from_slice(
+        &buffer.get_ref()[buffer.position() as usize..buffer.position() as usize + length],
+    );
     buffer.advance(length);
 
     if buffer.position() == buffer.get_ref().len() as u64 {
@@ -124,19 +136,28 @@ fn parse_frame(buffer: &mut Cursor<Vec<u8>>) -> Result<Box<[u8]>, FrameSplitterE
         // allocations.
         // and the reason we bother capping it at all is to avoid wasting memory if we
         // get a big packet once and then never again.
-        buffer.get_mut().shrink_to(1024 * 64);
+        buffer
+            .get_mut()
+            .shrink_to(read_buffer_capacity.max(1024 * 64));
 
         buffer.set_position(0);
     }
 
-    Ok(data.into_boxed_slice())
+    Ok(())
 }
 
-fn frame_splitter(buffer: &mut Cursor<Vec<u8>>) -> Result<Option<Box<[u8]>>, FrameSplitterError> {
+/// Split a frame off the start of the buffer and write it into `out`.
+///
+/// Returns whether a full frame was read.
+fn frame_splitter(
+    buffer: &mut Cursor<Vec<u8>>,
+    out: &mut Vec<u8>,
+    read_buffer_capacity: usize,
+) -> Result<bool, FrameSplitterError> {
     // https://tokio.rs/tokio/tutorial/framing
-    let read_frame = parse_frame(buffer);
+    let read_frame = parse_frame_into(buffer, out, read_buffer_capacity);
     match read_frame {
-        Ok(frame) => return Ok(Some(frame)),
+        Ok(()) => return Ok(true),
         Err(err) => match err {
             FrameSplitterError::BadLength { .. } | FrameSplitterError::Io { .. } => {
                 // we probably just haven't read enough yet
@@ -145,7 +166,7 @@ fn frame_splitter(buffer: &mut Cursor<Vec<u8>>) -> Result<Option<Box<[u8]>>, Fra
         },
     }
 
-    Ok(None)
+    Ok(false)
 }
 
 pub fn deserialize_packet<P: ProtocolPacket + Debug>(
@@ -191,15 +212,33 @@ pub fn compression_decoder(
     stream: &mut Cursor<&[u8]>,
     compression_threshold: u32,
 ) -> Result<Box<[u8]>, DecompressionError> {
+    let mut decoded_buf = Vec::new();
+    compression_decoder_into(stream, compression_threshold, &mut decoded_buf, &mut None)?;
+    Ok(decoded_buf.into_boxed_slice())
+}
+
+/// Like [`compression_decoder`], but writes the decompressed bytes into `out`
+/// (replacing its previous contents) and reuses the given zlib state.
+///
+/// The zlib state is only created once we get a packet that's actually
+/// compressed.
+fn compression_decoder_into(
+    stream: &mut Cursor<&[u8]>,
+    compression_threshold: u32,
+    out: &mut Vec<u8>,
+    decompress: &mut Option<Decompress>,
+) -> Result<(), DecompressionError> {
+    out.clear();
+
     // Data Length
     let n = u32::azalea_read_var(stream)?;
+    let input = &stream.get_ref()[stream.position() as usize..];
+    stream.set_position(stream.get_ref().len() as u64);
     if n == 0 {
         // no data size, no compression
-        let buf = stream.get_ref()[stream.position() as usize..]
-            .to_vec()
-            .into_boxed_slice();
-        stream.set_position(stream.get_ref().len() as u64);
-        return Ok(buf);
+        out.reserve_exact(input.len());
+        out.extend_from_slice(input);
+        return Ok(());
     }
 
     if VALIDATE_DECOMPRESSED {
@@ -219,12 +258,94 @@ pub fn compression_decoder(
 
     // VALIDATE_DECOMPRESSED should always be true, so the max they can make us
     // allocate here is 2mb
-    let mut decoded_buf = Vec::with_capacity(n as usize);
+    out.reserve_exact(n as usize);
 
-    let mut decoder = ZlibDecoder::new(stream);
-    decoder.read_to_end(&mut decoded_buf)?;
+    let decompress = match decompress {
+        Some(decompress) => {
+            decompress.reset(true);
+            decompress
+        }
+        None => decompress.insert(Decompress::new(true)),
+    };
+    loop {
+        let (total_in, total_out) = (decompress.total_in(), decompress.total_out());
+        let status = decompress
+            .decompress_vec(&input[total_in as usize..], out, FlushDecompress::Finish)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        if status == Status::StreamEnd {
+            return Ok(());
+        }
+        if out.len() == out.capacity() {
+            // the packet lied about its length, so keep going like read_to_end would
+            out.reserve(32);
+        } else if decompress.total_in() == total_in && decompress.total_out() == total_out {
+            // the stream was cut off
+            return Err(io::Error::from(io::ErrorKind::UnexpectedEof).into());
+        }
+    }
+}
 
-    Ok(decoded_buf.into_boxed_slice())
+/// Reusable buffers for decoding packets.
+///
+/// Normally, every packet that's read allocates a new buffer for its frame
+/// and another one for its decompressed data, and every compressed packet
+/// creates a new zlib decoder.
+/// Reading with these buffers instead reuses the same allocations for every
+/// packet, which helps when receiving lots of large packets like chunks.
+///
+/// The buffers keep the capacity of the largest packet that was read with
+/// them.
+///
+/// See [`read_raw_packet_into`] and
+/// [`Connection::set_reuse_decode_buffers`](crate::connect::Connection::set_reuse_decode_buffers).
+pub struct PacketDecodeBuffers {
+    frame: Vec<u8>,
+    decompressed: Vec<u8>,
+    decompress: Option<Decompress>,
+    is_decompressed: bool,
+}
+impl PacketDecodeBuffers {
+    /// Create empty buffers. Nothing is allocated until a packet is read into
+    /// them.
+    pub fn new() -> Self {
+        Self {
+            frame: Vec::new(),
+            decompressed: Vec::new(),
+            decompress: None,
+            is_decompressed: false,
+        }
+    }
+
+    /// The bytes of the last packet that was read into these buffers.
+    pub fn packet(&self) -> &[u8] {
+        if self.is_decompressed {
+            &self.decompressed
+        } else {
+            &self.frame
+        }
+    }
+
+    /// Take the bytes of the last packet, leaving the buffers empty.
+    pub(crate) fn take_packet(&mut self) -> Box<[u8]> {
+        if self.is_decompressed {
+            mem::take(&mut self.decompressed).into_boxed_slice()
+        } else {
+            mem::take(&mut self.frame).into_boxed_slice()
+        }
+    }
+}
+impl Default for PacketDecodeBuffers {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl Debug for PacketDecodeBuffers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PacketDecodeBuffers")
+            .field("frame_capacity", &self.frame.capacity())
+            .field("decompressed_capacity", &self.decompressed.capacity())
+            .finish()
+    }
 }
 
 /// Read a single packet from a stream.
@@ -276,13 +397,71 @@ pub async fn read_raw_packet<R>(
     // otherwise the borrow checker complains about the cipher being moved
     cipher: &mut Option<Aes128CfbDec>,
 ) -> Result<Box<[u8]>, Box<ReadPacketError>>
+where
+    R: AsyncRead + Unpin + Send + Sync,
+{
+    let mut decode_buffers = PacketDecodeBuffers::new();
+    read_raw_packet_into(
+        stream,
+        buffer,
+        compression_threshold,
+        cipher,
+        &mut decode_buffers,
+    )
+    .await?;
+    Ok(decode_buffers.take_packet())
+}
+
+/// Like [`read_raw_packet`], but the packet is read into the given reusable
+/// buffers instead of being allocated.
+///
+/// The packet's bytes can be accessed with [`PacketDecodeBuffers::packet`].
+pub async fn read_raw_packet_into<R>(
+    stream: &mut R,
+    buffer: &mut Cursor<Vec<u8>>,
+    compression_threshold: Option<u32>,
+    cipher: &mut Option<Aes128CfbDec>,
+    decode_buffers: &mut PacketDecodeBuffers,
+) -> Result<(), Box<ReadPacketError>>
+where
+    R: AsyncRead + Unpin + Send + Sync,
+{
+    read_raw_packet_into_with_capacity(
+        stream,
+        buffer,
+        compression_threshold,
+        cipher,
+        decode_buffers,
+        0,
+    )
+    .await
+}
+
+/// Like [`read_raw_packet_into`], but the buffer's capacity is never shrunk
+/// below `read_buffer_capacity`.
+///
+/// See
+/// [`Connection::set_read_buffer_capacity`](crate::connect::Connection::set_read_buffer_capacity).
+pub(crate) async fn read_raw_packet_into_with_capacity<R>(
+    stream: &mut R,
+    buffer: &mut Cursor<Vec<u8>>,
+    compression_threshold: Option<u32>,
+    cipher: &mut Option<Aes128CfbDec>,
+    decode_buffers: &mut PacketDecodeBuffers,
+    read_buffer_capacity: usize,
+) -> Result<(), Box<ReadPacketError>>
 where
     R: AsyncRead + Unpin + Send + Sync,
 {
     loop {
-        if let Some(buf) = read_raw_packet_from_buffer::<R>(buffer, compression_threshold)? {
+        if read_from_buffer_into(
+            buffer,
+            compression_threshold,
+            decode_buffers,
+            read_buffer_capacity,
+        )? {
             // we got a full packet!!
-            return Ok(buf);
+            return Ok(());
         };
 
         let bytes = read_and_decrypt_frame(stream, cipher).await?;
@@ -291,23 +470,84 @@ where
 }
 /// Read a packet from the stream, then if necessary decrypt it, decompress
 /// it, and split it.
+///
+/// If the cipher is `None`, then the data is assumed to not be encrypted. See
+/// [`encode_to_network_packet`](crate::write::encode_to_network_packet) for
+/// the other direction.
 pub fn try_read_raw_packet<R>(
     stream: &mut R,
     buffer: &mut Cursor<Vec<u8>>,
     compression_threshold: Option<u32>,
     cipher: &mut Option<Aes128CfbDec>,
 ) -> Result<Option<Box<[u8]>>, Box<ReadPacketError>>
+where
+    R: AsyncRead + Unpin + Send + Sync,
+{
+    let mut decode_buffers = PacketDecodeBuffers::new();
+    if try_read_raw_packet_into(
+        stream,
+        buffer,
+        compression_threshold,
+        cipher,
+        &mut decode_buffers,
+    )? {
+        Ok(Some(decode_buffers.take_packet()))
+    } else {
+        Ok(None)
+    }
+}
+
+/// Like [`try_read_raw_packet`], but the packet is read into the given
+/// reusable buffers instead of being allocated.
+///
+/// Returns whether a packet was read. The packet's bytes can be accessed with
+/// [`PacketDecodeBuffers::packet`].
+pub fn try_read_raw_packet_into<R>(
+    stream: &mut R,
+    buffer: &mut Cursor<Vec<u8>>,
+    compression_threshold: Option<u32>,
+    cipher: &mut Option<Aes128CfbDec>,
+    decode_buffers: &mut PacketDecodeBuffers,
+) -> Result<bool, Box<ReadPacketError>>
+where
+    R: AsyncRead + Unpin + Send + Sync,
+{
+    try_read_raw_packet_into_with_capacity(
+        stream,
+        buffer,
+        compression_threshold,
+        cipher,
+        decode_buffers,
+        0,
+    )
+}
+
+/// Like [`try_read_raw_packet_into`], but the buffer's capacity is never
+/// shrunk below `read_buffer_capacity`.
+pub(crate) fn try_read_raw_packet_into_with_capacity<R>(
+    stream: &mut R,
+    buffer: &mut Cursor<Vec<u8>>,
+    compression_threshold: Option<u32>,
+    cipher: &mut Option<Aes128CfbDec>,
+    decode_buffers: &mut PacketDecodeBuffers,
+    read_buffer_capacity: usize,
+) -> Result<bool, Box<ReadPacketError>>
 where
     R: AsyncRead + Unpin + Send + Sync,
 {
     loop {
-        if let Some(buf) = read_raw_packet_from_buffer::<R>(buffer, compression_threshold)? {
+        if read_from_buffer_into(
+            buffer,
+            compression_threshold,
+            decode_buffers,
+            read_buffer_capacity,
+        )? {
             // we got a full packet!!
-            return Ok(Some(buf));
+            return Ok(true);
         };
         let Some(bytes) = try_read_and_decrypt_frame(stream, cipher)? else {
             // no data received
-            return Ok(None);
+            return Ok(false);
         };
         // we got some data, so add it to the buffer and try again
         buffer.get_mut().extend_from_slice(&bytes);
@@ -371,14 +611,49 @@ pub fn read_raw_packet_from_buffer<R>(
 where
     R: AsyncRead + Unpin + Send + Sync,
 {
-    let Some(mut buf) = frame_splitter(buffer).map_err(ReadPacketError::from)? else {
+    let mut decode_buffers = PacketDecodeBuffers::new();
+    if read_raw_packet_from_buffer_into(buffer, compression_threshold, &mut decode_buffers)? {
+        Ok(Some(decode_buffers.take_packet()))
+    } else {
+        Ok(None)
+    }
+}
+
+/// Split a packet off the start of the buffer and decompress it into the
+/// reusable buffers.
+///
+/// Returns whether a full packet was read.
+pub fn read_raw_packet_from_buffer_into(
+    buffer: &mut Cursor<Vec<u8>>,
+    compression_threshold: Option<u32>,
+    decode_buffers: &mut PacketDecodeBuffers,
+) -> Result<bool, Box<ReadPacketError>> {
+    read_from_buffer_into(buffer, compression_threshold, decode_buffers, 0)
+}
+
+fn read_from_buffer_into(
+    buffer: &mut Cursor<Vec<u8>>,
+    compression_threshold: Option<u32>,
+    decode_buffers: &mut PacketDecodeBuffers,
+    read_buffer_capacity: usize,
+) -> Result<bool, Box<ReadPacketError>> {
+    decode_buffers.is_decompressed = false;
+    if !frame_splitter(buffer, &mut decode_buffers.frame, read_buffer_capacity)
+        .map_err(ReadPacketError::from)?
+    {
         // no full packet yet :(
-        return Ok(None);
+        return Ok(false);
     };
 
     if let Some(compression_threshold) = compression_threshold {
-        buf = compression_decoder(&mut Cursor::new(&buf[..]), compression_threshold)
-            .map_err(ReadPacketError::from)?;
+        compression_decoder_into(
+            &mut Cursor::new(&decode_buffers.frame[..]),
+            compression_threshold,
+            &mut decode_buffers.decompressed,
+            &mut decode_buffers.decompress,
+        )
+        .map_err(ReadPacketError::from)?;
+        decode_buffers.is_decompressed = true;
     }
 
     if tracing::enabled!(tracing::Level::TRACE) {
@@ -388,6 +663,7 @@ where
                 .unwrap_or(false)
         });
 
+        let buf = decode_buffers.packet();
         let buf_string: String = {
             if !*DO_NOT_CUT_OFF_PACKET_LOGS && buf.len() > 500 {
                 let cut_off_buf = &buf[..500];
@@ -399,7 +675,7 @@ where
         trace!("Reading packet with bytes: {buf_string}");
     };
 
-    Ok(Some(buf))
+    Ok(true)
 }
 
 #[cfg(test)]
@@ -408,7 +684,8 @@ mod tests {
 
     use azalea_buf::AzBuf as _;
 
-    use crate::{packets::game::ClientboundGamePacket, read::deserialize_packet};
+    use super::*;
+    use crate::{packets::game::ClientboundGamePacket, write::compression_encoder};
 
     #[test]
     fn fuzzed_1() {
@@ -454,4 +731,156 @@ mod tests {
         // memory leak in simdnbt
         let _ = simdnbt::owned::Nbt::azalea_read(&mut Cursor::new([10, 10, 0, 0, 0].as_slice()));
     }
+
+    #[test]
+    fn null_cipher_passes_data_through() {
+        use crate::write::encode_to_network_packet;
+
+        let raw_packet = (0..=255).collect::<Vec<u8>>();
+
+        // without a cipher, only the length prefix is added
+        let network_packet = encode_to_network_packet(&raw_packet, None, &mut None);
+        assert_eq!(network_packet[..2], [0x80, 0x02]);
+        assert_eq!(network_packet[2..], raw_packet);
+
+        // and it can be read back without a cipher too
+        for compression_threshold in [None, Some(64)] {
+            let network_packet =
+                encode_to_network_packet(&raw_packet, compression_threshold, &mut None);
+            let read = super::try_read_raw_packet(
+                &mut network_packet.as_slice(),
+                &mut Cursor::new(Vec::new()),
+                compression_threshold,
+                &mut None,
+            )
+            .unwrap()
+            .unwrap();
+            assert_eq!(*read, *raw_packet);
+        }
+
+        // while a real cipher changes the bytes
+        let (enc_cipher, _) = azalea_crypto::create_cipher(&[1; 16]);
+        let encrypted_packet = encode_to_network_packet(&raw_packet, None, &mut Some(enc_cipher));
+        assert_ne!(encrypted_packet, network_packet);
+    }
+
+    #[test]
+    fn reused_decompression_matches() {
+        let mut decode_buffers = PacketDecodeBuffers::new();
+        let packets: [Vec<u8>; 3] = [vec![7; 2000], (0..=255).collect(), vec![1, 2, 3]];
+        for packet in packets {
+            let compressed = compression_encoder(&packet, 256).unwrap();
+
+            let decompressed = compression_decoder(&mut Cursor::new(&compressed), 256).unwrap();
+            assert_eq!(*decompressed, packet);
+
+            compression_decoder_into(
+                &mut Cursor::new(&compressed),
+                256,
+                &mut decode_buffers.decompressed,
+                &mut decode_buffers.decompress,
+            )
+            .unwrap();
+            assert_eq!(decode_buffers.decompressed, packet);
+        }
+    }
+
+    #[tokio::test]
+    async fn reuse_decode_buffers() {
+        use crate::write::encode_to_network_packet;
+
+        let large_packet = vec![7; 2000];
+        let small_packet = vec![1, 2, 3];
+
+        let mut stream = Vec::new();
+        for packet in [&large_packet, &small_packet, &large_packet, &small_packet] {
+            stream.extend(encode_to_network_packet(packet, Some(256), &mut None));
+        }
+        let mut stream = stream.as_slice();
+
+        let mut buffer = Cursor::new(Vec::new());
+        let mut decode_buffers = PacketDecodeBuffers::new();
+
+        // the zlib state isn't created until we get a compressed packet
+        read_raw_packet_into(
+            &mut &*encode_to_network_packet(&small_packet, Some(256), &mut None),
+            &mut buffer,
+            Some(256),
+            &mut None,
+            &mut decode_buffers,
+        )
+        .await
+        .unwrap();
+        assert_eq!(decode_buffers.packet(), small_packet);
+        assert!(decode_buffers.decompress.is_none());
+
+        for packet in [&large_packet, &small_packet, &large_packet] {
+            read_raw_packet_into(
+                &mut stream,
+                &mut buffer,
+                Some(256),
+                &mut None,
+                &mut decode_buffers,
+            )
+            .await
+            .unwrap();
+            assert_eq!(decode_buffers.packet(), *packet);
+        }
+        assert!(decode_buffers.decompress.is_some());
+        assert!(decode_buffers.decompressed.capacity() >= large_packet.len());
+
+        assert!(
+            try_read_raw_packet_into(
+                &mut stream,
+                &mut buffer,
+                Some(256),
+                &mut None,
+                &mut decode_buffers,
+            )
+            .unwrap()
+        );
+        assert_eq!(decode_buffers.packet(), small_packet);
+    }
+
+    #[tokio::test]
+    async fn read_buffer_capacity() {
+        use crate::write::encode_to_network_packet;
+
+        let large_packet = vec![7; 100 * 1024];
+        let network_packet = encode_to_network_packet(&large_packet, None, &mut None);
+
+        // by default, the buffer is shrunk back down to 64KB after a large packet
+        let mut buffer = Cursor::new(Vec::new());
+        let read = read_raw_packet(&mut network_packet.as_slice(), &mut buffer, None, &mut None)
+            .await
+            .unwrap();
+        assert_eq!(*read, *large_packet);
+        assert!(buffer.get_ref().capacity() <= 64 * 1024);
+
+        // but not below the configured capacity
+        let mut buffer = Cursor::new(Vec::with_capacity(256 * 1024));
+        let mut decode_buffers = PacketDecodeBuffers::new();
+        for _ in 0..2 {
+            read_raw_packet_into_with_capacity(
+                &mut network_packet.as_slice(),
+                &mut buffer,
+                None,
+                &mut None,
+                &mut decode_buffers,
+                256 * 1024,
+            )
+            .await
+            .unwrap();
+            assert_eq!(decode_buffers.packet(), large_packet);
+            assert!(buffer.get_ref().is_empty());
+            assert!(buffer.get_ref().capacity() >= 256 * 1024);
+        }
+    }
+
+    #[test]
+    fn truncated_compressed_packet() {
+        let compressed = compression_encoder(&[7; 2000], 256).unwrap();
+        let truncated = &compressed[..compressed.len() - 4];
+        assert!(compression_decoder(&mut Cursor::new(truncated), 256).is_err());
+    }
 }
@@ -33,8 +33,21 @@ pub fn serialize_packet<P: ProtocolPacket + Debug>(
     packet: &P,
 ) -> Result<Box<[u8]>, PacketEncodeError> {
     let mut buf = Vec::new();
-    packet.id().azalea_write_var(&mut buf)?;
-    packet.write(&mut buf)?;
+    serialize_packet_into(packet, &mut buf)?;
+    Ok(buf.into_boxed_slice())
+}
+
+/// Like [`serialize_packet`], but writes into an existing buffer so its
+/// allocation can be reused.
+///
+/// The buffer is cleared before the packet is written to it.
+pub fn serialize_packet_into<P: ProtocolPacket + Debug>(
+    packet: &P,
+    buf: &mut Vec<u8>,
+) -> Result<(), PacketEncodeError> {
+    buf.clear();
+    packet.id().azalea_write_var(buf)?;
+    packet.write(buf)?;
     if buf.len() > MAXIMUM_UNCOMPRESSED_LENGTH as usize {
         return Err(PacketEncodeError::TooBig {
             actual: buf.len(),
@@ -42,7 +55,7 @@ pub fn serialize_packet<P: ProtocolPacket + Debug>(
             packet_string: format!("{packet:?}"),
         });
     }
-    Ok(buf.into_boxed_slice())
+    Ok(())
 }
 
 pub async fn write_raw_packet<W>(
@@ -78,6 +91,14 @@ where
     stream.write_all(&buffer).await
 }
 
+/// Compress, frame, and encrypt a serialized packet so it's ready to be sent
+/// over the network.
+///
+/// If the cipher is `None`, then the packet isn't encrypted and the bytes are
+/// left unchanged after framing. This is the case before encryption is
+/// enabled during login, and for the entire connection on offline-mode
+/// servers, so passing `&mut None` is also useful for testing the pipeline
+/// without a key exchange.
 pub fn encode_to_network_packet(
     raw_packet: &[u8],
     compression_threshold: Option<u32>,
@@ -65,6 +65,79 @@ impl Identifier {
             &self.inner
         }
     }
+
+    /// Iterate over the `/`-separated segments of the path.
+    ///
+    /// For example, `minecraft:recipes/building/oak_planks` has the segments
+    /// `recipes`, `building`, and `oak_planks`.
+    pub fn path_segments(&self) -> impl Iterator<Item = &str> {
+        self.path().split('/')
+    }
+
+    /// Returns a new identifier with the last segment of the path removed, or
+    /// `None` if the path only has one segment.
+    ///
+    /// For example, the parent of `minecraft:recipes/building/oak_planks` is
+    /// `minecraft:recipes/building`.
+    pub fn parent(&self) -> Option<Identifier> {
+        let (parent_path, _) = self.path().rsplit_once('/')?;
+        Some(Identifier::new(format!(
+            "{}:{parent_path}",
+            self.namespace()
+        )))
+    }
+
+    /// Returns a new identifier with the same namespace but a different path.
+    ///
+    /// For example, `minecraft:stone` with the path `stone_slab` becomes
+    /// `minecraft:stone_slab`.
+    pub fn with_path(&self, path: &str) -> Identifier {
+        Identifier::new(format!("{}:{path}", self.namespace()))
+    }
+
+    /// Returns a new identifier with the same path but a different namespace.
+    pub fn with_namespace(&self, namespace: &str) -> Identifier {
+        Identifier::new(format!("{namespace}:{}", self.path()))
+    }
+
+    /// Returns a new identifier with `suffix` appended to the path, without
+    /// adding any separator.
+    ///
+    /// For example, `minecraft:stone` joined with `_slab` becomes
+    /// `minecraft:stone_slab`.
+    pub fn join(&self, suffix: &str) -> Identifier {
+        Identifier::new(format!("{self}{suffix}"))
+    }
+
+    /// Whether the namespace and path only contain the characters that
+    /// Minecraft allows in identifiers.
+    ///
+    /// Namespaces may contain `a-z`, `0-9`, `_`, `-`, and `.`, and paths may
+    /// additionally contain `/`.
+    pub fn is_valid(&self) -> bool {
+        fn is_valid_namespace_char(c: char) -> bool {
+            matches!(c, 'a'..='z' | '0'..='9' | '_' | '-' | '.')
+        }
+        self.namespace().chars().all(is_valid_namespace_char)
+            && self
+                .path()
+                .chars()
+                .all(|c| c == '/' || is_valid_namespace_char(c))
+    }
+
+    /// Whether this identifier is equal to the identifier that `s` would be
+    /// parsed as, without allocating.
+    ///
+    /// Like [`Identifier::new`], a missing namespace means `minecraft`, so
+    /// `minecraft:stone` is equal to both `"stone"` and `"minecraft:stone"`.
+    pub fn eq_str(&self, s: &str) -> bool {
+        let (namespace, path) = match s.find(':') {
+            Some(0) => (DEFAULT_NAMESPACE, &s[1..]),
+            Some(colon_index) => (&s[..colon_index], &s[(colon_index + 1)..]),
+            None => (DEFAULT_NAMESPACE, s),
+        };
+        self.namespace() == namespace && self.path() == path
+    }
 }
 impl PartialEq for Identifier {
     fn eq(&self, other: &Self) -> bool {
@@ -187,6 +260,69 @@ mod tests {
         assert_eq!(r.path(), "");
     }
 
+    #[test]
+    fn path_segments() {
+        let r = Identifier::new("minecraft:recipes/building/oak_planks");
+        assert_eq!(
+            r.path_segments().collect::<Vec<_>>(),
+            ["recipes", "building", "oak_planks"]
+        );
+
+        let parent = r.parent().unwrap();
+        assert_eq!(parent, Identifier::new("minecraft:recipes/building"));
+        let grandparent = parent.parent().unwrap();
+        assert_eq!(grandparent, Identifier::new("recipes"));
+        assert_eq!(grandparent.parent(), None);
+
+        let r = Identifier::new("azalea:a/b");
+        assert_eq!(r.parent(), Some(Identifier::new("azalea:a")));
+    }
+
+    #[test]
+    fn with_path_and_namespace() {
+        let r = Identifier::new("azalea:stone");
+        assert_eq!(
+            r.with_path("stone_slab"),
+            Identifier::new("azalea:stone_slab")
+        );
+        assert_eq!(r.with_namespace("other"), Identifier::new("other:stone"));
+        assert_eq!(r.join("_slab"), Identifier::new("azalea:stone_slab"));
+
+        let r = Identifier::new("stone");
+        assert_eq!(r.with_path("dirt").namespace(), "minecraft");
+        assert_eq!(r.join("/top").path(), "stone/top");
+        assert_eq!(r.with_namespace("azalea").to_string(), "azalea:stone");
+        // the original is left unchanged
+        assert_eq!(r, Identifier::new("minecraft:stone"));
+    }
+
+    #[test]
+    fn is_valid() {
+        assert!(Identifier::new("stone").is_valid());
+        assert!(Identifier::new("bungeecord:main").is_valid());
+        assert!(Identifier::new("my-mod_1.0:a/b.c").is_valid());
+        assert!(!Identifier::new("BungeeCord").is_valid());
+        assert!(!Identifier::new("my/mod:channel").is_valid());
+        assert!(!Identifier::new("azalea:a b").is_valid());
+        assert!(!Identifier::new("azalea:a:b").is_valid());
+    }
+
+    #[test]
+    fn eq_str() {
+        let r = Identifier::new("minecraft:stone");
+        assert!(r.eq_str("stone"));
+        assert!(r.eq_str("minecraft:stone"));
+        assert!(r.eq_str(":stone"));
+        assert!(!r.eq_str("azalea:stone"));
+        assert!(!r.eq_str("stone_slab"));
+
+        let r = Identifier::new("azalea:a:b");
+        assert!(r.eq_str("azalea:a:b"));
+        assert!(!r.eq_str("a:b"));
+        assert!(Identifier::new("azalea:").eq_str("azalea:"));
+        assert!(!Identifier::new("azalea:").eq_str("azalea"));
+    }
+
     #[test]
     fn azbuf_identifier() {
         let mut buf = Vec::new();
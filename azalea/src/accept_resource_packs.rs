@@ -19,12 +19,15 @@ use bevy_ecs::prelude::*;
 
 use crate::app::{App, Plugin};
 
-/// A plugin that makes it so bots automatically accept resource packs.
+/// A plugin that makes it so bots automatically respond to resource packs,
+/// based on the [`ResourcePackPolicy`] resource.
+///
+/// By default, all resource packs are accepted.
 #[derive(Clone, Default)]
 pub struct AcceptResourcePacksPlugin;
 impl Plugin for AcceptResourcePacksPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(
+        app.init_resource::<ResourcePackPolicy>().add_systems(
             Update,
             accept_resource_pack
                 .before(perform_respawn)
@@ -36,46 +39,155 @@ impl Plugin for AcceptResourcePacksPlugin {
     }
 }
 
+/// A resource that decides how clients respond when the server asks them to
+/// download a resource pack.
+///
+/// A [`ResourcePackEvent`] is sent regardless of the policy.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Resource)]
+pub enum ResourcePackPolicy {
+    /// Pretend to accept and successfully load every resource pack.
+    #[default]
+    AlwaysAccept,
+    /// Decline every resource pack. Servers may kick us if the resource pack
+    /// was required.
+    AlwaysDecline,
+    /// Accept resource packs that the server says are required, and decline
+    /// the rest.
+    AcceptRequiredOnly,
+    /// Don't respond automatically. You'll have to send a
+    /// `ServerboundResourcePack` with the ID from the [`ResourcePackEvent`]
+    /// yourself.
+    Manual,
+}
+impl ResourcePackPolicy {
+    /// Whether we should accept the resource pack, or `None` if we shouldn't
+    /// respond at all.
+    pub fn should_accept(&self, required: bool) -> Option<bool> {
+        match self {
+            Self::AlwaysAccept => Some(true),
+            Self::AlwaysDecline => Some(false),
+            Self::AcceptRequiredOnly => Some(required),
+            Self::Manual => None,
+        }
+    }
+}
+
 fn accept_resource_pack(
     mut events: MessageReader<ResourcePackEvent>,
     mut commands: Commands,
     query_in_config_state: Query<Option<&InConfigState>>,
+    policy: Res<ResourcePackPolicy>,
 ) {
     for event in events.read() {
         let Ok(in_config_state_option) = query_in_config_state.get(event.entity) else {
             continue;
         };
+        let Some(accept) = policy.should_accept(event.required) else {
+            continue;
+        };
 
         if in_config_state_option.is_some() {
-            commands.trigger(SendConfigPacketEvent::new(
-                event.entity,
-                config::ServerboundResourcePack {
-                    id: event.id,
-                    action: config::s_resource_pack::Action::Accepted,
-                },
-            ));
-            commands.trigger(SendConfigPacketEvent::new(
-                event.entity,
-                config::ServerboundResourcePack {
-                    id: event.id,
-                    action: config::s_resource_pack::Action::SuccessfullyLoaded,
-                },
-            ));
+            let actions: &[_] = if accept {
+                &[
+                    config::s_resource_pack::Action::Accepted,
+                    config::s_resource_pack::Action::SuccessfullyLoaded,
+                ]
+            } else {
+                &[config::s_resource_pack::Action::Declined]
+            };
+            for &action in actions {
+                commands.trigger(SendConfigPacketEvent::new(
+                    event.entity,
+                    config::ServerboundResourcePack {
+                        id: event.id,
+                        action,
+                    },
+                ));
+            }
         } else {
-            commands.trigger(SendGamePacketEvent::new(
-                event.entity,
-                ServerboundResourcePack {
-                    id: event.id,
-                    action: s_resource_pack::Action::Accepted,
-                },
-            ));
-            commands.trigger(SendGamePacketEvent::new(
-                event.entity,
-                ServerboundResourcePack {
-                    id: event.id,
-                    action: s_resource_pack::Action::SuccessfullyLoaded,
-                },
-            ));
+            let actions: &[_] = if accept {
+                &[
+                    s_resource_pack::Action::Accepted,
+                    s_resource_pack::Action::SuccessfullyLoaded,
+                ]
+            } else {
+                &[s_resource_pack::Action::Declined]
+            };
+            for &action in actions {
+                commands.trigger(SendGamePacketEvent::new(
+                    event.entity,
+                    ServerboundResourcePack {
+                        id: event.id,
+                        action,
+                    },
+                ));
+            }
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use azalea_client::test_utils::prelude::*;
+    use azalea_protocol::packets::{
+        ConnectionProtocol,
+        game::{ClientboundResourcePackPush, ServerboundGamePacket},
+    };
+    use uuid::Uuid;
+
+    use super::*;
+
+    fn sent_actions(policy: ResourcePackPolicy, required: bool) -> Vec<s_resource_pack::Action> {
+        let _lock = init();
+
+        let mut simulation = Simulation::new(ConnectionProtocol::Game);
+        simulation.app.add_plugins(AcceptResourcePacksPlugin);
+        simulation.app.insert_resource(policy);
+        simulation.receive_packet(default_login_packet());
+        simulation.tick();
+
+        let sent_packets = SentPackets::new(&mut simulation);
+        let id = Uuid::from_u128(1);
+        simulation.receive_packet(ClientboundResourcePackPush {
+            id,
+            url: "https://example.com/pack.zip".to_owned(),
+            hash: "".to_owned(),
+            required,
+            prompt: None,
+        });
+        simulation.tick();
+
+        let mut actions = Vec::new();
+        while let Some(packet) = sent_packets.next() {
+            if let ServerboundGamePacket::ResourcePack(p) = packet {
+                assert_eq!(p.id, id);
+                actions.push(p.action);
+            }
+        }
+        actions
+    }
+
+    #[test]
+    fn test_resource_pack_policies() {
+        use s_resource_pack::Action::*;
+
+        let accepted = vec![Accepted, SuccessfullyLoaded];
+        assert_eq!(
+            sent_actions(ResourcePackPolicy::AlwaysAccept, false),
+            accepted
+        );
+        assert_eq!(
+            sent_actions(ResourcePackPolicy::AlwaysDecline, true),
+            vec![Declined]
+        );
+        assert_eq!(
+            sent_actions(ResourcePackPolicy::AcceptRequiredOnly, true),
+            accepted
+        );
+        assert_eq!(
+            sent_actions(ResourcePackPolicy::AcceptRequiredOnly, false),
+            vec![Declined]
+        );
+        assert_eq!(sent_actions(ResourcePackPolicy::Manual, true), vec![]);
+    }
+}
@@ -5,9 +5,15 @@ use azalea_client::{
 use bevy_app::Update;
 use bevy_ecs::prelude::*;
 
-use crate::app::{App, Plugin};
+use crate::{
+    Client,
+    app::{App, Plugin},
+};
 
 /// A plugin that makes [`DeathEvent`]s send [`PerformRespawnEvent`]s.
+///
+/// This can be disabled for individual clients with the [`AutoRespawn`]
+/// component.
 #[derive(Clone, Default)]
 pub struct AutoRespawnPlugin;
 impl Plugin for AutoRespawnPlugin {
@@ -21,13 +27,97 @@ impl Plugin for AutoRespawnPlugin {
     }
 }
 
+/// A component that decides whether a client respawns automatically when it
+/// dies.
+///
+/// If this component isn't present, the client will respawn automatically.
+/// A [`DeathEvent`] is sent regardless of this setting.
+#[derive(Clone, Component, Copy, Debug, Eq, PartialEq)]
+pub struct AutoRespawn(pub bool);
+impl Default for AutoRespawn {
+    fn default() -> Self {
+        Self(true)
+    }
+}
+
 fn auto_respawn(
     mut events: MessageReader<DeathEvent>,
+    query: Query<&AutoRespawn>,
     mut perform_respawn_events: MessageWriter<PerformRespawnEvent>,
 ) {
     for event in events.read() {
+        if query
+            .get(event.entity)
+            .is_ok_and(|auto_respawn| !auto_respawn.0)
+        {
+            continue;
+        }
         perform_respawn_events.write(PerformRespawnEvent {
             entity: event.entity,
         });
     }
 }
+
+impl Client {
+    /// Set whether this client should respawn automatically when it dies.
+    ///
+    /// This is enabled by default. See [`AutoRespawn`] for more details.
+    pub fn set_auto_respawn(&self, enabled: bool) {
+        self.ecs
+            .write()
+            .entity_mut(self.entity)
+            .insert(AutoRespawn(enabled));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use azalea_client::test_utils::prelude::*;
+    use azalea_protocol::packets::{
+        ConnectionProtocol,
+        game::{ClientboundSetHealth, ServerboundGamePacket, s_client_command},
+    };
+
+    use super::*;
+
+    fn respawns_after_death(auto_respawn: Option<AutoRespawn>) -> bool {
+        let _lock = init();
+
+        let mut simulation = Simulation::new(ConnectionProtocol::Game);
+        simulation.app.add_plugins(AutoRespawnPlugin);
+        if let Some(auto_respawn) = auto_respawn {
+            let entity = simulation.entity;
+            simulation
+                .app
+                .world_mut()
+                .entity_mut(entity)
+                .insert(auto_respawn);
+        }
+        simulation.receive_packet(default_login_packet());
+        simulation.tick();
+
+        let sent_packets = SentPackets::new(&mut simulation);
+        simulation.receive_packet(ClientboundSetHealth {
+            health: 0.,
+            food: 20,
+            saturation: 5.,
+        });
+        simulation.tick();
+
+        let mut respawned = false;
+        while let Some(packet) = sent_packets.next() {
+            if let ServerboundGamePacket::ClientCommand(p) = packet {
+                assert_eq!(p.action, s_client_command::Action::PerformRespawn);
+                respawned = true;
+            }
+        }
+        respawned
+    }
+
+    #[test]
+    fn test_auto_respawn() {
+        assert!(respawns_after_death(None));
+        assert!(respawns_after_death(Some(AutoRespawn(true))));
+        assert!(!respawns_after_death(Some(AutoRespawn(false))));
+    }
+}
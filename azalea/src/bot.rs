@@ -1,8 +1,9 @@
 use std::f64::consts::PI;
 
-use azalea_client::mining::Mining;
+use azalea_block::{BlockState, fluid_state::FluidState};
+use azalea_client::mining::{Mining, StopMiningBlockEvent};
 use azalea_core::{
-    position::{BlockPos, Vec3},
+    position::{BlockPos, ChunkPos, Vec3},
     tick::GameTick,
 };
 use azalea_entity::{
@@ -12,17 +13,21 @@ use azalea_entity::{
 use azalea_physics::PhysicsSystems;
 use bevy_app::Update;
 use bevy_ecs::prelude::*;
+use thiserror::Error;
+use tokio::sync::broadcast::error::RecvError;
 use tracing::trace;
 
 use crate::{
     Client,
     app::{App, Plugin, PluginGroup, PluginGroupBuilder},
+    client_impl::error::MissingComponentError,
     ecs::{
         component::Component,
         entity::Entity,
         query::{With, Without},
         system::{Commands, Query},
     },
+    error::AzaleaResult,
 };
 
 #[derive(Clone, Default)]
@@ -99,6 +104,13 @@ impl Client {
         });
     }
 
+    /// Turn the bot's head to look at the center of the block.
+    ///
+    /// This is a shortcut for `bot.look_at(pos.center())`.
+    pub fn look_at_block(&self, pos: BlockPos) {
+        self.look_at(pos.center());
+    }
+
     /// Wait for the specified number of ticks using
     /// [`Self::get_tick_broadcaster`].
     ///
@@ -127,6 +139,51 @@ impl Client {
         }
     }
 
+    /// Wait until the chunk at the given position is loaded, up to the
+    /// specified number of ticks.
+    ///
+    /// Returns whether the chunk was loaded before the timeout expired. If the
+    /// chunk is already loaded, this returns immediately.
+    ///
+    /// If `timeout_ticks` is None, there will be no timeout.
+    ///
+    /// ```
+    /// # use azalea::{BlockPos, Client, core::position::ChunkPos};
+    /// # async fn example(bot: &Client) -> azalea::Result<()> {
+    /// let target = BlockPos::new(1000, 64, 1000);
+    /// if bot
+    ///     .wait_for_chunk(ChunkPos::from(target), Some(20 * 5))
+    ///     .await?
+    /// {
+    ///     bot.look_at_block(target);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn wait_for_chunk(
+        &self,
+        pos: ChunkPos,
+        timeout_ticks: Option<usize>,
+    ) -> AzaleaResult<bool> {
+        let mut ticks = self.get_tick_broadcaster();
+        let mut elapsed_ticks = 0;
+        loop {
+            if self.world()?.read().chunks.get(&pos).is_some() {
+                return Ok(true);
+            }
+            if let Some(timeout_ticks) = timeout_ticks
+                && elapsed_ticks >= timeout_ticks
+            {
+                return Ok(false);
+            }
+
+            if let Err(RecvError::Closed) = ticks.recv().await {
+                return Ok(false);
+            }
+            elapsed_ticks += 1;
+        }
+    }
+
     /// Mine a block.
     ///
     /// This won't turn the bot's head towards the block, so if that's necessary
@@ -142,6 +199,97 @@ impl Client {
             }
         }
     }
+
+    /// Mine a block and wait until it's broken, with detailed errors.
+    ///
+    /// The time that it takes to break the block is the same as in vanilla,
+    /// and depends on the block's hardness, the held tool, and whether the bot
+    /// is on the ground or underwater.
+    ///
+    /// Unlike [`Self::mine`], this returns an error if the block changes into
+    /// something else while it's being mined, or if mining stops before the
+    /// block is broken (like if we get too far away from it).
+    ///
+    /// Like [`Self::mine`], this won't turn the bot's head towards the block.
+    ///
+    /// ```no_run
+    /// # use azalea::{BlockPos, prelude::*};
+    /// # async fn example(bot: &Client) {
+    /// let pos = BlockPos::new(0, 64, 0);
+    /// bot.look_at_block(pos);
+    /// if let Err(err) = bot.try_mine(pos).await {
+    ///     println!("couldn't mine {pos}: {err}");
+    /// }
+    /// # }
+    /// ```
+    pub async fn try_mine(&self, position: BlockPos) -> Result<(), MineBlockError> {
+        let world = self.world()?;
+        let original_block = world.read().get_block_state(position).unwrap_or_default();
+        if original_block.is_air() {
+            return Err(MineBlockError::BlockWasAir);
+        }
+        // waterlogged blocks leave their water behind when they're broken
+        let broken_block = BlockState::from(FluidState::from(original_block));
+
+        let mut ticks = self.get_tick_broadcaster();
+        self.start_mining(position);
+
+        let mut started = false;
+        let mut elapsed_ticks = 0;
+        loop {
+            if let Err(RecvError::Closed) = ticks.recv().await {
+                return Err(MineBlockError::Interrupted);
+            }
+            elapsed_ticks += 1;
+
+            let is_mining_block = self
+                .ecs
+                .read()
+                .get::<Mining>(self.entity)
+                .is_some_and(|mining| mining.pos == position);
+
+            let block = world.read().get_block_state(position).unwrap_or_default();
+            if block == broken_block {
+                return Ok(());
+            }
+            if block != original_block {
+                if is_mining_block {
+                    self.ecs.write().write_message(StopMiningBlockEvent {
+                        entity: self.entity,
+                    });
+                }
+                return Err(MineBlockError::BlockChanged(block));
+            }
+
+            if is_mining_block {
+                started = true;
+            } else if started || elapsed_ticks > MINE_START_TIMEOUT_TICKS {
+                return Err(MineBlockError::Interrupted);
+            }
+        }
+    }
+}
+
+/// How long [`Client::try_mine`] waits for mining to start before giving up.
+const MINE_START_TIMEOUT_TICKS: usize = 20;
+
+/// The reason that a block couldn't be mined by [`Client::try_mine`].
+#[derive(Debug, Error)]
+pub enum MineBlockError {
+    /// There was no block to mine.
+    #[error("the block was air")]
+    BlockWasAir,
+    /// The block turned into a different block before we finished mining it.
+    #[error("the block changed to {0:?} while it was being mined")]
+    BlockChanged(BlockState),
+    /// We stopped mining before the block was broken, or mining never started.
+    ///
+    /// This can happen if the block is out of reach, if something else started
+    /// mining a different block, or if the client disconnected.
+    #[error("mining was interrupted before the block was broken")]
+    Interrupted,
+    #[error(transparent)]
+    MissingComponent(#[from] MissingComponentError),
 }
 
 /// Event to jump once.
@@ -186,6 +334,11 @@ fn look_at_listener(
 
 /// Return the look direction that would make a client at `current` be
 /// looking at `target`.
+///
+/// This uses the same conventions as vanilla, so a `y_rot` of 0 is looking
+/// south (+Z), 90 is west (-X), and an `x_rot` of -90 is looking straight up.
+/// The `y_rot` may be outside of the -180 to 180 range, but
+/// [`LookDirection::update`] will take care of turning the shortest way.
 pub fn direction_looking_at(current: Vec3, target: Vec3) -> LookDirection {
     // borrowed from mineflayer's Bot.lookAt because i didn't want to do math
     let delta = target - current;
@@ -213,3 +366,191 @@ impl PluginGroup for DefaultBotPlugins {
             .add(crate::auto_reconnect::AutoReconnectPlugin)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_direction(delta: Vec3, y_rot: f32, x_rot: f32) {
+        let direction = direction_looking_at(Vec3::ZERO, delta);
+        assert!(
+            (direction.y_rot().rem_euclid(360.) - y_rot.rem_euclid(360.)).abs() < 1e-3,
+            "y_rot for {delta} was {}, expected {y_rot}",
+            direction.y_rot()
+        );
+        assert!(
+            (direction.x_rot() - x_rot).abs() < 1e-3,
+            "x_rot for {delta} was {}, expected {x_rot}",
+            direction.x_rot()
+        );
+    }
+
+    #[test]
+    fn test_wait_for_chunk() {
+        use std::sync::Arc;
+
+        use azalea_client::{local_player::WorldHolder, test_utils::prelude::*};
+        use azalea_protocol::packets::ConnectionProtocol;
+        use parking_lot::RwLock;
+
+        use crate::tick_broadcast::{TickBroadcast, TickBroadcastPlugin};
+
+        let _lock = init();
+
+        let mut simulation = Simulation::new(ConnectionProtocol::Game);
+        simulation.receive_packet(default_login_packet());
+        simulation.tick();
+
+        // the client shares its world with the simulation, but has its own ECS so we
+        // can control when ticks happen
+        let mut app = App::new();
+        app.add_plugins(TickBroadcastPlugin);
+        let mut ecs = std::mem::take(app.world_mut());
+        let world_holder = simulation.component::<WorldHolder>();
+        let entity = ecs.spawn(world_holder).id();
+        let tick_broadcast = (**ecs.resource::<TickBroadcast>()).clone();
+        let bot = Client::new(entity, Arc::new(RwLock::new(ecs)));
+
+        // the simulation has its own runtime, which can't be dropped in an async
+        // context
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap();
+        runtime.block_on(async {
+            let pos = ChunkPos::new(0, 0);
+            assert!(!bot.wait_for_chunk(pos, Some(0)).await.unwrap());
+
+            let (loaded, ()) = tokio::join!(bot.wait_for_chunk(pos, Some(20)), async {
+                tokio::task::yield_now().await;
+                let _ = tick_broadcast.send(());
+                tokio::task::yield_now().await;
+
+                simulation.receive_packet(make_basic_empty_chunk(pos, (384 + 64) / 16));
+                simulation.tick();
+                let _ = tick_broadcast.send(());
+            });
+            assert!(loaded.unwrap());
+
+            // it's already loaded now, so this doesn't need to wait for any ticks
+            assert!(bot.wait_for_chunk(pos, Some(0)).await.unwrap());
+
+            let other_pos = ChunkPos::new(5, 5);
+            let (loaded, ()) = tokio::join!(bot.wait_for_chunk(other_pos, Some(3)), async {
+                for _ in 0..3 {
+                    tokio::task::yield_now().await;
+                    let _ = tick_broadcast.send(());
+                }
+            });
+            assert!(!loaded.unwrap());
+        });
+    }
+
+    #[test]
+    fn test_try_mine() {
+        use std::sync::Arc;
+
+        use azalea_block::blocks;
+        use azalea_client::{
+            local_player::WorldHolder, mining::StartMiningBlockEvent, test_utils::prelude::*,
+        };
+        use azalea_core::direction::Direction;
+        use azalea_protocol::packets::ConnectionProtocol;
+        use parking_lot::RwLock;
+
+        use crate::tick_broadcast::{TickBroadcast, TickBroadcastPlugin};
+
+        let _lock = init();
+
+        let mut simulation = Simulation::new(ConnectionProtocol::Game);
+        simulation.receive_packet(default_login_packet());
+        simulation.receive_packet(make_basic_empty_chunk(ChunkPos::new(0, 0), (384 + 64) / 16));
+        simulation.tick();
+
+        let mut app = App::new();
+        app.add_plugins(TickBroadcastPlugin)
+            .add_message::<StartMiningBlockEvent>()
+            .add_message::<StopMiningBlockEvent>();
+        let mut ecs = std::mem::take(app.world_mut());
+        let world_holder = simulation.component::<WorldHolder>();
+        let world = world_holder.shared.clone();
+        let entity = ecs.spawn(world_holder).id();
+        let tick_broadcast = (**ecs.resource::<TickBroadcast>()).clone();
+        let bot = Client::new(entity, Arc::new(RwLock::new(ecs)));
+
+        let pos = BlockPos::new(0, 1, 0);
+        let set_block = |block: BlockState| {
+            world.write().set_block_state(pos, block);
+        };
+        let set_mining = |mining: bool| {
+            let mut ecs = bot.ecs.write();
+            if mining {
+                ecs.entity_mut(bot.entity).insert(Mining {
+                    pos,
+                    dir: Direction::Down,
+                    force: true,
+                });
+            } else {
+                ecs.entity_mut(bot.entity).remove::<Mining>();
+            }
+        };
+        let tick = || async {
+            tokio::task::yield_now().await;
+            let _ = tick_broadcast.send(());
+            tokio::task::yield_now().await;
+        };
+
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap();
+        runtime.block_on(async {
+            assert!(matches!(
+                bot.try_mine(pos).await,
+                Err(MineBlockError::BlockWasAir)
+            ));
+
+            // the block gets broken
+            set_block(blocks::Stone {}.into());
+            let (res, ()) = tokio::join!(bot.try_mine(pos), async {
+                set_mining(true);
+                tick().await;
+                set_mining(false);
+                set_block(BlockState::AIR);
+                tick().await;
+            });
+            assert!(res.is_ok());
+
+            // the block changes into something else while we're mining it
+            set_block(blocks::Stone {}.into());
+            let (res, ()) = tokio::join!(bot.try_mine(pos), async {
+                set_mining(true);
+                tick().await;
+                set_block(blocks::Dirt {}.into());
+                tick().await;
+            });
+            assert!(matches!(res, Err(MineBlockError::BlockChanged(block)) if block == blocks::Dirt {}.into()));
+
+            // mining stops without the block being broken
+            set_mining(false);
+            let (res, ()) = tokio::join!(bot.try_mine(pos), async {
+                set_mining(true);
+                tick().await;
+                set_mining(false);
+                tick().await;
+            });
+            assert!(matches!(res, Err(MineBlockError::Interrupted)));
+        });
+    }
+
+    #[test]
+    fn test_direction_looking_at() {
+        assert_direction(Vec3::new(0., 0., 1.), 0., 0.);
+        assert_direction(Vec3::new(-1., 0., 0.), 90., 0.);
+        assert_direction(Vec3::new(0., 0., -1.), 180., 0.);
+        assert_direction(Vec3::new(1., 0., 0.), -90., 0.);
+        assert_direction(Vec3::new(-1., 0., 1.), 45., 0.);
+
+        assert_direction(Vec3::new(0., 1., 1.), 0., -45.);
+        assert_direction(Vec3::new(0., -1., 1.), 0., 45.);
+        assert_direction(Vec3::new(0., -1., 0.), 0., 90.);
+    }
+}
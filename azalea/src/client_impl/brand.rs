@@ -0,0 +1,87 @@
+use azalea_client::brand::ClientBrand;
+use tracing::{debug, warn};
+
+use crate::{
+    Client,
+    client_impl::{error::AzaleaResult, plugin_message::PluginMessageError},
+};
+
+impl Client {
+    /// Change the brand that the client tells the server it's using.
+    ///
+    /// The default brand is "vanilla". This is sent to the server on the
+    /// `minecraft:brand` channel when the client joins, and some anticheats
+    /// check it.
+    ///
+    /// If the client is already in the `configuration` or `game` state, the
+    /// new brand is sent immediately. Otherwise, it's stored and sent when the
+    /// client finishes logging in.
+    pub fn set_brand(&self, brand: &str) -> AzaleaResult<()> {
+        let client_brand = ClientBrand(brand.to_owned());
+        self.query_self::<&mut ClientBrand, _>(|mut b| {
+            *b = client_brand.clone();
+        })?;
+
+        match self.send_plugin_message("brand".into(), &client_brand.payload_data()) {
+            Ok(()) => debug!("Sending client brand (already joined): {brand:?}"),
+            // we haven't joined yet, so it'll be sent by `send_brand`
+            Err(PluginMessageError::WrongState) => {}
+            Err(e) => warn!("Couldn't send client brand {brand:?}: {e}"),
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use azalea_client::{
+        InConfigState,
+        packet::{config::SendConfigPacketEvent, login::InLoginState},
+    };
+    use azalea_protocol::packets::config::ServerboundConfigPacket;
+    use bevy_ecs::{observer::On, world::World};
+    use parking_lot::{Mutex, RwLock};
+
+    use super::*;
+
+    #[test]
+    fn test_set_brand() {
+        let mut ecs = World::new();
+        let entity = ecs.spawn((ClientBrand::default(), InLoginState)).id();
+
+        let sent = Arc::new(Mutex::new(Vec::new()));
+        let sent_clone = sent.clone();
+        ecs.add_observer(move |event: On<SendConfigPacketEvent>| {
+            sent_clone.lock().push(event.packet.clone());
+        });
+
+        let client = Client::new(entity, Arc::new(RwLock::new(ecs)));
+
+        // while logging in, the brand is only stored
+        client.set_brand("azalea").unwrap();
+        client.ecs.write().flush();
+        assert!(sent.lock().is_empty());
+        assert_eq!(
+            *client.component::<ClientBrand>().unwrap(),
+            ClientBrand("azalea".to_owned())
+        );
+
+        // but in the configuration state, it's sent right away
+        client
+            .ecs
+            .write()
+            .entity_mut(entity)
+            .remove::<InLoginState>()
+            .insert(InConfigState);
+        client.set_brand("other").unwrap();
+        client.ecs.write().flush();
+        let sent = sent.lock();
+        let [ServerboundConfigPacket::CustomPayload(packet)] = &sent[..] else {
+            panic!("expected one custom payload packet, got {sent:?}");
+        };
+        assert_eq!(*packet.data, *b"\x05other");
+    }
+}
@@ -1,4 +1,6 @@
-use azalea_client::client_chat::{ChatKind, SendChatEvent, handler::SendChatKindEvent};
+use azalea_client::client_chat::{
+    ChatKind, ChatSigningMode, MAX_CHAT_LENGTH, SendChatEvent, handler::SendChatKindEvent,
+};
 
 use crate::Client;
 
@@ -48,4 +50,65 @@ impl Client {
             content: content.into(),
         });
     }
+
+    /// Send a chat message, splitting it into multiple messages if it's longer
+    /// than the server allows.
+    ///
+    /// The message is split every [`MAX_CHAT_LENGTH`] characters (not bytes),
+    /// and the parts are sent in order. Unlike [`Client::chat`], this never
+    /// sends a command, even if the message starts with a `/`.
+    pub fn chat_long(&self, message: &str) {
+        let chars = message.chars().collect::<Vec<_>>();
+        let mut ecs = self.ecs.write();
+        for chunk in chars.chunks(MAX_CHAT_LENGTH) {
+            ecs.write_message(SendChatKindEvent {
+                entity: self.entity,
+                content: chunk.iter().collect(),
+                kind: ChatKind::Message,
+            });
+        }
+    }
+
+    /// Choose whether chat messages sent by this client should be signed.
+    ///
+    /// Use [`ChatSigningMode::Unsigned`] for servers that accept unsigned
+    /// chat. This setting is kept when the client reconnects.
+    pub fn set_chat_signing_mode(&self, mode: ChatSigningMode) {
+        self.ecs.write().entity_mut(self.entity).insert(mode);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use bevy_ecs::{message::Messages, world::World};
+    use parking_lot::RwLock;
+
+    use super::*;
+
+    #[test]
+    fn test_chat_long() {
+        let mut world = World::new();
+        world.init_resource::<Messages<SendChatKindEvent>>();
+        let entity = world.spawn_empty().id();
+        let client = Client::new(entity, Arc::new(RwLock::new(world)));
+
+        // multibyte characters count as one character each
+        let message = "é".repeat(300) + &"a".repeat(300);
+        client.chat_long(&message);
+
+        let sent = client
+            .ecs
+            .write()
+            .resource_mut::<Messages<SendChatKindEvent>>()
+            .drain()
+            .map(|event| event.content)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            sent.iter().map(|s| s.chars().count()).collect::<Vec<_>>(),
+            [256, 256, 88]
+        );
+        assert_eq!(sent.concat(), message);
+    }
 }
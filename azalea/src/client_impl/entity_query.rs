@@ -1,6 +1,6 @@
 use std::{any, sync::Arc};
 
-use azalea_core::position::Vec3;
+use azalea_core::{entity_id::MinecraftEntityId, position::Vec3};
 use azalea_entity::{LocalEntity, Position, metadata};
 use azalea_world::WorldName;
 use bevy_ecs::{
@@ -254,6 +254,62 @@ impl Client {
         self.nearest_entities::<(With<metadata::Player>, Without<LocalEntity>)>()
     }
 
+    /// Returns an array of [`EntityRef`]s for all known entities within
+    /// `radius` blocks of the client, sorted by nearest first.
+    ///
+    /// Every entity is returned alongside its position. The client itself is
+    /// never included, and entities that don't have a position (or are in a
+    /// different world) are skipped.
+    ///
+    /// If you need to filter the entities by their components, consider using
+    /// [`Self::nearest_entities_by`] instead.
+    ///
+    /// ```
+    /// # use azalea_entity::metadata::AbstractMonster;
+    /// # fn example(bot: &azalea::Client) {
+    /// // attack the nearest hostile mob that's in reach
+    /// let nearby = bot.nearby_entities(3.).unwrap();
+    /// if let Some((mob, _position)) = nearby
+    ///     .iter()
+    ///     .find(|(entity, _)| entity.get_component::<AbstractMonster>().is_some())
+    /// {
+    ///     mob.attack();
+    /// }
+    /// # }
+    /// ```
+    pub fn nearby_entities(&self, radius: f64) -> AzaleaResult<Box<[(EntityRef, Vec3)]>> {
+        let (world_name, position) = {
+            let world_name = self.component::<WorldName>()?;
+            let position = self.component::<Position>()?;
+
+            (world_name.clone(), **position)
+        };
+
+        let entities = {
+            let mut ecs = self.ecs.write();
+            let mut query =
+                ecs.query_filtered::<(Entity, &WorldName, &Position), With<MinecraftEntityId>>();
+            let mut entities = query
+                .iter(&ecs)
+                .filter(|(e, e_world_name, _)| *e != self.entity && **e_world_name == world_name)
+                .map(|(e, _, e_position)| (e, **e_position))
+                .filter(|(_, e_position)| {
+                    e_position.distance_squared_to(position) <= radius * radius
+                })
+                .collect::<Vec<(Entity, Vec3)>>();
+            entities.sort_by_cached_key(|(_, e_position)| {
+                // to_bits is fine here as long as the number is positive
+                e_position.distance_squared_to(position).to_bits()
+            });
+            entities
+        };
+
+        Ok(entities
+            .into_iter()
+            .map(|(e, e_position)| (self.entity_ref_for(e), e_position))
+            .collect())
+    }
+
     /// Returns an array of all [`Entity`]s in the world that match the
     /// predicate, sorted by nearest first.
     ///
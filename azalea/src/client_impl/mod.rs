@@ -1,12 +1,13 @@
 use std::{collections::HashMap, sync::Arc};
 
 use azalea_auth::game_profile::GameProfile;
+use azalea_chat::FormattedText;
 use azalea_client::{
     DefaultPlugins,
     account::Account,
-    connection::RawConnection,
+    connection::{CompressionThreshold, RawConnection},
     disconnect::DisconnectEvent,
-    join::{ConnectOpts, StartJoinServerEvent},
+    join::{ConnectOpts, ConnectTimeouts, StartJoinServerEvent},
     local_player::{Experience, Hunger, TabList, WorldHolder},
     packet::game::SendGamePacketEvent,
     player::{GameProfileComponent, PlayerInfo},
@@ -16,8 +17,11 @@ use azalea_client::{
 use azalea_core::{
     data_registry::{DataRegistryWithKey, ResolvableDataRegistry},
     entity_id::MinecraftEntityId,
+    game_type::GameMode,
+    position::BlockPos,
 };
 use azalea_entity::indexing::{EntityIdIndex, EntityUuidIndex};
+use azalea_physics::collision::{BlockWithShape, EMPTY_SHAPE, VoxelShape};
 use azalea_protocol::{
     address::{ResolvableAddr, ResolvedAddr},
     connect::Proxy,
@@ -41,6 +45,7 @@ use crate::{
 };
 
 pub mod attack;
+pub mod brand;
 pub mod chat;
 pub mod client_information;
 pub mod entity_query;
@@ -49,6 +54,7 @@ pub mod interact;
 pub mod inventory;
 pub mod mining;
 pub mod movement;
+pub mod plugin_message;
 
 /// A Minecraft client instance that can interact with the world.
 ///
@@ -111,6 +117,8 @@ impl StartClientOpts {
                     address,
                     server_proxy: None,
                     sessionserver_proxy: None,
+                    timeouts: ConnectTimeouts::default(),
+                    read_buffer_capacity: 0,
                 },
                 event_sender,
             },
@@ -146,6 +154,21 @@ impl StartClientOpts {
         self.connect_opts.sessionserver_proxy = Some(proxy);
         self
     }
+    /// Configure how long each phase of joining the server may take before
+    /// the connection is considered to have timed out.
+    pub fn timeouts(mut self, timeouts: ConnectTimeouts) -> Self {
+        self.connect_opts.timeouts = timeouts;
+        self
+    }
+    /// Set the minimum capacity of the buffer that packets are read into.
+    ///
+    /// See [`Connection::set_read_buffer_capacity`].
+    ///
+    /// [`Connection::set_read_buffer_capacity`]: azalea_protocol::connect::Connection::set_read_buffer_capacity
+    pub fn read_buffer_capacity(mut self, capacity: usize) -> Self {
+        self.connect_opts.read_buffer_capacity = capacity;
+        self
+    }
 }
 
 impl Client {
@@ -264,6 +287,18 @@ impl Client {
         });
     }
 
+    /// Disconnect this client from the server, like [`Self::disconnect`], but
+    /// with a reason that's included in the [`DisconnectEvent`].
+    ///
+    /// The reason isn't sent to the server, but it'll show up in your logs and
+    /// in [`Event::Disconnect`](crate::Event::Disconnect).
+    pub fn disconnect_with_reason(&self, reason: impl Into<FormattedText>) {
+        self.ecs.write().write_message(DisconnectEvent {
+            entity: self.entity,
+            reason: Some(reason.into()),
+        });
+    }
+
     /// End the entire client or swarm, and return from
     /// [`ClientBuilder::start`] or [`SwarmBuilder::start`].
     ///
@@ -365,6 +400,31 @@ impl Client {
         Ok(world_holder.partial.clone())
     }
 
+    /// Get the collision shape of the block at the given position, or an empty
+    /// shape if the block is air or isn't loaded.
+    ///
+    /// The shape is relative to the block's position, so a full block is from
+    /// `(0, 0, 0)` to `(1, 1, 1)`.
+    ///
+    /// ```
+    /// # use azalea_core::position::BlockPos;
+    /// # fn example(bot: &azalea::Client) -> azalea::error::AzaleaResult<()> {
+    /// // check whether we'd be able to stand on top of a block
+    /// let pos = BlockPos::new(0, 64, 0);
+    /// let can_stand_on = bot.get_block_collision_shape(pos)?.is_full_cube()
+    ///     && bot.get_block_collision_shape(pos.up(1))?.is_empty()
+    ///     && bot.get_block_collision_shape(pos.up(2))?.is_empty();
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn get_block_collision_shape(&self, pos: BlockPos) -> AzaleaResult<VoxelShape> {
+        let block_state = self.world()?.read().get_block_state(pos);
+        Ok(match block_state {
+            Some(block_state) => block_state.collision_shape(pos).into_owned(),
+            None => EMPTY_SHAPE.clone(),
+        })
+    }
+
     /// Returns whether we have a received the login packet yet.
     pub fn logged_in(&self) -> bool {
         // the login packet tells us the world name
@@ -399,6 +459,35 @@ impl Client {
         Ok(self.component::<Experience>()?.to_owned())
     }
 
+    /// Get the current game mode of this client.
+    ///
+    /// This is a shortcut for `*self.component::<GameMode>()?`. To be notified
+    /// when it changes, use [`GameModeChangeEvent`].
+    ///
+    /// ```
+    /// # use azalea::{Client, core::game_type::GameMode};
+    /// # fn example(bot: &Client) -> azalea::Result<()> {
+    /// // flying is only allowed in creative and spectator mode
+    /// let can_fly = matches!(bot.game_mode()?, GameMode::Creative | GameMode::Spectator);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`GameModeChangeEvent`]: azalea_client::packet::game::GameModeChangeEvent
+    pub fn game_mode(&self) -> AzaleaResult<GameMode> {
+        Ok(*self.component::<GameMode>()?)
+    }
+
+    /// Get the compression threshold that the server sent us while logging
+    /// in, or `None` if compression is disabled.
+    pub fn compression_threshold(&self) -> AzaleaResult<Option<i32>> {
+        // servers that don't enable compression never send the threshold, so a
+        // missing component isn't an error
+        self.query_self::<Option<&CompressionThreshold>, _>(|threshold| {
+            threshold.and_then(|threshold| threshold.0)
+        })
+    }
+
     /// Get the username of this client's account.
     ///
     /// This is a shortcut for `bot.account().username().to_owned()`.
@@ -500,10 +589,33 @@ impl Client {
 
     /// Call the given function with the client's [`RegistryHolder`].
     ///
+    /// This contains the registries that the server sent during the
+    /// configuration phase, so it can be used to convert between the names
+    /// and protocol IDs of data-driven registry entries.
+    ///
     /// Note that the player's world will be locked during this time, which may
     /// result in a deadlock if you try to access the world again while
     /// in the function.
     ///
+    /// ```
+    /// # use azalea::{Client, Identifier};
+    /// # fn example(bot: &Client) -> azalea::Result<()> {
+    /// let biome_registry = Identifier::new("worldgen/biome");
+    /// let plains_id = bot.with_registry_holder(|registries| {
+    ///     registries.identifier_to_protocol_id(biome_registry.clone(), &Identifier::new("plains"))
+    /// })?;
+    /// if let Some(plains_id) = plains_id {
+    ///     let name = bot.with_registry_holder(|registries| {
+    ///         registries
+    ///             .protocol_id_to_identifier(biome_registry, plains_id)
+    ///             .cloned()
+    ///     })?;
+    ///     assert_eq!(name, Some(Identifier::new("plains")));
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
     /// [`RegistryHolder`]: azalea_core::registry_holder::RegistryHolder
     pub fn with_registry_holder<R>(
         &self,
@@ -0,0 +1,191 @@
+use azalea_buf::UnsizedByteArray;
+use azalea_client::{InConfigState, InGameState, packet::config::SendConfigPacketEvent};
+use azalea_protocol::packets::{config, game};
+use azalea_registry::identifier::Identifier;
+use bevy_ecs::query::Has;
+use thiserror::Error;
+
+use crate::Client;
+
+/// The maximum number of bytes that the vanilla server accepts in a
+/// serverbound plugin message.
+pub const MAX_PLUGIN_MESSAGE_LENGTH: usize = 32767;
+
+/// The reason that a plugin message couldn't be sent by
+/// [`Client::send_plugin_message`].
+#[derive(Debug, Error)]
+pub enum PluginMessageError {
+    /// The channel contains characters that aren't allowed in identifiers.
+    ///
+    /// See [`Identifier::is_valid`].
+    #[error("invalid plugin channel {0}")]
+    InvalidChannel(Identifier),
+    /// The data is longer than [`MAX_PLUGIN_MESSAGE_LENGTH`], so the server
+    /// would kick us for sending it.
+    #[error("plugin message is {length} bytes long, but the maximum is {max}")]
+    TooLong { length: usize, max: usize },
+    /// The client isn't in the `game` or `configuration` state, so there's no
+    /// packet that the message could be sent with.
+    #[error("plugin messages can only be sent in the game or configuration state")]
+    WrongState,
+}
+
+impl Client {
+    /// Send a plugin message (also known as a custom payload) to the server on
+    /// the given channel.
+    ///
+    /// This is used to communicate with server plugins, mods, and proxies,
+    /// like BungeeCord on the `bungeecord:main` channel. The data is sent
+    /// as-is, without a length prefix.
+    ///
+    /// This works in both the `configuration` and `game` states. In any other
+    /// state (like while logging in), [`PluginMessageError::WrongState`] is
+    /// returned.
+    ///
+    /// ```no_run
+    /// # use azalea::prelude::*;
+    /// # fn example(bot: &Client) -> Result<(), Box<dyn std::error::Error>> {
+    /// // ask BungeeCord which server we're connected to
+    /// let mut data = Vec::new();
+    /// azalea::buf::AzBuf::azalea_write(&"GetServer".to_owned(), &mut data)?;
+    /// bot.send_plugin_message("bungeecord:main".into(), &data)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn send_plugin_message(
+        &self,
+        channel: Identifier,
+        data: &[u8],
+    ) -> Result<(), PluginMessageError> {
+        if !channel.is_valid() {
+            return Err(PluginMessageError::InvalidChannel(channel));
+        }
+        if data.len() > MAX_PLUGIN_MESSAGE_LENGTH {
+            return Err(PluginMessageError::TooLong {
+                length: data.len(),
+                max: MAX_PLUGIN_MESSAGE_LENGTH,
+            });
+        }
+
+        let (in_game_state, in_config_state) = self
+            .query_self::<(Has<InGameState>, Has<InConfigState>), _>(|states| states)
+            .unwrap_or_default();
+        let data = UnsizedByteArray::from(data.to_vec());
+        if in_game_state {
+            self.write_packet(game::ServerboundCustomPayload {
+                identifier: channel,
+                data,
+            });
+        } else if in_config_state {
+            self.ecs
+                .write()
+                .commands()
+                .trigger(SendConfigPacketEvent::new(
+                    self.entity,
+                    config::ServerboundCustomPayload {
+                        identifier: channel,
+                        data,
+                    },
+                ));
+        } else {
+            return Err(PluginMessageError::WrongState);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use azalea_client::packet::game::SendGamePacketEvent;
+    use azalea_protocol::packets::{config::ServerboundConfigPacket, game::ServerboundGamePacket};
+    use bevy_ecs::{observer::On, world::World};
+    use parking_lot::{Mutex, RwLock};
+
+    use super::*;
+
+    #[test]
+    fn test_send_plugin_message() {
+        let mut ecs = World::new();
+        let entity = ecs.spawn(InGameState).id();
+
+        let sent = Arc::new(Mutex::new(Vec::new()));
+        let sent_clone = sent.clone();
+        ecs.add_observer(move |event: On<SendGamePacketEvent>| {
+            sent_clone.lock().push(event.packet.clone());
+        });
+
+        let client = Client::new(entity, Arc::new(RwLock::new(ecs)));
+        client
+            .send_plugin_message("bungeecord:main".into(), b"\x09GetServer")
+            .unwrap();
+        client.ecs.write().flush();
+
+        let sent = sent.lock();
+        let [ServerboundGamePacket::CustomPayload(packet)] = &sent[..] else {
+            panic!("expected one custom payload packet, got {sent:?}");
+        };
+        assert_eq!(packet.identifier, Identifier::new("bungeecord:main"));
+        assert_eq!(packet.data.0, b"\x09GetServer");
+    }
+
+    #[test]
+    fn test_send_plugin_message_in_config_state() {
+        let mut ecs = World::new();
+        let entity = ecs.spawn(InConfigState).id();
+
+        let sent = Arc::new(Mutex::new(Vec::new()));
+        let sent_clone = sent.clone();
+        ecs.add_observer(move |event: On<SendConfigPacketEvent>| {
+            sent_clone.lock().push(event.packet.clone());
+        });
+
+        let client = Client::new(entity, Arc::new(RwLock::new(ecs)));
+        client
+            .send_plugin_message("azalea:test".into(), b"hi")
+            .unwrap();
+        client.ecs.write().flush();
+
+        let sent = sent.lock();
+        let [ServerboundConfigPacket::CustomPayload(packet)] = &sent[..] else {
+            panic!("expected one custom payload packet, got {sent:?}");
+        };
+        assert_eq!(packet.identifier, Identifier::new("azalea:test"));
+        assert_eq!(packet.data.0, b"hi");
+    }
+
+    #[test]
+    fn test_send_plugin_message_in_wrong_state() {
+        let mut ecs = World::new();
+        let entity = ecs.spawn_empty().id();
+        let client = Client::new(entity, Arc::new(RwLock::new(ecs)));
+
+        assert!(matches!(
+            client.send_plugin_message("azalea:test".into(), b"hi"),
+            Err(PluginMessageError::WrongState)
+        ));
+    }
+
+    #[test]
+    fn test_send_invalid_plugin_message() {
+        let mut ecs = World::new();
+        let entity = ecs.spawn(InGameState).id();
+        let client = Client::new(entity, Arc::new(RwLock::new(ecs)));
+
+        assert!(matches!(
+            client.send_plugin_message("BungeeCord".into(), &[]),
+            Err(PluginMessageError::InvalidChannel(_))
+        ));
+        assert!(matches!(
+            client.send_plugin_message("azalea:test".into(), &[0; MAX_PLUGIN_MESSAGE_LENGTH + 1]),
+            Err(PluginMessageError::TooLong {
+                length: 32768,
+                max: MAX_PLUGIN_MESSAGE_LENGTH
+            })
+        ));
+        client
+            .send_plugin_message("azalea:test".into(), &[0; MAX_PLUGIN_MESSAGE_LENGTH])
+            .unwrap();
+    }
+}
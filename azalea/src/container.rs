@@ -9,20 +9,32 @@ use azalea_core::position::BlockPos;
 use azalea_entity::inventory::Inventory;
 use azalea_inventory::{
     ItemStack, Menu,
-    operations::{ClickOperation, PickupClick, QuickMoveClick},
+    operations::{ClickOperation, PickupClick, QuickMoveClick, ThrowClick},
 };
 use azalea_physics::collision::BlockWithShape;
 use azalea_protocol::packets::game::ClientboundGamePacket;
+use azalea_registry::builtin::ItemKind;
 use bevy_app::{App, Plugin, Update};
-use bevy_ecs::{component::Component, prelude::MessageReader, system::Commands};
+use bevy_ecs::{
+    component::Component,
+    prelude::MessageReader,
+    system::{Commands, Query},
+};
 use derive_more::Deref;
+use thiserror::Error;
 
-use crate::{Client, client_impl::error::AzaleaResult};
+use crate::{
+    Client,
+    client_impl::error::{AzaleaResult, MissingComponentError},
+};
 
 pub struct ContainerPlugin;
 impl Plugin for ContainerPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(Update, handle_menu_opened_event);
+        app.add_systems(
+            Update,
+            (handle_menu_opened_event, handle_container_updated_event),
+        );
     }
 }
 
@@ -71,8 +83,42 @@ impl Client {
         pos: BlockPos,
         timeout_ticks: Option<usize>,
     ) -> AzaleaResult<Option<ContainerHandle>> {
+        match self
+            .try_open_container_at_with_timeout_ticks(pos, timeout_ticks)
+            .await
+        {
+            Ok(container) => Ok(Some(container)),
+            Err(OpenContainerError::MissingComponent(err)) => Err(err),
+            Err(_) => Ok(None),
+        }
+    }
+
+    /// Open a container in the world, returning an [`OpenContainerError`]
+    /// that describes why it failed if it couldn't be opened.
+    ///
+    /// This is like [`Self::open_container_at`], but with more detailed
+    /// errors. It also times out after 5 seconds (100 ticks).
+    pub async fn try_open_container_at(
+        &self,
+        pos: BlockPos,
+    ) -> Result<ContainerHandle, OpenContainerError> {
+        self.try_open_container_at_with_timeout_ticks(pos, Some(20 * 5))
+            .await
+    }
+
+    /// Open a container in the world with detailed errors, or time out after a
+    /// specified amount of ticks.
+    ///
+    /// See [`Self::try_open_container_at`] and
+    /// [`Self::open_container_at_with_timeout_ticks`].
+    pub async fn try_open_container_at_with_timeout_ticks(
+        &self,
+        pos: BlockPos,
+        timeout_ticks: Option<usize>,
+    ) -> Result<ContainerHandle, OpenContainerError> {
         let mut ticks = self.get_tick_broadcaster();
         // wait until it's not air (up to 10 ticks)
+        let mut is_air = true;
         for _ in 0..10 {
             let block = self
                 .world()?
@@ -80,18 +126,25 @@ impl Client {
                 .get_block_state(pos)
                 .unwrap_or_default();
             if !block.is_collision_shape_empty() {
+                is_air = false;
                 break;
             }
             let _ = ticks.recv().await;
         }
 
+        // we still click the block if it's air, in case our world is out of date
         self.ecs
             .write()
             .entity_mut(self.entity)
             .insert(WaitingForInventoryOpen);
         self.block_interact(pos);
 
-        self.wait_for_container_open(timeout_ticks).await
+        match self.try_wait_for_container_open(timeout_ticks).await {
+            Err(OpenContainerError::Timeout | OpenContainerError::NotAContainer) if is_air => {
+                Err(OpenContainerError::BlockWasAir)
+            }
+            res => res,
+        }
     }
 
     /// Wait until a container is open, up to the specified number of ticks.
@@ -104,6 +157,22 @@ impl Client {
         &self,
         timeout_ticks: Option<usize>,
     ) -> AzaleaResult<Option<ContainerHandle>> {
+        match self.try_wait_for_container_open(timeout_ticks).await {
+            Ok(container) => Ok(Some(container)),
+            Err(OpenContainerError::MissingComponent(err)) => Err(err),
+            Err(_) => Ok(None),
+        }
+    }
+
+    /// Wait until a container is open, up to the specified number of ticks.
+    ///
+    /// This is like [`Self::wait_for_container_open`], but returns an
+    /// [`OpenContainerError`] that describes why the container couldn't be
+    /// opened.
+    pub async fn try_wait_for_container_open(
+        &self,
+        timeout_ticks: Option<usize>,
+    ) -> Result<ContainerHandle, OpenContainerError> {
         let mut ticks = self.get_tick_broadcaster();
         let mut elapsed_ticks = 0;
         while ticks.recv().await.is_ok() {
@@ -116,15 +185,15 @@ impl Client {
             if let Some(timeout_ticks) = timeout_ticks
                 && elapsed_ticks >= timeout_ticks
             {
-                return Ok(None);
+                return Err(OpenContainerError::Timeout);
             }
         }
 
         let inventory_id = self.component::<Inventory>()?.id;
         if inventory_id == 0 {
-            Ok(None)
+            Err(OpenContainerError::NotAContainer)
         } else {
-            Ok(Some(ContainerHandle::new(inventory_id, self.clone())))
+            Ok(ContainerHandle::new(inventory_id, self.clone()))
         }
     }
 
@@ -173,6 +242,25 @@ impl Client {
     }
 }
 
+/// The reason that a container couldn't be opened by
+/// [`Client::try_open_container_at`].
+#[derive(Debug, Error)]
+pub enum OpenContainerError {
+    /// The server didn't open a container before the timeout expired.
+    #[error("timed out while waiting for the container to open")]
+    Timeout,
+    /// The block was still air (or had no collision shape) after waiting for
+    /// it to load, and clicking it anyway didn't open a container.
+    #[error("the block was air")]
+    BlockWasAir,
+    /// The server responded, but didn't leave a container open, likely
+    /// because the block isn't a container or it couldn't be opened.
+    #[error("the block didn't open a container")]
+    NotAContainer,
+    #[error(transparent)]
+    MissingComponent(#[from] MissingComponentError),
+}
+
 /// A handle to a container that may be open.
 ///
 /// This does not close the container when it's dropped. See [`ContainerHandle`]
@@ -245,6 +333,37 @@ impl ContainerHandleRef {
         Some(self.menu().ok()??.contents())
     }
 
+    /// Returns the non-empty item slots in the container (not including the
+    /// player's inventory), along with their slot indexes.
+    ///
+    /// The indexes can be passed directly to [`Self::click`] and similar
+    /// functions.
+    ///
+    /// If the container is closed, this will return `None`.
+    pub fn contents_indexed(&self) -> Option<Vec<(usize, ItemStack)>> {
+        Some(indexed_contents(&self.menu().ok()??))
+    }
+
+    /// Returns the number of empty slots in the container, not including the
+    /// player's inventory.
+    ///
+    /// If this is the player's inventory, then only the main storage slots
+    /// and hotbar are counted, not the armor, offhand, or crafting slots.
+    ///
+    /// If the container is closed, this will return `None`.
+    pub fn free_slots(&self) -> Option<usize> {
+        Some(free_slots(&self.menu().ok()??))
+    }
+
+    /// Returns whether the container has no empty slots left.
+    ///
+    /// See [`Self::free_slots`] for which slots are counted.
+    ///
+    /// If the container is closed, this will return `None`.
+    pub fn is_full(&self) -> Option<bool> {
+        Some(self.free_slots()? == 0)
+    }
+
     /// Return the contents of the menu, including the player's inventory.
     ///
     /// If the container is closed, this will return `None`.
@@ -288,6 +407,39 @@ impl ContainerHandleRef {
         });
     }
 
+    /// A shortcut for [`Self::click`] with `ThrowClick::Single`.
+    ///
+    /// This drops one item from the slot into the world, like pressing Q.
+    pub fn drop_one(&self, slot: impl Into<usize>) {
+        self.click(ThrowClick::Single {
+            slot: slot.into() as u16,
+        });
+    }
+    /// A shortcut for [`Self::click`] with `ThrowClick::All`.
+    ///
+    /// This drops the whole stack in the slot into the world, like pressing
+    /// Ctrl+Q.
+    pub fn drop_stack(&self, slot: impl Into<usize>) {
+        self.click(ThrowClick::All {
+            slot: slot.into() as u16,
+        });
+    }
+
+    /// Drop every stack of the given item in the player's inventory.
+    ///
+    /// Only the slots in [`Menu::player_slots_range`] are checked, so items in
+    /// the open container (or in the armor and crafting slots) are kept.
+    ///
+    /// Returns the number of stacks that were dropped, or `None` if the
+    /// container is closed.
+    pub fn drop_all_matching(&self, item: ItemKind) -> Option<usize> {
+        let slots = matching_player_slots(&self.menu().ok()??, item);
+        for &slot in &slots {
+            self.drop_stack(slot);
+        }
+        Some(slots.len())
+    }
+
     /// Simulate a click in the container and send the packet to perform the
     /// action.
     pub fn click(&self, operation: impl Into<ClickOperation>) {
@@ -298,6 +450,71 @@ impl ContainerHandleRef {
             operation,
         });
     }
+
+    /// Simulate a click in the container, and then wait until the server
+    /// updates the contents of the container.
+    ///
+    /// This times out after 10 ticks. Use
+    /// [`Self::click_and_wait_with_timeout_ticks`] if you would like to
+    /// configure this.
+    ///
+    /// Returns the menu after it was updated, or `None` if the timeout expired
+    /// or the container was closed.
+    ///
+    /// Note that the server won't necessarily send an update if the state that
+    /// we predicted after the click was correct, so a timeout doesn't always
+    /// mean that the click failed.
+    pub async fn click_and_wait(
+        &self,
+        operation: impl Into<ClickOperation>,
+    ) -> AzaleaResult<Option<Menu>> {
+        self.click_and_wait_with_timeout_ticks(operation, 10).await
+    }
+
+    /// Simulate a click in the container, and then wait until the server
+    /// updates the contents of the container or the given number of ticks
+    /// pass.
+    ///
+    /// See [`Self::click_and_wait`] for more information.
+    pub async fn click_and_wait_with_timeout_ticks(
+        &self,
+        operation: impl Into<ClickOperation>,
+        timeout_ticks: usize,
+    ) -> AzaleaResult<Option<Menu>> {
+        let mut ticks = self.client.get_tick_broadcaster();
+
+        self.client
+            .ecs
+            .write()
+            .entity_mut(self.client.entity)
+            .insert(WaitingForContainerUpdate {
+                container_id: self.id,
+            });
+        self.click(operation);
+
+        let mut updated = false;
+        for _ in 0..timeout_ticks {
+            if ticks.recv().await.is_err() {
+                break;
+            }
+            let ecs = self.client.ecs.read();
+            if ecs
+                .get::<WaitingForContainerUpdate>(self.client.entity)
+                .is_none()
+            {
+                updated = true;
+                break;
+            }
+        }
+
+        if !updated {
+            if let Ok(mut entity) = self.client.ecs.write().get_entity_mut(self.client.entity) {
+                entity.remove::<WaitingForContainerUpdate>();
+            }
+            return Ok(None);
+        }
+        self.menu()
+    }
 }
 
 /// A handle to the open container.
@@ -329,6 +546,30 @@ impl ContainerHandle {
     }
 }
 
+fn indexed_contents(menu: &Menu) -> Vec<(usize, ItemStack)> {
+    let player_slots_range = menu.player_slots_range();
+    menu.slots()
+        .into_iter()
+        .enumerate()
+        .filter(|(i, item)| !player_slots_range.contains(i) && item.is_present())
+        .collect()
+}
+
+fn matching_player_slots(menu: &Menu, item: ItemKind) -> Vec<usize> {
+    let slots = menu.slots();
+    menu.player_slots_range()
+        .filter(|&i| slots[i].kind() == item)
+        .collect()
+}
+
+fn free_slots(menu: &Menu) -> usize {
+    let storage = match menu {
+        Menu::Player(_) => menu.slots()[menu.player_slots_range()].to_vec(),
+        _ => menu.contents(),
+    };
+    storage.iter().filter(|item| item.is_empty()).count()
+}
+
 #[derive(Component, Debug)]
 pub struct WaitingForInventoryOpen;
 
@@ -344,3 +585,268 @@ pub fn handle_menu_opened_event(
         }
     }
 }
+
+/// Present on clients that are waiting for the server to send a
+/// `ContainerSetSlot` or `ContainerSetContent` packet for the given container.
+///
+/// See [`ContainerHandleRef::click_and_wait`].
+#[derive(Component, Debug)]
+pub struct WaitingForContainerUpdate {
+    pub container_id: i32,
+}
+
+pub fn handle_container_updated_event(
+    mut commands: Commands,
+    mut events: MessageReader<ReceiveGamePacketEvent>,
+    query: Query<&WaitingForContainerUpdate>,
+) {
+    for event in events.read() {
+        let container_id = match event.packet.as_ref() {
+            ClientboundGamePacket::ContainerSetContent(p) => p.container_id,
+            ClientboundGamePacket::ContainerSetSlot(p) => p.container_id,
+            _ => continue,
+        };
+        // updates to other containers (like our own inventory) don't count
+        if let Ok(waiting) = query.get(event.entity)
+            && waiting.container_id == container_id
+        {
+            commands
+                .entity(event.entity)
+                .remove::<WaitingForContainerUpdate>();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{sync::Arc, time::Duration};
+
+    use azalea_client::{interact::StartUseItemEvent, local_player::WorldHolder};
+    use azalea_registry::builtin::MenuKind;
+    use bevy_ecs::{message::Messages, observer::On, world::World};
+    use parking_lot::RwLock;
+
+    use super::*;
+    use crate::tick_broadcast::{TickBroadcast, TickBroadcastPlugin};
+
+    #[test]
+    fn test_free_slots() {
+        let mut menu = Menu::from_kind(MenuKind::Generic9x3);
+        for i in 0..27 {
+            if ![3, 10, 20].contains(&i) {
+                *menu.slot_mut(i).unwrap() = ItemKind::Stone.into();
+            }
+        }
+        // the player's inventory is empty, but it shouldn't count
+        assert_eq!(free_slots(&menu), 3);
+        *menu.slot_mut(3).unwrap() = ItemKind::Stone.into();
+        *menu.slot_mut(10).unwrap() = ItemKind::Stone.into();
+        *menu.slot_mut(20).unwrap() = ItemKind::Stone.into();
+        assert_eq!(free_slots(&menu), 0);
+
+        // armor and crafting slots aren't counted for the player's inventory
+        let mut menu = Menu::Player(Default::default());
+        assert_eq!(free_slots(&menu), 36);
+        *menu.slot_mut(5).unwrap() = ItemKind::DiamondHelmet.into();
+        *menu.slot_mut(9).unwrap() = ItemKind::Stone.into();
+        assert_eq!(free_slots(&menu), 35);
+    }
+
+    /// Make a client in an empty world, and spawn a task that sends a tick
+    /// every millisecond.
+    fn make_test_client() -> Client {
+        let mut app = App::new();
+        app.add_plugins(TickBroadcastPlugin)
+            .add_message::<StartUseItemEvent>();
+        let mut ecs = std::mem::take(app.world_mut());
+
+        let entity = ecs.spawn(Inventory::default()).id();
+        ecs.entity_mut(entity)
+            .insert(WorldHolder::new(entity, Default::default()));
+        let tick_broadcast = (**ecs.resource::<TickBroadcast>()).clone();
+        tokio::spawn(async move {
+            while tick_broadcast.send(()).is_ok() {
+                tokio::time::sleep(Duration::from_millis(1)).await;
+            }
+        });
+
+        Client::new(entity, Arc::new(RwLock::new(ecs)))
+    }
+
+    #[tokio::test]
+    async fn test_open_container_block_was_air() {
+        let bot = make_test_client();
+        let res = bot
+            .try_open_container_at_with_timeout_ticks(BlockPos::new(0, 0, 0), Some(3))
+            .await;
+        assert!(matches!(res, Err(OpenContainerError::BlockWasAir)));
+
+        // the block is still clicked in case our world is out of date
+        let mut ecs = bot.ecs.write();
+        let clicks = ecs
+            .resource_mut::<Messages<StartUseItemEvent>>()
+            .drain()
+            .map(|event| event.force_block)
+            .collect::<Vec<_>>();
+        assert_eq!(clicks, [Some(BlockPos::new(0, 0, 0))]);
+    }
+
+    #[tokio::test]
+    async fn test_open_container_timeout() {
+        let bot = make_test_client();
+        bot.ecs
+            .write()
+            .entity_mut(bot.entity)
+            .insert(WaitingForInventoryOpen);
+        let res = bot.try_wait_for_container_open(Some(3)).await;
+        assert!(matches!(res, Err(OpenContainerError::Timeout)));
+
+        // and if the server never opened anything, the old function gives None
+        bot.ecs
+            .write()
+            .entity_mut(bot.entity)
+            .remove::<WaitingForInventoryOpen>();
+        assert!(
+            bot.wait_for_container_open(Some(3))
+                .await
+                .unwrap()
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_container_update_for_other_container() {
+        use azalea_protocol::packets::{
+            Packet,
+            game::{ClientboundContainerSetContent, ClientboundContainerSetSlot},
+        };
+
+        let mut app = App::new();
+        app.add_message::<ReceiveGamePacketEvent>()
+            .add_systems(Update, handle_container_updated_event);
+        let entity = app
+            .world_mut()
+            .spawn(WaitingForContainerUpdate { container_id: 2 })
+            .id();
+
+        // an update to our own inventory shouldn't stop the wait
+        app.world_mut().write_message(ReceiveGamePacketEvent {
+            entity,
+            packet: Arc::new(
+                ClientboundContainerSetSlot {
+                    container_id: 0,
+                    state_id: 1,
+                    slot: 36,
+                    item_stack: ItemKind::Stone.into(),
+                }
+                .into_variant(),
+            ),
+        });
+        app.update();
+        assert!(
+            app.world()
+                .get::<WaitingForContainerUpdate>(entity)
+                .is_some()
+        );
+
+        app.world_mut().write_message(ReceiveGamePacketEvent {
+            entity,
+            packet: Arc::new(
+                ClientboundContainerSetContent {
+                    container_id: 2,
+                    state_id: 2,
+                    items: Vec::new(),
+                    carried_item: ItemStack::Empty,
+                }
+                .into_variant(),
+            ),
+        });
+        app.update();
+        assert!(
+            app.world()
+                .get::<WaitingForContainerUpdate>(entity)
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_indexed_contents() {
+        let mut menu = Menu::from_kind(MenuKind::Generic9x3);
+        *menu.slot_mut(2).unwrap() = ItemKind::Stone.into();
+        *menu.slot_mut(26).unwrap() = ItemKind::Dirt.into();
+        // in the player's inventory, so it shouldn't be included
+        *menu.slot_mut(27).unwrap() = ItemKind::Diamond.into();
+
+        let contents = indexed_contents(&menu);
+        assert_eq!(
+            contents,
+            vec![(2, ItemKind::Stone.into()), (26, ItemKind::Dirt.into())]
+        );
+        for (i, item) in contents {
+            assert_eq!(menu.slot(i), Some(&item));
+        }
+
+        // the offhand comes after the player's inventory in the player menu
+        let mut menu = Menu::Player(Default::default());
+        *menu.slot_mut(45).unwrap() = ItemKind::Shield.into();
+        assert_eq!(indexed_contents(&menu), vec![(45, ItemKind::Shield.into())]);
+    }
+
+    #[test]
+    fn test_matching_player_slots() {
+        let mut menu = Menu::from_kind(MenuKind::Generic9x3);
+        // in the chest, so it shouldn't be included
+        *menu.slot_mut(0).unwrap() = ItemKind::Dirt.into();
+        *menu.slot_mut(27).unwrap() = ItemKind::Dirt.into();
+        *menu.slot_mut(28).unwrap() = ItemKind::Stone.into();
+        *menu.slot_mut(62).unwrap() = ItemKind::Dirt.into();
+        assert_eq!(matching_player_slots(&menu, ItemKind::Dirt), vec![27, 62]);
+        assert!(matching_player_slots(&menu, ItemKind::Diamond).is_empty());
+    }
+
+    #[test]
+    fn test_drop_slot() {
+        let mut ecs = World::new();
+        let entity = ecs.spawn(Inventory::default()).id();
+
+        let clicks = Arc::new(parking_lot::Mutex::new(Vec::new()));
+        let clicks_clone = clicks.clone();
+        ecs.add_observer(move |click: On<ContainerClickEvent>| {
+            clicks_clone
+                .lock()
+                .push((click.window_id, click.operation.clone()));
+        });
+        {
+            let mut inventory = ecs.get_mut::<Inventory>(entity).unwrap();
+            let menu = &mut inventory.inventory_menu;
+            *menu.slot_mut(9).unwrap() = ItemKind::Dirt.into();
+            *menu.slot_mut(39).unwrap() = ItemKind::Dirt.into();
+        }
+
+        let bot = Client::new(entity, Arc::new(RwLock::new(ecs)));
+        let inventory = bot.get_inventory().unwrap();
+        inventory.drop_one(36_usize);
+        inventory.drop_stack(37_usize);
+        assert_eq!(inventory.drop_all_matching(ItemKind::Dirt), Some(2));
+
+        let clicks = clicks.lock();
+        assert_eq!(clicks.len(), 4);
+        assert!(clicks.iter().all(|(window_id, _)| *window_id == 0));
+        assert!(matches!(
+            clicks[0].1,
+            ClickOperation::Throw(ThrowClick::Single { slot: 36 })
+        ));
+        assert!(matches!(
+            clicks[1].1,
+            ClickOperation::Throw(ThrowClick::All { slot: 37 })
+        ));
+        assert!(matches!(
+            clicks[2].1,
+            ClickOperation::Throw(ThrowClick::All { slot: 9 })
+        ));
+        assert!(matches!(
+            clicks[3].1,
+            ClickOperation::Throw(ThrowClick::All { slot: 39 })
+        ));
+    }
+}
@@ -10,6 +10,7 @@ use azalea_entity::{Dead, InLoadedChunk};
 use azalea_protocol::{
     connect::ConnectionError, packets::game::c_player_combat_kill::ClientboundPlayerCombatKill,
 };
+use azalea_registry::identifier::Identifier;
 use azalea_world::WorldName;
 use bevy_app::{App, Plugin, PreUpdate, Update};
 use bevy_ecs::prelude::*;
@@ -19,6 +20,7 @@ use tokio::sync::mpsc;
 use crate::{
     chunks::ReceiveChunkEvent,
     client_chat::{ChatPacket, ChatReceivedEvent},
+    custom_payload::CustomPayloadEvent,
     disconnect::DisconnectEvent,
     packet::game::{
         AddPlayerEvent, DeathEvent, KeepAliveEvent, RemovePlayerEvent, UpdatePlayerEvent,
@@ -134,6 +136,15 @@ pub enum Event {
     /// [`ConnectionFailedEvent`].
     ConnectionFailed(Arc<ConnectionError>),
     ReceiveChunk(ChunkPos),
+    /// The server sent us a custom payload (also known as a plugin message) on
+    /// the given channel.
+    ///
+    /// You can register handlers for specific channels with
+    /// [`CustomPayloadHandlers`](crate::custom_payload::CustomPayloadHandlers).
+    CustomPayload {
+        channel: Identifier,
+        data: Vec<u8>,
+    },
 }
 
 /// A component that contains an event sender for events that are only
@@ -169,7 +180,8 @@ impl Plugin for EventsPlugin {
             init_listener.before(super::connection::read_packets),
         )
         .add_systems(GameTick, tick_listener)
-        .add_observer(keepalive_listener);
+        .add_observer(keepalive_listener)
+        .add_observer(custom_payload_listener);
     }
 }
 
@@ -296,6 +308,18 @@ pub fn keepalive_listener(keep_alive: On<KeepAliveEvent>, query: Query<&LocalPla
     }
 }
 
+pub fn custom_payload_listener(
+    custom_payload: On<CustomPayloadEvent>,
+    query: Query<&LocalPlayerEvents>,
+) {
+    if let Ok(local_player_events) = query.get(custom_payload.entity) {
+        let _ = local_player_events.send(Event::CustomPayload {
+            channel: custom_payload.channel.clone(),
+            data: custom_payload.data.clone(),
+        });
+    }
+}
+
 pub fn disconnect_listener(
     query: Query<&LocalPlayerEvents>,
     mut events: MessageReader<DisconnectEvent>,
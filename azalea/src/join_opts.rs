@@ -1,5 +1,6 @@
 use std::net::SocketAddr;
 
+use azalea_client::join::ConnectTimeouts;
 use azalea_protocol::{address::ServerAddr, connect::Proxy};
 
 /// Optional settings when adding an account to a swarm or client.
@@ -26,6 +27,17 @@ pub struct JoinOpts {
     /// to the server.
     #[doc(alias = "custom_resolved_address")]
     pub custom_socket_addr: Option<SocketAddr>,
+    /// Override how long each phase of joining the server may take.
+    ///
+    /// If this is `None`, the defaults from [`ConnectTimeouts`] are used.
+    pub timeouts: Option<ConnectTimeouts>,
+    /// The minimum capacity of the buffer that packets are read into.
+    ///
+    /// If this is `None`, the buffer only grows when it needs to. See
+    /// [`Connection::set_read_buffer_capacity`].
+    ///
+    /// [`Connection::set_read_buffer_capacity`]: azalea_protocol::connect::Connection::set_read_buffer_capacity
+    pub read_buffer_capacity: Option<usize>,
 }
 
 impl JoinOpts {
@@ -46,6 +58,12 @@ impl JoinOpts {
         if let Some(custom_socket_addr) = other.custom_socket_addr {
             self.custom_socket_addr = Some(custom_socket_addr);
         }
+        if let Some(timeouts) = other.timeouts {
+            self.timeouts = Some(timeouts);
+        }
+        if let Some(read_buffer_capacity) = other.read_buffer_capacity {
+            self.read_buffer_capacity = Some(read_buffer_capacity);
+        }
     }
 
     /// Configure the SOCKS5 proxy used for connecting to the server and for
@@ -94,6 +112,25 @@ impl JoinOpts {
         self
     }
 
+    /// Set how long each phase of joining the server may take before the
+    /// connection is considered to have timed out.
+    #[must_use]
+    pub fn timeouts(mut self, timeouts: ConnectTimeouts) -> Self {
+        self.timeouts = Some(timeouts);
+        self
+    }
+
+    /// Set the minimum capacity of the buffer that packets are read into.
+    ///
+    /// Setting this to around the size of the largest packets you expect to
+    /// receive (like chunk packets) avoids reallocating the buffer while
+    /// they're being read.
+    #[must_use]
+    pub fn read_buffer_capacity(mut self, capacity: usize) -> Self {
+        self.read_buffer_capacity = Some(capacity);
+        self
+    }
+
     #[doc(hidden)]
     #[deprecated = "renamed to `custom_server_addr`."]
     pub fn custom_address(self, server_addr: ServerAddr) -> Self {
@@ -190,6 +190,8 @@ impl Swarm {
                 address,
                 server_proxy,
                 sessionserver_proxy,
+                timeouts: join_opts.timeouts.unwrap_or_default(),
+                read_buffer_capacity: join_opts.read_buffer_capacity.unwrap_or_default(),
             },
             event_sender: Some(tx),
         })